@@ -13,6 +13,117 @@ export type AccessControl = {
     description: "Access Control for Solana Programs";
   };
   instructions: [
+    {
+      name: "acceptAuthority";
+      discriminator: [107, 86, 198, 91, 33, 12, 107, 160];
+      accounts: [
+        {
+          name: "accessControlAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [97, 99];
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+          writable: true;
+        },
+        {
+          name: "authority";
+          docs: [
+            "Current authority, signs the transfer hook and metadata update authority changes"
+          ];
+          signer: true;
+        },
+        {
+          name: "newAuthority";
+          docs: ["Proposed authority accepting the handover"];
+          signer: true;
+        },
+        {
+          name: "tokenProgram";
+          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "approveProposal";
+      discriminator: [136, 108, 102, 85, 98, 114, 7, 147];
+      accounts: [
+        {
+          name: "proposal";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [112, 114, 111, 112, 111, 115, 97, 108];
+              },
+              {
+                kind: "account";
+                path: "accessControl";
+              },
+              {
+                kind: "account";
+                path: "proposal.id";
+                account: "proposal";
+              }
+            ];
+          };
+        },
+        {
+          name: "accessControl";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [97, 99];
+              },
+              {
+                kind: "account";
+                path: "access_control.mint";
+                account: "accessControl";
+              }
+            ];
+          };
+        },
+        {
+          name: "authorityWalletRole";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [119, 97, 108, 108, 101, 116, 95, 114, 111, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "access_control.mint";
+                account: "accessControl";
+              },
+              {
+                kind: "account";
+                path: "authority";
+              }
+            ];
+          };
+        },
+        {
+          name: "authority";
+          signer: true;
+        }
+      ];
+      args: [];
+    },
     {
       name: "burnSecurities";
       discriminator: [79, 165, 145, 57, 203, 228, 175, 0];
@@ -128,135 +239,58 @@ export type AccessControl = {
         {
           name: "amount";
           type: "u64";
+        },
+        {
+          name: "reason";
+          type: "string";
         }
       ];
     },
     {
-      name: "forceTransferBetween";
-      discriminator: [186, 115, 85, 7, 217, 111, 254, 108];
+      name: "cancelProposal";
+      discriminator: [106, 74, 128, 146, 19, 65, 39, 23];
       accounts: [
         {
-          name: "sourceAccount";
+          name: "proposal";
           writable: true;
           pda: {
             seeds: [
               {
-                kind: "account";
-                path: "sourceAuthority";
+                kind: "const";
+                value: [112, 114, 111, 112, 111, 115, 97, 108];
               },
               {
                 kind: "account";
-                path: "tokenProgram";
+                path: "accessControl";
               },
               {
                 kind: "account";
-                path: "securityMint";
+                path: "proposal.id";
+                account: "proposal";
               }
             ];
-            program: {
-              kind: "const";
-              value: [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ];
-            };
           };
         },
         {
-          name: "securityMint";
+          name: "proposer";
           writable: true;
         },
         {
-          name: "destinationAccount";
-          writable: true;
+          name: "accessControl";
           pda: {
             seeds: [
               {
-                kind: "account";
-                path: "destinationAuthority";
-              },
-              {
-                kind: "account";
-                path: "tokenProgram";
+                kind: "const";
+                value: [97, 99];
               },
               {
                 kind: "account";
-                path: "securityMint";
+                path: "access_control.mint";
+                account: "accessControl";
               }
             ];
-            program: {
-              kind: "const";
-              value: [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ];
-            };
           };
         },
-        {
-          name: "authority";
-          signer: true;
-        },
         {
           name: "authorityWalletRole";
           pda: {
@@ -267,7 +301,8 @@ export type AccessControl = {
               },
               {
                 kind: "account";
-                path: "securityMint";
+                path: "access_control.mint";
+                account: "accessControl";
               },
               {
                 kind: "account";
@@ -277,67 +312,40 @@ export type AccessControl = {
           };
         },
         {
-          name: "accessControlAccount";
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [97, 99];
-              },
-              {
-                kind: "account";
-                path: "securityMint";
-              }
-            ];
-          };
-        },
-        {
-          name: "sourceAuthority";
-        },
-        {
-          name: "destinationAuthority";
-        },
-        {
-          name: "tokenProgram";
-          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
-        }
-      ];
-      args: [
-        {
-          name: "amount";
-          type: "u64";
+          name: "authority";
+          signer: true;
         }
       ];
+      args: [];
     },
     {
-      name: "freezeWallet";
-      discriminator: [93, 202, 159, 167, 22, 246, 255, 211];
+      name: "createProposal";
+      discriminator: [132, 116, 68, 174, 216, 160, 198, 22];
       accounts: [
         {
-          name: "authority";
-          signer: true;
-        },
-        {
-          name: "authorityWalletRole";
+          name: "proposal";
+          writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [119, 97, 108, 108, 101, 116, 95, 114, 111, 108, 101];
+                value: [112, 114, 111, 112, 111, 115, 97, 108];
               },
               {
                 kind: "account";
-                path: "securityMint";
+                path: "accessControl";
               },
               {
                 kind: "account";
-                path: "authority";
+                path: "access_control.proposals_count";
+                account: "accessControl";
               }
             ];
           };
         },
         {
           name: "accessControl";
+          writable: true;
           pda: {
             seeds: [
               {
@@ -353,269 +361,258 @@ export type AccessControl = {
         },
         {
           name: "securityMint";
-          writable: true;
         },
         {
-          name: "targetAccount";
-          writable: true;
+          name: "authorityWalletRole";
           pda: {
             seeds: [
               {
-                kind: "account";
-                path: "targetAuthority";
+                kind: "const";
+                value: [119, 97, 108, 108, 101, 116, 95, 114, 111, 108, 101];
               },
               {
                 kind: "account";
-                path: "tokenProgram";
+                path: "securityMint";
               },
               {
                 kind: "account";
-                path: "securityMint";
+                path: "payer";
               }
             ];
-            program: {
-              kind: "const";
-              value: [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ];
-            };
           };
         },
-        {
-          name: "targetAuthority";
-        },
-        {
-          name: "tokenProgram";
-          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
-        }
-      ];
-      args: [];
-    },
-    {
-      name: "initializeAccessControl";
-      discriminator: [244, 90, 245, 242, 199, 224, 247, 140];
-      accounts: [
         {
           name: "payer";
           writable: true;
           signer: true;
         },
-        {
-          name: "authority";
-        },
-        {
-          name: "mint";
-          writable: true;
-          signer: true;
-        },
-        {
-          name: "accessControl";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [97, 99];
-              },
-              {
-                kind: "account";
-                path: "mint";
-              }
-            ];
-          };
-        },
-        {
-          name: "walletRole";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [119, 97, 108, 108, 101, 116, 95, 114, 111, 108, 101];
-              },
-              {
-                kind: "account";
-                path: "mint";
-              },
-              {
-                kind: "account";
-                path: "payer";
-              }
-            ];
-          };
-        },
         {
           name: "systemProgram";
           address: "11111111111111111111111111111111";
-        },
-        {
-          name: "tokenProgram";
-          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
         }
       ];
       args: [
         {
-          name: "args";
+          name: "action";
           type: {
             defined: {
-              name: "initializeAccessControlArgs";
+              name: "proposalAction";
             };
           };
+        },
+        {
+          name: "reason";
+          type: "string";
+        },
+        {
+          name: "expiresAt";
+          type: "i64";
         }
       ];
     },
     {
-      name: "initializeWalletRole";
-      discriminator: [218, 166, 58, 194, 218, 211, 151, 175];
+      name: "executeBurnSecuritiesProposal";
+      discriminator: [61, 175, 211, 23, 139, 217, 210, 46];
       accounts: [
         {
-          name: "walletRole";
+          name: "proposal";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [119, 97, 108, 108, 101, 116, 95, 114, 111, 108, 101];
+                value: [112, 114, 111, 112, 111, 115, 97, 108];
               },
               {
                 kind: "account";
-                path: "securityToken";
+                path: "accessControl";
               },
               {
                 kind: "account";
-                path: "userWallet";
+                path: "proposal.id";
+                account: "proposal";
               }
             ];
           };
         },
         {
-          name: "authorityWalletRole";
+          name: "proposer";
+          writable: true;
+        },
+        {
+          name: "accessControl";
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [119, 97, 108, 108, 101, 116, 95, 114, 111, 108, 101];
-              },
-              {
-                kind: "account";
-                path: "securityToken";
+                value: [97, 99];
               },
               {
                 kind: "account";
-                path: "payer";
+                path: "securityMint";
               }
             ];
           };
         },
         {
-          name: "accessControl";
+          name: "securityMint";
+          writable: true;
+        },
+        {
+          name: "targetAccount";
+          writable: true;
           pda: {
             seeds: [
               {
-                kind: "const";
-                value: [97, 99];
+                kind: "account";
+                path: "targetAuthority";
               },
               {
                 kind: "account";
-                path: "securityToken";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "securityMint";
               }
             ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
           };
         },
         {
-          name: "securityToken";
-        },
-        {
-          name: "userWallet";
+          name: "targetAuthority";
         },
         {
-          name: "payer";
-          writable: true;
+          name: "executor";
           signer: true;
         },
         {
-          name: "systemProgram";
-          address: "11111111111111111111111111111111";
-        }
-      ];
-      args: [
-        {
-          name: "role";
-          type: "u8";
+          name: "tokenProgram";
+          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
         }
       ];
+      args: [];
     },
     {
-      name: "mintSecurities";
-      discriminator: [90, 195, 58, 36, 142, 195, 14, 225];
+      name: "executeForceTransferBetweenProposal";
+      discriminator: [54, 18, 169, 42, 126, 118, 150, 8];
       accounts: [
         {
-          name: "authority";
-          signer: true;
-        },
-        {
-          name: "authorityWalletRole";
+          name: "proposal";
+          writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [119, 97, 108, 108, 101, 116, 95, 114, 111, 108, 101];
+                value: [112, 114, 111, 112, 111, 115, 97, 108];
               },
               {
                 kind: "account";
-                path: "securityMint";
+                path: "accessControl";
               },
               {
                 kind: "account";
-                path: "authority";
+                path: "proposal.id";
+                account: "proposal";
               }
             ];
           };
         },
         {
-          name: "accessControl";
+          name: "proposer";
+          writable: true;
+        },
+        {
+          name: "sourceAccount";
+          writable: true;
           pda: {
             seeds: [
               {
-                kind: "const";
-                value: [97, 99];
+                kind: "account";
+                path: "sourceAuthority";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
               },
               {
                 kind: "account";
                 path: "securityMint";
               }
             ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
           };
         },
         {
@@ -680,27 +677,7 @@ export type AccessControl = {
           };
         },
         {
-          name: "destinationAuthority";
-        },
-        {
-          name: "tokenProgram";
-          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
-        }
-      ];
-      args: [
-        {
-          name: "amount";
-          type: "u64";
-        }
-      ];
-    },
-    {
-      name: "setLockupEscrowAccount";
-      discriminator: [134, 172, 249, 223, 25, 118, 55, 93];
-      accounts: [
-        {
-          name: "accessControlAccount";
-          writable: true;
+          name: "accessControl";
           pda: {
             seeds: [
               {
@@ -709,99 +686,179 @@ export type AccessControl = {
               },
               {
                 kind: "account";
-                path: "mint";
+                path: "securityMint";
               }
             ];
           };
         },
         {
-          name: "mint";
-        },
-        {
-          name: "authorityWalletRole";
+          name: "sourceAuthority";
         },
         {
-          name: "escrowAccount";
+          name: "destinationAuthority";
         },
         {
-          name: "tokenlockAccount";
+          name: "executor";
+          signer: true;
         },
         {
-          name: "payer";
-          writable: true;
-          signer: true;
+          name: "tokenProgram";
+          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
         }
       ];
       args: [];
     },
     {
-      name: "setMaxTotalSupply";
-      discriminator: [249, 164, 34, 254, 160, 89, 214, 12];
+      name: "executeMintSecuritiesProposal";
+      discriminator: [71, 186, 61, 141, 247, 244, 185, 226];
       accounts: [
         {
-          name: "accessControlAccount";
+          name: "proposal";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [97, 99];
+                value: [112, 114, 111, 112, 111, 115, 97, 108];
               },
               {
                 kind: "account";
-                path: "mint";
+                path: "accessControl";
+              },
+              {
+                kind: "account";
+                path: "proposal.id";
+                account: "proposal";
               }
             ];
           };
         },
         {
-          name: "mint";
+          name: "proposer";
+          writable: true;
         },
         {
-          name: "authorityWalletRole";
+          name: "accessControl";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [97, 99];
+              },
+              {
+                kind: "account";
+                path: "securityMint";
+              }
+            ];
+          };
         },
         {
-          name: "payer";
+          name: "securityMint";
+          writable: true;
+        },
+        {
+          name: "destinationAccount";
           writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "destinationAuthority";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "securityMint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "destinationAuthority";
+        },
+        {
+          name: "executor";
           signer: true;
-        }
-      ];
-      args: [
+        },
         {
-          name: "maxTotalSupply";
-          type: "u64";
+          name: "tokenProgram";
+          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
         }
       ];
+      args: [];
     },
     {
-      name: "thawWallet";
-      discriminator: [79, 251, 128, 221, 55, 172, 181, 221];
+      name: "executeSetMaxTotalSupplyProposal";
+      discriminator: [253, 75, 102, 159, 59, 100, 15, 243];
       accounts: [
         {
-          name: "authority";
-          signer: true;
-        },
-        {
-          name: "authorityWalletRole";
+          name: "proposal";
+          writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [119, 97, 108, 108, 101, 116, 95, 114, 111, 108, 101];
+                value: [112, 114, 111, 112, 111, 115, 97, 108];
               },
               {
                 kind: "account";
-                path: "securityMint";
+                path: "accessControl";
               },
               {
                 kind: "account";
-                path: "authority";
+                path: "proposal.id";
+                account: "proposal";
               }
             ];
           };
         },
+        {
+          name: "proposer";
+          writable: true;
+        },
         {
           name: "accessControl";
+          writable: true;
           pda: {
             seeds: [
               {
@@ -810,23 +867,33 @@ export type AccessControl = {
               },
               {
                 kind: "account";
-                path: "securityMint";
+                path: "mint";
               }
             ];
           };
         },
         {
-          name: "securityMint";
-          writable: true;
+          name: "mint";
         },
         {
-          name: "targetAccount";
+          name: "executor";
+          signer: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "forceTransferBetween";
+      discriminator: [186, 115, 85, 7, 217, 111, 254, 108];
+      accounts: [
+        {
+          name: "sourceAccount";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "account";
-                path: "targetAuthority";
+                path: "sourceAuthority";
               },
               {
                 kind: "account";
@@ -877,39 +944,70 @@ export type AccessControl = {
           };
         },
         {
-          name: "targetAuthority";
+          name: "securityMint";
+          writable: true;
         },
         {
-          name: "tokenProgram";
-          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
-        }
-      ];
-      args: [];
-    },
-    {
-      name: "updateWalletRole";
-      discriminator: [1, 63, 55, 231, 251, 199, 154, 9];
-      accounts: [
-        {
-          name: "walletRole";
+          name: "destinationAccount";
           writable: true;
           pda: {
             seeds: [
               {
-                kind: "const";
-                value: [119, 97, 108, 108, 101, 116, 95, 114, 111, 108, 101];
+                kind: "account";
+                path: "destinationAuthority";
               },
               {
                 kind: "account";
-                path: "securityToken";
+                path: "tokenProgram";
               },
               {
                 kind: "account";
-                path: "userWallet";
+                path: "securityMint";
               }
             ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
           };
         },
+        {
+          name: "authority";
+          signer: true;
+        },
         {
           name: "authorityWalletRole";
           pda: {
@@ -920,17 +1018,17 @@ export type AccessControl = {
               },
               {
                 kind: "account";
-                path: "securityToken";
+                path: "securityMint";
               },
               {
                 kind: "account";
-                path: "payer";
+                path: "authority";
               }
             ];
           };
         },
         {
-          name: "accessControl";
+          name: "accessControlAccount";
           pda: {
             seeds: [
               {
@@ -939,152 +1037,1939 @@ export type AccessControl = {
               },
               {
                 kind: "account";
-                path: "securityToken";
+                path: "securityMint";
               }
             ];
           };
         },
         {
-          name: "securityToken";
-        },
-        {
-          name: "userWallet";
+          name: "sourceAuthority";
         },
         {
-          name: "payer";
-          writable: true;
-          signer: true;
+          name: "destinationAuthority";
         },
         {
-          name: "systemProgram";
-          address: "11111111111111111111111111111111";
+          name: "tokenProgram";
+          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
         }
       ];
       args: [
         {
-          name: "role";
-          type: "u8";
-        }
-      ];
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "reason";
+          type: "string";
+        }
+      ];
+    },
+    {
+      name: "freezeWallet";
+      discriminator: [93, 202, 159, 167, 22, 246, 255, 211];
+      accounts: [
+        {
+          name: "authority";
+          signer: true;
+        },
+        {
+          name: "authorityWalletRole";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [119, 97, 108, 108, 101, 116, 95, 114, 111, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "securityMint";
+              },
+              {
+                kind: "account";
+                path: "authority";
+              }
+            ];
+          };
+        },
+        {
+          name: "accessControl";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [97, 99];
+              },
+              {
+                kind: "account";
+                path: "securityMint";
+              }
+            ];
+          };
+        },
+        {
+          name: "securityMint";
+          writable: true;
+        },
+        {
+          name: "targetAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "targetAuthority";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "securityMint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "targetAuthority";
+        },
+        {
+          name: "tokenProgram";
+          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+        }
+      ];
+      args: [
+        {
+          name: "reason";
+          type: "string";
+        }
+      ];
+    },
+    {
+      name: "initializeAccessControl";
+      discriminator: [244, 90, 245, 242, 199, 224, 247, 140];
+      accounts: [
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "authority";
+        },
+        {
+          name: "mint";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "accessControl";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [97, 99];
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+          };
+        },
+        {
+          name: "walletRole";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [119, 97, 108, 108, 101, 116, 95, 114, 111, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "mint";
+              },
+              {
+                kind: "account";
+                path: "payer";
+              }
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "tokenProgram";
+          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: {
+              name: "initializeAccessControlArgs";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "initializeWalletRole";
+      discriminator: [218, 166, 58, 194, 218, 211, 151, 175];
+      accounts: [
+        {
+          name: "walletRole";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [119, 97, 108, 108, 101, 116, 95, 114, 111, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "securityToken";
+              },
+              {
+                kind: "account";
+                path: "userWallet";
+              }
+            ];
+          };
+        },
+        {
+          name: "authorityWalletRole";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [119, 97, 108, 108, 101, 116, 95, 114, 111, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "securityToken";
+              },
+              {
+                kind: "account";
+                path: "payer";
+              }
+            ];
+          };
+        },
+        {
+          name: "accessControl";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [97, 99];
+              },
+              {
+                kind: "account";
+                path: "securityToken";
+              }
+            ];
+          };
+        },
+        {
+          name: "securityToken";
+        },
+        {
+          name: "userWallet";
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [
+        {
+          name: "role";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "migrateAccessControl";
+      discriminator: [9, 226, 131, 152, 244, 16, 25, 133];
+      accounts: [
+        {
+          name: "accessControl";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [97, 99];
+              },
+              {
+                kind: "account";
+                path: "securityToken";
+              }
+            ];
+          };
+        },
+        {
+          name: "securityToken";
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "mintSecurities";
+      discriminator: [90, 195, 58, 36, 142, 195, 14, 225];
+      accounts: [
+        {
+          name: "authority";
+          signer: true;
+        },
+        {
+          name: "authorityWalletRole";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [119, 97, 108, 108, 101, 116, 95, 114, 111, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "securityMint";
+              },
+              {
+                kind: "account";
+                path: "authority";
+              }
+            ];
+          };
+        },
+        {
+          name: "accessControl";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [97, 99];
+              },
+              {
+                kind: "account";
+                path: "securityMint";
+              }
+            ];
+          };
+        },
+        {
+          name: "securityMint";
+          writable: true;
+        },
+        {
+          name: "destinationAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "destinationAuthority";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "securityMint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "destinationAuthority";
+        },
+        {
+          name: "tokenProgram";
+          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "reason";
+          type: "string";
+        }
+      ];
+    },
+    {
+      name: "proposeAuthority";
+      discriminator: [20, 148, 236, 198, 76, 119, 99, 142];
+      accounts: [
+        {
+          name: "accessControlAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [97, 99];
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "authority";
+          signer: true;
+        }
+      ];
+      args: [
+        {
+          name: "newAuthority";
+          type: "pubkey";
+        }
+      ];
+    },
+    {
+      name: "revokeWalletRole";
+      discriminator: [165, 167, 227, 9, 175, 205, 215, 76];
+      accounts: [
+        {
+          name: "walletRole";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [119, 97, 108, 108, 101, 116, 95, 114, 111, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "securityToken";
+              },
+              {
+                kind: "account";
+                path: "userWallet";
+              }
+            ];
+          };
+        },
+        {
+          name: "authorityWalletRole";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [119, 97, 108, 108, 101, 116, 95, 114, 111, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "securityToken";
+              },
+              {
+                kind: "account";
+                path: "payer";
+              }
+            ];
+          };
+        },
+        {
+          name: "accessControl";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [97, 99];
+              },
+              {
+                kind: "account";
+                path: "securityToken";
+              }
+            ];
+          };
+        },
+        {
+          name: "securityToken";
+        },
+        {
+          name: "userWallet";
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "rentReceiver";
+          writable: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "setApprovalThreshold";
+      discriminator: [136, 178, 240, 249, 212, 42, 150, 63];
+      accounts: [
+        {
+          name: "accessControlAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [97, 99];
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        }
+      ];
+      args: [
+        {
+          name: "actionType";
+          type: {
+            defined: {
+              name: "proposalActionType";
+            };
+          };
+        },
+        {
+          name: "threshold";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "setLockupEscrowAccount";
+      discriminator: [134, 172, 249, 223, 25, 118, 55, 93];
+      accounts: [
+        {
+          name: "accessControlAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [97, 99];
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "escrowAccount";
+        },
+        {
+          name: "tokenlockAccount";
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "setMaxTotalSupply";
+      discriminator: [249, 164, 34, 254, 160, 89, 214, 12];
+      accounts: [
+        {
+          name: "accessControlAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [97, 99];
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        }
+      ];
+      args: [
+        {
+          name: "maxTotalSupply";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "thawWallet";
+      discriminator: [79, 251, 128, 221, 55, 172, 181, 221];
+      accounts: [
+        {
+          name: "authority";
+          signer: true;
+        },
+        {
+          name: "authorityWalletRole";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [119, 97, 108, 108, 101, 116, 95, 114, 111, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "securityMint";
+              },
+              {
+                kind: "account";
+                path: "authority";
+              }
+            ];
+          };
+        },
+        {
+          name: "accessControl";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [97, 99];
+              },
+              {
+                kind: "account";
+                path: "securityMint";
+              }
+            ];
+          };
+        },
+        {
+          name: "securityMint";
+          writable: true;
+        },
+        {
+          name: "targetAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "targetAuthority";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "securityMint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "targetAuthority";
+        },
+        {
+          name: "tokenProgram";
+          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+        }
+      ];
+      args: [
+        {
+          name: "reason";
+          type: "string";
+        }
+      ];
+    },
+    {
+      name: "updateTokenMetadata";
+      discriminator: [243, 6, 8, 23, 126, 181, 251, 158];
+      accounts: [
+        {
+          name: "accessControlAccount";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [97, 99];
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+          writable: true;
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "authority";
+          docs: ["Metadata update authority of the mint"];
+          signer: true;
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "tokenProgram";
+          address: "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: {
+              name: "updateTokenMetadataArgs";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "updateWalletRole";
+      discriminator: [1, 63, 55, 231, 251, 199, 154, 9];
+      accounts: [
+        {
+          name: "walletRole";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [119, 97, 108, 108, 101, 116, 95, 114, 111, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "securityToken";
+              },
+              {
+                kind: "account";
+                path: "userWallet";
+              }
+            ];
+          };
+        },
+        {
+          name: "authorityWalletRole";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [119, 97, 108, 108, 101, 116, 95, 114, 111, 108, 101];
+              },
+              {
+                kind: "account";
+                path: "securityToken";
+              },
+              {
+                kind: "account";
+                path: "payer";
+              }
+            ];
+          };
+        },
+        {
+          name: "accessControl";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [97, 99];
+              },
+              {
+                kind: "account";
+                path: "securityToken";
+              }
+            ];
+          };
+        },
+        {
+          name: "securityToken";
+        },
+        {
+          name: "userWallet";
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [
+        {
+          name: "role";
+          type: "u8";
+        }
+      ];
     }
   ];
   accounts: [
     {
-      name: "accessControl";
-      discriminator: [147, 81, 178, 92, 223, 66, 181, 132];
+      name: "accessControl";
+      discriminator: [147, 81, 178, 92, 223, 66, 181, 132];
+    },
+    {
+      name: "proposal";
+      discriminator: [26, 94, 189, 187, 116, 136, 53, 33];
+    },
+    {
+      name: "walletRole";
+      discriminator: [219, 71, 35, 217, 102, 248, 173, 9];
+    }
+  ];
+  events: [
+    {
+      name: "approvalThresholdSetEvent";
+      discriminator: [164, 207, 2, 151, 161, 142, 41, 216];
+    },
+    {
+      name: "authorityProposedEvent";
+      discriminator: [221, 27, 73, 198, 252, 169, 231, 224];
+    },
+    {
+      name: "authorityTransferredEvent";
+      discriminator: [54, 31, 118, 237, 152, 5, 201, 11];
+    },
+    {
+      name: "forceTransferBetweenEvent";
+      discriminator: [172, 71, 127, 79, 132, 250, 14, 24];
+    },
+    {
+      name: "lockupEscrowAccountSetEvent";
+      discriminator: [155, 156, 6, 80, 235, 100, 236, 196];
+    },
+    {
+      name: "maxTotalSupplySetEvent";
+      discriminator: [219, 8, 111, 221, 190, 204, 240, 123];
+    },
+    {
+      name: "proposalApprovedEvent";
+      discriminator: [238, 183, 55, 8, 87, 232, 170, 248];
+    },
+    {
+      name: "proposalCancelledEvent";
+      discriminator: [193, 253, 159, 226, 227, 182, 188, 160];
+    },
+    {
+      name: "proposalCreatedEvent";
+      discriminator: [154, 240, 33, 66, 194, 233, 203, 209];
+    },
+    {
+      name: "proposalExecutedEvent";
+      discriminator: [120, 242, 13, 36, 223, 3, 110, 180];
+    },
+    {
+      name: "securitiesBurnedEvent";
+      discriminator: [161, 106, 217, 209, 193, 119, 253, 56];
+    },
+    {
+      name: "securitiesMintedEvent";
+      discriminator: [192, 217, 111, 224, 143, 63, 217, 187];
+    },
+    {
+      name: "tokenMetadataUpdatedEvent";
+      discriminator: [140, 96, 3, 20, 186, 208, 246, 153];
+    },
+    {
+      name: "walletFrozenEvent";
+      discriminator: [118, 116, 50, 115, 158, 213, 245, 152];
+    },
+    {
+      name: "walletRoleRevokedEvent";
+      discriminator: [255, 25, 14, 46, 148, 111, 218, 136];
+    },
+    {
+      name: "walletRoleUpdatedEvent";
+      discriminator: [179, 105, 225, 243, 107, 180, 181, 236];
+    },
+    {
+      name: "walletThawedEvent";
+      discriminator: [248, 187, 130, 50, 133, 8, 47, 227];
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "unauthorized";
+      msg: "unauthorized";
+    },
+    {
+      code: 6001;
+      name: "invalidRole";
+      msg: "Invalid role";
+    },
+    {
+      code: 6002;
+      name: "mintExceedsMaxTotalSupply";
+      msg: "Cannot mint more than max total supply";
+    },
+    {
+      code: 6003;
+      name: "incorrectTokenlockAccount";
+      msg: "Wrong tokenlock account";
+    },
+    {
+      code: 6004;
+      name: "mismatchedEscrowAccount";
+      msg: "Mismatched escrow account";
+    },
+    {
+      code: 6005;
+      name: "cantBurnSecuritiesWithinLockup";
+      msg: "Cannot burn securities within lockup; cancel the lockup first";
+    },
+    {
+      code: 6006;
+      name: "cantForceTransferBetweenLockup";
+      msg: "Cannot force transfer between lockup accounts";
+    },
+    {
+      code: 6007;
+      name: "newMaxTotalSupplyMustExceedCurrentTotalSupply";
+      msg: "New max total supply must exceed current total supply";
+    },
+    {
+      code: 6008;
+      name: "cannotFreezeLockupEscrowAccount";
+      msg: "Cannot freeze lockup escrow account";
+    },
+    {
+      code: 6009;
+      name: "valueUnchanged";
+      msg: "The provided value is already set. No changes were made";
+    },
+    {
+      code: 6010;
+      name: "invalidPendingAuthority";
+      msg: "New authority was not proposed";
+    },
+    {
+      code: 6011;
+      name: "cannotRemoveLastAdmin";
+      msg: "Cannot remove the last Contract Admin or Reserve Admin";
+    },
+    {
+      code: 6012;
+      name: "proposalRequired";
+      msg: "Action requires an approved proposal";
+    },
+    {
+      code: 6013;
+      name: "invalidApprovalThreshold";
+      msg: "Invalid approval threshold";
+    },
+    {
+      code: 6014;
+      name: "proposalAlreadyApproved";
+      msg: "Proposal is already approved by the wallet";
+    },
+    {
+      code: 6015;
+      name: "notEnoughApprovals";
+      msg: "Proposal does not have enough approvals";
+    },
+    {
+      code: 6016;
+      name: "proposalActionMismatch";
+      msg: "Accounts do not match the proposal action";
+    },
+    {
+      code: 6017;
+      name: "maxApprovalsReached";
+      msg: "Proposal reached the max number of approvals";
+    },
+    {
+      code: 6018;
+      name: "invalidHolderBalanceAccounts";
+      msg: "Missing or invalid accounts to sync the holder balance";
+    },
+    {
+      code: 6019;
+      name: "reasonTooLong";
+      msg: "Reason is too long";
+    },
+    {
+      code: 6020;
+      name: "adminCountOutOfSync";
+      msg: "Tracked admin role count does not match the wallet roles";
+    },
+    {
+      code: 6021;
+      name: "accountAlreadyMigrated";
+      msg: "Account is already migrated";
+    },
+    {
+      code: 6022;
+      name: "invalidWalletRoleAccounts";
+      msg: "Missing or invalid wallet role accounts";
+    },
+    {
+      code: 6023;
+      name: "reserveAdminsBelowApprovalThreshold";
+      msg: "Fewer Reserve Admins would be left than an approval threshold requires";
+    },
+    {
+      code: 6024;
+      name: "invalidProposalExpiration";
+      msg: "Proposal expiration must be in the future";
+    },
+    {
+      code: 6025;
+      name: "proposalExpired";
+      msg: "Proposal is expired";
+    }
+  ];
+  types: [
+    {
+      name: "accessControl";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            type: "pubkey";
+          },
+          {
+            name: "authority";
+            type: "pubkey";
+          },
+          {
+            name: "maxTotalSupply";
+            type: "u64";
+          },
+          {
+            name: "lockupEscrowAccount";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "pendingAuthority";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "contractAdminCount";
+            type: "u64";
+          },
+          {
+            name: "reserveAdminCount";
+            type: "u64";
+          },
+          {
+            name: "walletsAdminCount";
+            type: "u64";
+          },
+          {
+            name: "transferAdminCount";
+            type: "u64";
+          },
+          {
+            name: "approvalThresholds";
+            type: {
+              array: ["u8", 4];
+            };
+          },
+          {
+            name: "proposalsCount";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "approvalThresholdSetEvent";
+      docs: [
+        "Emitted when the approvals required for an action type are changed."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            docs: ["Security token mint."];
+            type: "pubkey";
+          },
+          {
+            name: "actionType";
+            docs: ["Action type the threshold applies to."];
+            type: {
+              defined: {
+                name: "proposalActionType";
+              };
+            };
+          },
+          {
+            name: "thresholdBefore";
+            docs: ["Previous number of required approvals."];
+            type: "u8";
+          },
+          {
+            name: "threshold";
+            docs: ["New number of required approvals."];
+            type: "u8";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who changed the threshold."];
+            type: "pubkey";
+          }
+        ];
+      };
+    },
+    {
+      name: "authorityProposedEvent";
+      docs: [
+        "Emitted when a new authority of the mint transfer hook and metadata is proposed."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            docs: ["Security token mint."];
+            type: "pubkey";
+          },
+          {
+            name: "authority";
+            docs: ["Current authority who proposed the new authority."];
+            type: "pubkey";
+          },
+          {
+            name: "pendingAuthority";
+            docs: ["Proposed authority."];
+            type: "pubkey";
+          }
+        ];
+      };
+    },
+    {
+      name: "authorityTransferredEvent";
+      docs: ["Emitted when the proposed authority accepts the handover."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            docs: ["Security token mint."];
+            type: "pubkey";
+          },
+          {
+            name: "authorityBefore";
+            docs: ["Previous authority."];
+            type: "pubkey";
+          },
+          {
+            name: "authority";
+            docs: ["New authority."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      name: "walletRole";
-      discriminator: [219, 71, 35, 217, 102, 248, 173, 9];
-    }
-  ];
-  errors: [
+      name: "forceTransferBetweenEvent";
+      docs: ["Emitted when securities are force transferred between wallets."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            docs: ["Security token mint."];
+            type: "pubkey";
+          },
+          {
+            name: "sourceAccount";
+            docs: ["Token account the securities are transferred from."];
+            type: "pubkey";
+          },
+          {
+            name: "sourceAuthority";
+            docs: ["Owner of the source token account."];
+            type: "pubkey";
+          },
+          {
+            name: "destinationAccount";
+            docs: ["Token account the securities are transferred to."];
+            type: "pubkey";
+          },
+          {
+            name: "destinationAuthority";
+            docs: ["Owner of the destination token account."];
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            docs: ["Amount of transferred securities."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who transferred the securities."];
+            type: "pubkey";
+          },
+          {
+            name: "reason";
+            docs: ["Reason of the action, empty if not given."];
+            type: "string";
+          }
+        ];
+      };
+    },
     {
-      code: 6000;
-      name: "unauthorized";
-      msg: "unauthorized";
+      name: "initializeAccessControlArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "decimals";
+            type: "u8";
+          },
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "symbol";
+            type: "string";
+          },
+          {
+            name: "uri";
+            type: "string";
+          },
+          {
+            name: "hookProgramId";
+            type: "pubkey";
+          },
+          {
+            name: "maxTotalSupply";
+            type: "u64";
+          }
+        ];
+      };
     },
     {
-      code: 6001;
-      name: "invalidRole";
-      msg: "Invalid role";
+      name: "lockupEscrowAccountSetEvent";
+      docs: ["Emitted when the tokenlock escrow account is set."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            docs: ["Security token mint."];
+            type: "pubkey";
+          },
+          {
+            name: "lockupEscrowAccountBefore";
+            docs: ["Previous escrow account."];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "lockupEscrowAccount";
+            docs: ["New escrow account."];
+            type: "pubkey";
+          },
+          {
+            name: "tokenlockAccount";
+            docs: ["Tokenlock account of the escrow."];
+            type: "pubkey";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who set the escrow account."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6002;
-      name: "mintExceedsMaxTotalSupply";
-      msg: "Cannot mint more than max total supply";
+      name: "maxTotalSupplySetEvent";
+      docs: ["Emitted when the max total supply is changed."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            docs: ["Security token mint."];
+            type: "pubkey";
+          },
+          {
+            name: "maxTotalSupplyBefore";
+            docs: ["Previous max total supply."];
+            type: "u64";
+          },
+          {
+            name: "maxTotalSupply";
+            docs: ["New max total supply."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who changed the max total supply."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6003;
-      name: "incorrectTokenlockAccount";
-      msg: "Wrong tokenlock account";
+      name: "proposal";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "accessControl";
+            type: "pubkey";
+          },
+          {
+            name: "id";
+            type: "u64";
+          },
+          {
+            name: "proposer";
+            type: "pubkey";
+          },
+          {
+            name: "action";
+            type: {
+              defined: {
+                name: "proposalAction";
+              };
+            };
+          },
+          {
+            name: "reason";
+            type: "string";
+          },
+          {
+            name: "approvals";
+            type: {
+              vec: "pubkey";
+            };
+          },
+          {
+            name: "expiresAt";
+            type: "i64";
+          }
+        ];
+      };
     },
     {
-      code: 6004;
-      name: "mismatchedEscrowAccount";
-      msg: "Mismatched escrow account";
+      name: "proposalAction";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "mintSecurities";
+            fields: [
+              {
+                name: "destinationAccount";
+                type: "pubkey";
+              },
+              {
+                name: "amount";
+                type: "u64";
+              }
+            ];
+          },
+          {
+            name: "burnSecurities";
+            fields: [
+              {
+                name: "targetAccount";
+                type: "pubkey";
+              },
+              {
+                name: "amount";
+                type: "u64";
+              }
+            ];
+          },
+          {
+            name: "forceTransferBetween";
+            fields: [
+              {
+                name: "sourceAccount";
+                type: "pubkey";
+              },
+              {
+                name: "destinationAccount";
+                type: "pubkey";
+              },
+              {
+                name: "amount";
+                type: "u64";
+              }
+            ];
+          },
+          {
+            name: "setMaxTotalSupply";
+            fields: [
+              {
+                name: "maxTotalSupply";
+                type: "u64";
+              }
+            ];
+          }
+        ];
+      };
+    },
+    {
+      name: "proposalActionType";
+      repr: {
+        kind: "rust";
+      };
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "mintSecurities";
+          },
+          {
+            name: "burnSecurities";
+          },
+          {
+            name: "forceTransferBetween";
+          },
+          {
+            name: "setMaxTotalSupply";
+          }
+        ];
+      };
+    },
+    {
+      name: "proposalApprovedEvent";
+      docs: ["Emitted when a proposal is approved."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "proposal";
+            docs: ["Proposal account."];
+            type: "pubkey";
+          },
+          {
+            name: "proposalId";
+            docs: ["Id of the proposal."];
+            type: "u64";
+          },
+          {
+            name: "approvals";
+            docs: ["Number of approvals including this one."];
+            type: "u8";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who approved the proposal."];
+            type: "pubkey";
+          }
+        ];
+      };
+    },
+    {
+      name: "proposalCancelledEvent";
+      docs: ["Emitted when a proposal is cancelled and closed."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "proposal";
+            docs: ["Proposal account."];
+            type: "pubkey";
+          },
+          {
+            name: "proposalId";
+            docs: ["Id of the proposal."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Proposer or Contract Admin who cancelled the proposal."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6005;
-      name: "cantBurnSecuritiesWithinLockup";
-      msg: "Cannot burn securities within lockup; cancel the lockup first";
+      name: "proposalCreatedEvent";
+      docs: ["Emitted when a proposal is created."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "proposal";
+            docs: ["Proposal account."];
+            type: "pubkey";
+          },
+          {
+            name: "proposalId";
+            docs: ["Id of the proposal."];
+            type: "u64";
+          },
+          {
+            name: "action";
+            docs: ["Proposed action."];
+            type: {
+              defined: {
+                name: "proposalAction";
+              };
+            };
+          },
+          {
+            name: "reason";
+            docs: ["Reason of the proposed action, empty if not given."];
+            type: "string";
+          },
+          {
+            name: "expiresAt";
+            docs: [
+              "Unix timestamp from which the proposal can no longer be approved or executed."
+            ];
+            type: "i64";
+          },
+          {
+            name: "proposer";
+            docs: ["Admin who created the proposal."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6006;
-      name: "cantForceTransferBetweenLockup";
-      msg: "Cannot force transfer between lockup accounts";
+      name: "proposalExecutedEvent";
+      docs: ["Emitted when an approved proposal is executed and closed."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "proposal";
+            docs: ["Proposal account."];
+            type: "pubkey";
+          },
+          {
+            name: "proposalId";
+            docs: ["Id of the proposal."];
+            type: "u64";
+          },
+          {
+            name: "approvals";
+            docs: ["Number of approvals of the proposal."];
+            type: "u8";
+          },
+          {
+            name: "executor";
+            docs: ["Wallet which executed the proposal."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6007;
-      name: "newMaxTotalSupplyMustExceedCurrentTotalSupply";
-      msg: "New max total supply must exceed current total supply";
+      name: "securitiesBurnedEvent";
+      docs: ["Emitted when securities are burned."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            docs: ["Security token mint."];
+            type: "pubkey";
+          },
+          {
+            name: "targetAccount";
+            docs: ["Token account the securities are burned from."];
+            type: "pubkey";
+          },
+          {
+            name: "targetAuthority";
+            docs: ["Owner of the target token account."];
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            docs: ["Amount of burned securities."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who burned the securities."];
+            type: "pubkey";
+          },
+          {
+            name: "reason";
+            docs: ["Reason of the action, empty if not given."];
+            type: "string";
+          }
+        ];
+      };
     },
     {
-      code: 6008;
-      name: "cannotFreezeLockupEscrowAccount";
-      msg: "Cannot freeze lockup escrow account";
+      name: "securitiesMintedEvent";
+      docs: ["Emitted when securities are minted."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            docs: ["Security token mint."];
+            type: "pubkey";
+          },
+          {
+            name: "destinationAccount";
+            docs: ["Token account the securities are minted to."];
+            type: "pubkey";
+          },
+          {
+            name: "destinationAuthority";
+            docs: ["Owner of the destination token account."];
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            docs: ["Amount of minted securities."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who minted the securities."];
+            type: "pubkey";
+          },
+          {
+            name: "reason";
+            docs: ["Reason of the action, empty if not given."];
+            type: "string";
+          }
+        ];
+      };
     },
     {
-      code: 6009;
-      name: "valueUnchanged";
-      msg: "The provided value is already set. No changes were made";
-    }
-  ];
-  types: [
+      name: "tokenMetadataField";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "key";
+            type: "string";
+          },
+          {
+            name: "value";
+            type: "string";
+          }
+        ];
+      };
+    },
     {
-      name: "accessControl";
+      name: "tokenMetadataUpdatedEvent";
+      docs: ["Emitted when the mint metadata is updated."];
       type: {
         kind: "struct";
         fields: [
           {
             name: "mint";
+            docs: ["Security token mint."];
             type: "pubkey";
           },
           {
-            name: "authority";
-            type: "pubkey";
+            name: "name";
+            docs: ["New name, if changed."];
+            type: {
+              option: "string";
+            };
           },
           {
-            name: "maxTotalSupply";
-            type: "u64";
+            name: "symbol";
+            docs: ["New symbol, if changed."];
+            type: {
+              option: "string";
+            };
           },
           {
-            name: "lockupEscrowAccount";
+            name: "uri";
+            docs: ["New uri, if changed."];
             type: {
-              option: "pubkey";
+              option: "string";
+            };
+          },
+          {
+            name: "additionalMetadata";
+            docs: ["Changed additional key/value pairs."];
+            type: {
+              vec: {
+                defined: {
+                  name: "tokenMetadataField";
+                };
+              };
             };
+          },
+          {
+            name: "authority";
+            docs: ["Admin who updated the metadata."];
+            type: "pubkey";
           }
         ];
       };
     },
     {
-      name: "initializeAccessControlArgs";
+      name: "updateTokenMetadataArgs";
       type: {
         kind: "struct";
         fields: [
-          {
-            name: "decimals";
-            type: "u8";
-          },
           {
             name: "name";
-            type: "string";
+            type: {
+              option: "string";
+            };
           },
           {
             name: "symbol";
-            type: "string";
+            type: {
+              option: "string";
+            };
           },
           {
             name: "uri";
-            type: "string";
+            type: {
+              option: "string";
+            };
           },
           {
-            name: "hookProgramId";
+            name: "additionalMetadata";
+            type: {
+              vec: {
+                defined: {
+                  name: "tokenMetadataField";
+                };
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "walletFrozenEvent";
+      docs: ["Emitted when a wallet is frozen."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            docs: ["Security token mint."];
             type: "pubkey";
           },
           {
-            name: "maxTotalSupply";
-            type: "u64";
+            name: "targetAccount";
+            docs: ["Frozen token account."];
+            type: "pubkey";
+          },
+          {
+            name: "targetAuthority";
+            docs: ["Owner of the frozen token account."];
+            type: "pubkey";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who froze the wallet."];
+            type: "pubkey";
+          },
+          {
+            name: "reason";
+            docs: ["Reason of the action, empty if not given."];
+            type: "string";
           }
         ];
       };
@@ -1108,6 +2993,107 @@ export type AccessControl = {
           }
         ];
       };
+    },
+    {
+      name: "walletRoleRevokedEvent";
+      docs: [
+        "Emitted when a wallet role is revoked and its account is closed."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "walletRole";
+            docs: ["Closed wallet role account."];
+            type: "pubkey";
+          },
+          {
+            name: "wallet";
+            docs: ["Wallet the roles belonged to."];
+            type: "pubkey";
+          },
+          {
+            name: "role";
+            docs: ["Roles of the wallet before the revocation."];
+            type: "u8";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who revoked the roles."];
+            type: "pubkey";
+          }
+        ];
+      };
+    },
+    {
+      name: "walletRoleUpdatedEvent";
+      docs: [
+        "Emitted when a role is granted to a new wallet or the roles of a wallet are changed."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "walletRole";
+            docs: ["Wallet role account."];
+            type: "pubkey";
+          },
+          {
+            name: "wallet";
+            docs: ["Wallet the roles belong to."];
+            type: "pubkey";
+          },
+          {
+            name: "roleBefore";
+            docs: ["Previous roles of the wallet, 0 for a new wallet role."];
+            type: "u8";
+          },
+          {
+            name: "role";
+            docs: ["New roles of the wallet."];
+            type: "u8";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who changed the roles."];
+            type: "pubkey";
+          }
+        ];
+      };
+    },
+    {
+      name: "walletThawedEvent";
+      docs: ["Emitted when a wallet is thawed."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            docs: ["Security token mint."];
+            type: "pubkey";
+          },
+          {
+            name: "targetAccount";
+            docs: ["Thawed token account."];
+            type: "pubkey";
+          },
+          {
+            name: "targetAuthority";
+            docs: ["Owner of the thawed token account."];
+            type: "pubkey";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who thawed the wallet."];
+            type: "pubkey";
+          },
+          {
+            name: "reason";
+            docs: ["Reason of the action, empty if not given."];
+            type: "string";
+          }
+        ];
+      };
     }
   ];
 };
@@ -411,6 +411,38 @@ export type Dividends = {
             type: {
               option: "pubkey";
             };
+          },
+          {
+            name: "pendingAuthority";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "contractAdminCount";
+            type: "u64";
+          },
+          {
+            name: "reserveAdminCount";
+            type: "u64";
+          },
+          {
+            name: "walletsAdminCount";
+            type: "u64";
+          },
+          {
+            name: "transferAdminCount";
+            type: "u64";
+          },
+          {
+            name: "approvalThresholds";
+            type: {
+              array: ["u8", 4];
+            };
+          },
+          {
+            name: "proposalsCount";
+            type: "u64";
           }
         ];
       };
@@ -5,7 +5,7 @@
  * IDL can be found at `target/idl/tokenlock.json`.
  */
 export type Tokenlock = {
-  address: "7CN3iHcRimZRa97M38cyMQAF68ecQYDqHfCUgBeSARG2";
+  address: "tokenlockaccountsidconst";
   metadata: {
     name: "tokenlock";
     version: "0.1.1";
@@ -62,12 +62,31 @@ export type Tokenlock = {
         },
         {
           name: "securityAssociatedAccountFrom";
+          writable: true;
         },
         {
           name: "securityAssociatedAccountTo";
         },
         {
           name: "transferRule";
+        },
+        {
+          name: "transferRestrictionGroupFrom";
+        },
+        {
+          name: "transferRestrictionGroupTo";
+        },
+        {
+          name: "transferRestrictionHolderFrom";
+        },
+        {
+          name: "transferRestrictionHolderTo";
+        },
+        {
+          name: "transferRuleFromGroupToAny";
+        },
+        {
+          name: "transferRuleFromAnyToGroup";
         }
       ];
       args: [
@@ -348,12 +367,31 @@ export type Tokenlock = {
         },
         {
           name: "securityAssociatedAccountFrom";
+          writable: true;
         },
         {
           name: "securityAssociatedAccountTo";
         },
         {
           name: "transferRule";
+        },
+        {
+          name: "transferRestrictionGroupFrom";
+        },
+        {
+          name: "transferRestrictionGroupTo";
+        },
+        {
+          name: "transferRestrictionHolderFrom";
+        },
+        {
+          name: "transferRestrictionHolderTo";
+        },
+        {
+          name: "transferRuleFromGroupToAny";
+        },
+        {
+          name: "transferRuleFromAnyToGroup";
         }
       ];
       args: [
@@ -406,12 +444,31 @@ export type Tokenlock = {
         },
         {
           name: "securityAssociatedAccountFrom";
+          writable: true;
         },
         {
           name: "securityAssociatedAccountTo";
         },
         {
           name: "transferRule";
+        },
+        {
+          name: "transferRestrictionGroupFrom";
+        },
+        {
+          name: "transferRestrictionGroupTo";
+        },
+        {
+          name: "transferRestrictionHolderFrom";
+        },
+        {
+          name: "transferRestrictionHolderTo";
+        },
+        {
+          name: "transferRuleFromGroupToAny";
+        },
+        {
+          name: "transferRuleFromAnyToGroup";
         }
       ];
       args: [
@@ -663,6 +720,38 @@ export type Tokenlock = {
             type: {
               option: "pubkey";
             };
+          },
+          {
+            name: "pendingAuthority";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "contractAdminCount";
+            type: "u64";
+          },
+          {
+            name: "reserveAdminCount";
+            type: "u64";
+          },
+          {
+            name: "walletsAdminCount";
+            type: "u64";
+          },
+          {
+            name: "transferAdminCount";
+            type: "u64";
+          },
+          {
+            name: "approvalThresholds";
+            type: {
+              array: ["u8", 4];
+            };
+          },
+          {
+            name: "proposalsCount";
+            type: "u64";
           }
         ];
       };
@@ -849,6 +938,72 @@ export type Tokenlock = {
             type: {
               option: "pubkey";
             };
+          },
+          {
+            name: "currentHoldersOfRecordCount";
+            type: "u64";
+          },
+          {
+            name: "holdersOfRecordEnforced";
+            type: "bool";
+          },
+          {
+            name: "defaultTransferRule";
+            type: {
+              option: {
+                defined: {
+                  name: "transferRule";
+                };
+              };
+            };
+          },
+          {
+            name: "pausedFrom";
+            type: "u64";
+          },
+          {
+            name: "pausedUntil";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "transferRule";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "transferRestrictionData";
+            type: "pubkey";
+          },
+          {
+            name: "transferGroupIdFrom";
+            type: "u64";
+          },
+          {
+            name: "transferGroupIdTo";
+            type: "u64";
+          },
+          {
+            name: "lockedUntil";
+            type: "u64";
+          },
+          {
+            name: "minAmount";
+            type: "u64";
+          },
+          {
+            name: "maxAmount";
+            type: "u64";
+          },
+          {
+            name: "lotSize";
+            type: "u64";
+          },
+          {
+            name: "lockedAfter";
+            type: "u64";
           }
         ];
       };
@@ -13,6 +13,68 @@ export type TransferRestrictions = {
     description: "Transfer restrictions for Solana tokens";
   };
   instructions: [
+    {
+      name: "detectTransferRestriction";
+      discriminator: [151, 28, 141, 250, 88, 74, 27, 74];
+      accounts: [
+        {
+          name: "sourceAccount";
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "destinationAccount";
+        },
+        {
+          name: "transferRestrictionData";
+        },
+        {
+          name: "securityAssociatedAccountFrom";
+        },
+        {
+          name: "securityAssociatedAccountTo";
+        },
+        {
+          name: "transferRule";
+        },
+        {
+          name: "transferRestrictionHolderFrom";
+        },
+        {
+          name: "transferRestrictionHolderTo";
+        },
+        {
+          name: "transferRestrictionGroupTo";
+        },
+        {
+          name: "transferRestrictionGroupFrom";
+        },
+        {
+          name: "holderGroupFrom";
+        },
+        {
+          name: "holderGroupTo";
+        },
+        {
+          name: "transferRuleFromGroupToAny";
+        },
+        {
+          name: "transferRuleFromAnyToGroup";
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        }
+      ];
+      returns: {
+        defined: {
+          name: "transferRestrictionReason";
+        };
+      };
+    },
     {
       name: "enforceTransferRestrictions";
       discriminator: [77, 50, 36, 109, 250, 175, 122, 22];
@@ -43,6 +105,7 @@ export type TransferRestrictions = {
         },
         {
           name: "securityAssociatedAccountFrom";
+          writable: true;
           pda: {
             seeds: [
               {
@@ -73,6 +136,80 @@ export type TransferRestrictions = {
         },
         {
           name: "transferRule";
+          docs: [
+            "and routes covered only by a wildcard or the default transfer rule"
+          ];
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114];
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData";
+              },
+              {
+                kind: "account";
+                path: "security_associated_account_from.group";
+                account: "securityAssociatedAccount";
+              },
+              {
+                kind: "account";
+                path: "security_associated_account_to.group";
+                account: "securityAssociatedAccount";
+              }
+            ];
+          };
+        },
+        {
+          name: "transferRestrictionGroupFrom";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 103];
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData";
+              },
+              {
+                kind: "account";
+                path: "security_associated_account_from.group";
+                account: "securityAssociatedAccount";
+              }
+            ];
+          };
+        },
+        {
+          name: "transferRestrictionGroupTo";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 103];
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData";
+              },
+              {
+                kind: "account";
+                path: "security_associated_account_to.group";
+                account: "securityAssociatedAccount";
+              }
+            ];
+          };
+        },
+        {
+          name: "transferRestrictionHolderFrom";
+        },
+        {
+          name: "transferRestrictionHolderTo";
+        },
+        {
+          name: "transferRuleFromGroupToAny";
           pda: {
             seeds: [
               {
@@ -88,6 +225,29 @@ export type TransferRestrictions = {
                 path: "security_associated_account_from.group";
                 account: "securityAssociatedAccount";
               },
+              {
+                kind: "const";
+                value: [255, 255, 255, 255, 255, 255, 255, 255];
+              }
+            ];
+          };
+        },
+        {
+          name: "transferRuleFromAnyToGroup";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114];
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData";
+              },
+              {
+                kind: "const";
+                value: [255, 255, 255, 255, 255, 255, 255, 255];
+              },
               {
                 kind: "account";
                 path: "security_associated_account_to.group";
@@ -95,9 +255,22 @@ export type TransferRestrictions = {
               }
             ];
           };
+        },
+        {
+          name: "authority";
+          docs: [
+            "Owner of the source account, records the amount in the sender velocity window when present"
+          ];
+          signer: true;
+          optional: true;
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
         }
       ];
-      args: [];
     },
     {
       name: "executeTransaction";
@@ -153,15 +326,48 @@ export type TransferRestrictions = {
         },
         {
           name: "transferRestrictionData";
+          writable: true;
         },
         {
           name: "securityAssociatedAccountFrom";
+          writable: true;
         },
         {
           name: "securityAssociatedAccountTo";
+          writable: true;
         },
         {
           name: "transferRule";
+        },
+        {
+          name: "transferRestrictionHolderFrom";
+          writable: true;
+        },
+        {
+          name: "transferRestrictionHolderTo";
+          writable: true;
+        },
+        {
+          name: "transferRestrictionGroupTo";
+          writable: true;
+        },
+        {
+          name: "transferRestrictionGroupFrom";
+          writable: true;
+        },
+        {
+          name: "holderGroupFrom";
+          writable: true;
+        },
+        {
+          name: "holderGroupTo";
+          writable: true;
+        },
+        {
+          name: "transferRuleFromGroupToAny";
+        },
+        {
+          name: "transferRuleFromAnyToGroup";
         }
       ];
       args: [
@@ -350,6 +556,7 @@ export type TransferRestrictions = {
         },
         {
           name: "transferRestrictionData";
+          writable: true;
           pda: {
             seeds: [
               {
@@ -598,6 +805,56 @@ export type TransferRestrictions = {
         }
       ];
     },
+    {
+      name: "initializeTransferRestrictionHolderFromReference";
+      discriminator: [246, 144, 6, 113, 212, 208, 87, 207];
+      accounts: [
+        {
+          name: "transferRestrictionHolder";
+          writable: true;
+        },
+        {
+          name: "transferRestrictionData";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 100];
+              },
+              {
+                kind: "account";
+                path: "access_control_account.mint";
+                account: "accessControl";
+              }
+            ];
+          };
+        },
+        {
+          name: "accessControlAccount";
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [
+        {
+          name: "reference";
+          type: {
+            array: ["u8", 32];
+          };
+        }
+      ];
+    },
     {
       name: "initializeTransferRestrictionsData";
       discriminator: [214, 241, 131, 83, 138, 120, 171, 133];
@@ -675,28 +932,6 @@ export type TransferRestrictions = {
         {
           name: "transferRule";
           writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [116, 114];
-              },
-              {
-                kind: "account";
-                path: "transferRestrictionData";
-              },
-              {
-                kind: "account";
-                path: "transfer_restriction_group_from.id";
-                account: "transferRestrictionGroup";
-              },
-              {
-                kind: "account";
-                path: "transfer_restriction_group_to.id";
-                account: "transferRestrictionGroup";
-              }
-            ];
-          };
         },
         {
           name: "transferRestrictionData";
@@ -716,9 +951,11 @@ export type TransferRestrictions = {
         },
         {
           name: "transferRestrictionGroupFrom";
+          optional: true;
         },
         {
           name: "transferRestrictionGroupTo";
+          optional: true;
         },
         {
           name: "accessControlAccount";
@@ -744,23 +981,101 @@ export type TransferRestrictions = {
       ];
     },
     {
-      name: "pause";
-      discriminator: [211, 22, 221, 251, 74, 121, 193, 47];
+      name: "migrateAccount";
+      discriminator: [177, 228, 60, 125, 13, 116, 44, 84];
       accounts: [
         {
-          name: "securityMint";
+          name: "account";
+          docs: [
+            "older layout, the account type is checked by its discriminator"
+          ];
+          writable: true;
         },
         {
-          name: "transferRestrictionData";
+          name: "payer";
           writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [116, 114, 100];
-              },
-              {
-                kind: "account";
+          signer: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "migrateSecurityAssociatedAccount";
+      discriminator: [250, 177, 152, 15, 227, 161, 203, 170];
+      accounts: [
+        {
+          name: "securityAssociatedAccount";
+          docs: ["before the holder balances were tracked can be reallocated"];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [115, 97, 97];
+              },
+              {
+                kind: "account";
+                path: "tokenAccount";
+              }
+            ];
+          };
+        },
+        {
+          name: "holder";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "holderGroup";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "group";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "transferRestrictionData";
+          writable: true;
+        },
+        {
+          name: "tokenAccount";
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "pause";
+      discriminator: [211, 22, 221, 251, 74, 121, 193, 47];
+      accounts: [
+        {
+          name: "securityMint";
+        },
+        {
+          name: "transferRestrictionData";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 100];
+              },
+              {
+                kind: "account";
                 path: "securityMint";
               }
             ];
@@ -786,66 +1101,70 @@ export type TransferRestrictions = {
       ];
     },
     {
-      name: "revokeHolder";
-      discriminator: [250, 238, 38, 18, 138, 55, 227, 111];
+      name: "pauseGroup";
+      discriminator: [99, 116, 17, 67, 185, 65, 65, 118];
       accounts: [
         {
-          name: "holder";
-          writable: true;
+          name: "transferRestrictionData";
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [116, 114, 104];
-              },
-              {
-                kind: "account";
-                path: "transferRestrictionData";
+                value: [116, 114, 100];
               },
               {
                 kind: "account";
-                path: "holder.id";
-                account: "transferRestrictionHolder";
+                path: "mint";
               }
             ];
           };
         },
         {
-          name: "transferRestrictionData";
+          name: "mint";
+        },
+        {
+          name: "accessControlAccount";
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "group";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [116, 114, 100];
+                value: [116, 114, 103];
               },
               {
                 kind: "account";
-                path: "transfer_restriction_data.security_token_mint";
-                account: "transferRestrictionData";
+                path: "transferRestrictionData";
+              },
+              {
+                kind: "account";
+                path: "group.id";
+                account: "transferRestrictionGroup";
               }
             ];
           };
         },
-        {
-          name: "authorityWalletRole";
-          writable: true;
-        },
         {
           name: "payer";
           writable: true;
           signer: true;
-        },
+        }
+      ];
+      args: [
         {
-          name: "systemProgram";
-          address: "11111111111111111111111111111111";
+          name: "paused";
+          type: "bool";
         }
       ];
-      args: [];
     },
     {
-      name: "revokeHolderGroup";
-      discriminator: [33, 153, 183, 187, 204, 120, 164, 40];
+      name: "provisionInvestor";
+      discriminator: [40, 90, 118, 150, 123, 10, 108, 90];
       accounts: [
         {
           name: "holder";
@@ -861,9 +1180,8 @@ export type TransferRestrictions = {
                 path: "transferRestrictionData";
               },
               {
-                kind: "account";
-                path: "holder.id";
-                account: "transferRestrictionHolder";
+                kind: "arg";
+                path: "holderId";
               }
             ];
           };
@@ -890,24 +1208,24 @@ export type TransferRestrictions = {
           };
         },
         {
-          name: "transferRestrictionData";
+          name: "securityAssociatedAccount";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [116, 114, 100];
+                value: [115, 97, 97];
               },
               {
                 kind: "account";
-                path: "transfer_restriction_data.security_token_mint";
-                account: "transferRestrictionData";
+                path: "associatedTokenAccount";
               }
             ];
           };
         },
         {
           name: "group";
+          writable: true;
           pda: {
             seeds: [
               {
@@ -926,59 +1244,12 @@ export type TransferRestrictions = {
             ];
           };
         },
-        {
-          name: "authorityWalletRole";
-          writable: true;
-        },
-        {
-          name: "payer";
-          writable: true;
-          signer: true;
-        },
-        {
-          name: "systemProgram";
-          address: "11111111111111111111111111111111";
-        }
-      ];
-      args: [];
-    },
-    {
-      name: "revokeSecurityAssociatedAccount";
-      discriminator: [75, 206, 46, 31, 84, 165, 44, 66];
-      accounts: [
-        {
-          name: "securityAssociatedAccount";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [115, 97, 97];
-              },
-              {
-                kind: "account";
-                path: "associatedTokenAccount";
-              }
-            ];
-          };
-        },
-        {
-          name: "group";
-          writable: true;
-        },
-        {
-          name: "holder";
-          writable: true;
-        },
-        {
-          name: "holderGroup";
-          writable: true;
-        },
         {
           name: "securityToken";
         },
         {
           name: "transferRestrictionData";
+          writable: true;
           pda: {
             seeds: [
               {
@@ -1097,20 +1368,25 @@ export type TransferRestrictions = {
           address: "11111111111111111111111111111111";
         }
       ];
-      args: [];
+      args: [
+        {
+          name: "holderId";
+          type: "u64";
+        }
+      ];
     },
     {
-      name: "setAllowTransferRule";
-      discriminator: [4, 83, 246, 172, 106, 193, 31, 116];
+      name: "provisionInvestors";
+      discriminator: [29, 184, 43, 123, 175, 61, 83, 129];
       accounts: [
         {
-          name: "transferRule";
+          name: "group";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [116, 114];
+                value: [116, 114, 103];
               },
               {
                 kind: "account";
@@ -1118,19 +1394,18 @@ export type TransferRestrictions = {
               },
               {
                 kind: "account";
-                path: "transfer_restriction_group_from.id";
-                account: "transferRestrictionGroup";
-              },
-              {
-                kind: "account";
-                path: "transfer_restriction_group_to.id";
+                path: "group.id";
                 account: "transferRestrictionGroup";
               }
             ];
           };
         },
+        {
+          name: "securityToken";
+        },
         {
           name: "transferRestrictionData";
+          writable: true;
           pda: {
             seeds: [
               {
@@ -1139,21 +1414,11 @@ export type TransferRestrictions = {
               },
               {
                 kind: "account";
-                path: "access_control_account.mint";
-                account: "accessControl";
+                path: "securityToken";
               }
             ];
           };
         },
-        {
-          name: "transferRestrictionGroupFrom";
-        },
-        {
-          name: "transferRestrictionGroupTo";
-        },
-        {
-          name: "accessControlAccount";
-        },
         {
           name: "authorityWalletRole";
         },
@@ -1161,19 +1426,28 @@ export type TransferRestrictions = {
           name: "payer";
           writable: true;
           signer: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
         }
       ];
       args: [
         {
-          name: "lockedUntil";
-          type: "u64";
+          name: "holderIds";
+          type: {
+            vec: "u64";
+          };
         }
       ];
     },
     {
-      name: "setHolderGroupMax";
-      discriminator: [83, 33, 238, 145, 212, 216, 16, 197];
+      name: "renewWalletsValidity";
+      discriminator: [198, 0, 125, 227, 46, 130, 17, 163];
       accounts: [
+        {
+          name: "securityToken";
+        },
         {
           name: "transferRestrictionData";
           pda: {
@@ -1184,28 +1458,39 @@ export type TransferRestrictions = {
               },
               {
                 kind: "account";
-                path: "mint";
+                path: "securityToken";
               }
             ];
           };
         },
         {
-          name: "mint";
+          name: "authorityWalletRole";
         },
         {
-          name: "accessControlAccount";
-        },
+          name: "payer";
+          writable: true;
+          signer: true;
+        }
+      ];
+      args: [
         {
-          name: "authorityWalletRole";
-        },
+          name: "validUntil";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "revokeHolder";
+      discriminator: [250, 238, 38, 18, 138, 55, 227, 111];
+      accounts: [
         {
-          name: "group";
+          name: "holder";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [116, 114, 103];
+                value: [116, 114, 104];
               },
               {
                 kind: "account";
@@ -1213,29 +1498,12 @@ export type TransferRestrictions = {
               },
               {
                 kind: "account";
-                path: "group.id";
-                account: "transferRestrictionGroup";
+                path: "holder.id";
+                account: "transferRestrictionHolder";
               }
             ];
           };
         },
-        {
-          name: "payer";
-          writable: true;
-          signer: true;
-        }
-      ];
-      args: [
-        {
-          name: "holderGroupMax";
-          type: "u64";
-        }
-      ];
-    },
-    {
-      name: "setHolderMax";
-      discriminator: [254, 104, 250, 53, 13, 151, 2, 161];
-      accounts: [
         {
           name: "transferRestrictionData";
           writable: true;
@@ -1247,121 +1515,81 @@ export type TransferRestrictions = {
               },
               {
                 kind: "account";
-                path: "mint";
+                path: "transfer_restriction_data.security_token_mint";
+                account: "transferRestrictionData";
               }
             ];
           };
         },
-        {
-          name: "mint";
-        },
-        {
-          name: "accessControlAccount";
-        },
         {
           name: "authorityWalletRole";
+          writable: true;
         },
         {
           name: "payer";
           writable: true;
           signer: true;
-        }
-      ];
-      args: [
+        },
         {
-          name: "holderMax";
-          type: "u64";
+          name: "rentReceiver";
+          writable: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
         }
       ];
+      args: [];
     },
     {
-      name: "setLockupEscrowAccount";
-      discriminator: [134, 172, 249, 223, 25, 118, 55, 93];
+      name: "revokeHolderGroup";
+      discriminator: [33, 153, 183, 187, 204, 120, 164, 40];
       accounts: [
         {
-          name: "transferRestrictionData";
+          name: "holder";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [116, 114, 100];
+                value: [116, 114, 104];
               },
               {
                 kind: "account";
-                path: "mint";
-              }
-            ];
-          };
-        },
-        {
-          name: "escrowSecurityAssociatedAccount";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [115, 97, 97];
+                path: "transferRestrictionData";
               },
               {
                 kind: "account";
-                path: "escrowAccount";
+                path: "holder.id";
+                account: "transferRestrictionHolder";
               }
             ];
           };
         },
         {
-          name: "mint";
-        },
-        {
-          name: "accessControlAccount";
-        },
-        {
-          name: "authorityWalletRole";
-        },
-        {
-          name: "escrowAccount";
-        },
-        {
-          name: "tokenlockAccount";
-        },
-        {
-          name: "payer";
-          writable: true;
-          signer: true;
-        },
-        {
-          name: "systemProgram";
-          address: "11111111111111111111111111111111";
-        }
-      ];
-      args: [];
-    },
-    {
-      name: "updateWalletGroup";
-      discriminator: [225, 33, 252, 93, 186, 129, 24, 241];
-      accounts: [
-        {
-          name: "securityAssociatedAccount";
+          name: "holderGroup";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [115, 97, 97];
+                value: [116, 114, 104, 103];
               },
               {
                 kind: "account";
-                path: "userAssociatedTokenAccount";
+                path: "holder";
+              },
+              {
+                kind: "account";
+                path: "group.id";
+                account: "transferRestrictionGroup";
               }
             ];
           };
         },
-        {
-          name: "securityToken";
-        },
         {
           name: "transferRestrictionData";
+          writable: true;
           pda: {
             seeds: [
               {
@@ -1370,14 +1598,14 @@ export type TransferRestrictions = {
               },
               {
                 kind: "account";
-                path: "securityToken";
+                path: "transfer_restriction_data.security_token_mint";
+                account: "transferRestrictionData";
               }
             ];
           };
         },
         {
-          name: "transferRestrictionGroupCurrent";
-          writable: true;
+          name: "group";
           pda: {
             seeds: [
               {
@@ -1390,85 +1618,88 @@ export type TransferRestrictions = {
               },
               {
                 kind: "account";
-                path: "transfer_restriction_group_current.id";
+                path: "group.id";
                 account: "transferRestrictionGroup";
               }
             ];
           };
         },
         {
-          name: "transferRestrictionGroupNew";
+          name: "authorityWalletRole";
           writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [116, 114, 103];
-              },
-              {
-                kind: "account";
-                path: "transferRestrictionData";
-              },
-              {
-                kind: "account";
-                path: "transfer_restriction_group_new.id";
-                account: "transferRestrictionGroup";
-              }
-            ];
-          };
         },
         {
-          name: "holderGroupCurrent";
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "rentReceiver";
+          writable: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "revokeSecurityAssociatedAccount";
+      discriminator: [75, 206, 46, 31, 84, 165, 44, 66];
+      accounts: [
+        {
+          name: "securityAssociatedAccount";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [116, 114, 104, 103];
-              },
-              {
-                kind: "account";
-                path: "security_associated_account.holder";
-                account: "securityAssociatedAccount";
+                value: [115, 97, 97];
               },
               {
                 kind: "account";
-                path: "security_associated_account.group";
-                account: "securityAssociatedAccount";
+                path: "associatedTokenAccount";
               }
             ];
           };
         },
         {
-          name: "holderGroupNew";
+          name: "group";
+          writable: true;
+        },
+        {
+          name: "holder";
+          writable: true;
+        },
+        {
+          name: "holderGroup";
+          writable: true;
+        },
+        {
+          name: "securityToken";
+        },
+        {
+          name: "transferRestrictionData";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [116, 114, 104, 103];
-              },
-              {
-                kind: "account";
-                path: "security_associated_account.holder";
-                account: "securityAssociatedAccount";
+                value: [116, 114, 100];
               },
               {
                 kind: "account";
-                path: "transfer_restriction_group_new.id";
-                account: "transferRestrictionGroup";
+                path: "securityToken";
               }
             ];
           };
         },
-        {
-          name: "authorityWalletRole";
-        },
         {
           name: "userWallet";
         },
         {
-          name: "userAssociatedTokenAccount";
+          name: "associatedTokenAccount";
           pda: {
             seeds: [
               {
@@ -1556,350 +1787,3436 @@ export type TransferRestrictions = {
             };
           };
         },
+        {
+          name: "authorityWalletRole";
+        },
         {
           name: "payer";
           writable: true;
           signer: true;
         },
+        {
+          name: "rentReceiver";
+          writable: true;
+        },
         {
           name: "systemProgram";
           address: "11111111111111111111111111111111";
         }
       ];
       args: [];
-    }
-  ];
-  accounts: [
+    },
     {
-      name: "accessControl";
-      discriminator: [147, 81, 178, 92, 223, 66, 181, 132];
+      name: "schedulePause";
+      discriminator: [50, 100, 176, 220, 195, 43, 103, 52];
+      accounts: [
+        {
+          name: "securityMint";
+        },
+        {
+          name: "transferRestrictionData";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 100];
+              },
+              {
+                kind: "account";
+                path: "securityMint";
+              }
+            ];
+          };
+        },
+        {
+          name: "accessControlAccount";
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        }
+      ];
+      args: [
+        {
+          name: "pausedFrom";
+          type: "u64";
+        },
+        {
+          name: "pausedUntil";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "setAllowTransferRule";
+      discriminator: [4, 83, 246, 172, 106, 193, 31, 116];
+      accounts: [
+        {
+          name: "transferRule";
+          docs: ["with an older layout can be reallocated"];
+          writable: true;
+        },
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 100];
+              },
+              {
+                kind: "account";
+                path: "access_control_account.mint";
+                account: "accessControl";
+              }
+            ];
+          };
+        },
+        {
+          name: "transferRestrictionGroupFrom";
+          optional: true;
+        },
+        {
+          name: "transferRestrictionGroupTo";
+          optional: true;
+        },
+        {
+          name: "accessControlAccount";
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [
+        {
+          name: "lockedUntil";
+          type: "u64";
+        },
+        {
+          name: "lockedAfter";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "setDefaultTransferRule";
+      discriminator: [167, 72, 240, 54, 39, 9, 214, 251];
+      accounts: [
+        {
+          name: "transferRestrictionData";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 100];
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "accessControlAccount";
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        }
+      ];
+      args: [
+        {
+          name: "lockedUntil";
+          type: "u64";
+        },
+        {
+          name: "lockedAfter";
+          type: "u64";
+        },
+        {
+          name: "minAmount";
+          type: "u64";
+        },
+        {
+          name: "maxAmount";
+          type: "u64";
+        },
+        {
+          name: "lotSize";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "setGroupLockup";
+      discriminator: [160, 38, 34, 22, 169, 76, 56, 70];
+      accounts: [
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 100];
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "accessControlAccount";
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "group";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 103];
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData";
+              },
+              {
+                kind: "account";
+                path: "group.id";
+                account: "transferRestrictionGroup";
+              }
+            ];
+          };
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        }
+      ];
+      args: [
+        {
+          name: "lockedUntil";
+          type: "u64";
+        },
+        {
+          name: "frozen";
+          type: "bool";
+        }
+      ];
+    },
+    {
+      name: "setGroupMaxBalance";
+      discriminator: [75, 11, 158, 1, 181, 245, 51, 101];
+      accounts: [
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 100];
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "accessControlAccount";
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "group";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 103];
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData";
+              },
+              {
+                kind: "account";
+                path: "group.id";
+                account: "transferRestrictionGroup";
+              }
+            ];
+          };
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        }
+      ];
+      args: [
+        {
+          name: "maxBalance";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "setGroupMetadata";
+      discriminator: [95, 99, 144, 96, 159, 14, 111, 104];
+      accounts: [
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 100];
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "accessControlAccount";
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "group";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 103];
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData";
+              },
+              {
+                kind: "account";
+                path: "group.id";
+                account: "transferRestrictionGroup";
+              }
+            ];
+          };
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        }
+      ];
+      args: [
+        {
+          name: "name";
+          type: "string";
+        },
+        {
+          name: "jurisdiction";
+          type: "string";
+        },
+        {
+          name: "flags";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "setGroupVelocityLimit";
+      discriminator: [223, 123, 221, 182, 124, 224, 178, 64];
+      accounts: [
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 100];
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "accessControlAccount";
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "group";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 103];
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData";
+              },
+              {
+                kind: "account";
+                path: "group.id";
+                account: "transferRestrictionGroup";
+              }
+            ];
+          };
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        }
+      ];
+      args: [
+        {
+          name: "velocityLimit";
+          type: "u64";
+        },
+        {
+          name: "velocityPeriod";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "setHolderActive";
+      discriminator: [213, 201, 231, 105, 168, 36, 5, 153];
+      accounts: [
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 100];
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "accessControlAccount";
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "holder";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 104];
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData";
+              },
+              {
+                kind: "account";
+                path: "holder.id";
+                account: "transferRestrictionHolder";
+              }
+            ];
+          };
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        }
+      ];
+      args: [
+        {
+          name: "active";
+          type: "bool";
+        }
+      ];
+    },
+    {
+      name: "setHolderGroupMax";
+      discriminator: [83, 33, 238, 145, 212, 216, 16, 197];
+      accounts: [
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 100];
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "accessControlAccount";
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "group";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 103];
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData";
+              },
+              {
+                kind: "account";
+                path: "group.id";
+                account: "transferRestrictionGroup";
+              }
+            ];
+          };
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        }
+      ];
+      args: [
+        {
+          name: "holderGroupMax";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "setHolderMax";
+      discriminator: [254, 104, 250, 53, 13, 151, 2, 161];
+      accounts: [
+        {
+          name: "transferRestrictionData";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 100];
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "accessControlAccount";
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        }
+      ];
+      args: [
+        {
+          name: "holderMax";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "setHolderMaxBalance";
+      discriminator: [134, 215, 157, 187, 143, 187, 251, 93];
+      accounts: [
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 100];
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "accessControlAccount";
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "holder";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 104];
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData";
+              },
+              {
+                kind: "account";
+                path: "holder.id";
+                account: "transferRestrictionHolder";
+              }
+            ];
+          };
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        }
+      ];
+      args: [
+        {
+          name: "maxBalance";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "setHoldersOfRecordEnforcement";
+      discriminator: [112, 150, 57, 26, 18, 33, 207, 117];
+      accounts: [
+        {
+          name: "transferRestrictionData";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 100];
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "accessControlAccount";
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        }
+      ];
+      args: [
+        {
+          name: "enforced";
+          type: "bool";
+        }
+      ];
+    },
+    {
+      name: "setLockupEscrowAccount";
+      discriminator: [134, 172, 249, 223, 25, 118, 55, 93];
+      accounts: [
+        {
+          name: "transferRestrictionData";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 100];
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+          };
+        },
+        {
+          name: "escrowSecurityAssociatedAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [115, 97, 97];
+              },
+              {
+                kind: "account";
+                path: "escrowAccount";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "accessControlAccount";
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "escrowAccount";
+        },
+        {
+          name: "tokenlockAccount";
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "setTransferRuleAmountLimits";
+      discriminator: [26, 151, 24, 164, 8, 67, 74, 157];
+      accounts: [
+        {
+          name: "transferRule";
+          docs: ["before the amount limits were introduced can be reallocated"];
+          writable: true;
+        },
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 100];
+              },
+              {
+                kind: "account";
+                path: "access_control_account.mint";
+                account: "accessControl";
+              }
+            ];
+          };
+        },
+        {
+          name: "transferRestrictionGroupFrom";
+          optional: true;
+        },
+        {
+          name: "transferRestrictionGroupTo";
+          optional: true;
+        },
+        {
+          name: "accessControlAccount";
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [
+        {
+          name: "minAmount";
+          type: "u64";
+        },
+        {
+          name: "maxAmount";
+          type: "u64";
+        },
+        {
+          name: "lotSize";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "syncHolderBalance";
+      discriminator: [39, 102, 158, 159, 230, 110, 128, 194];
+      accounts: [
+        {
+          name: "securityAssociatedAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [115, 97, 97];
+              },
+              {
+                kind: "account";
+                path: "tokenAccount";
+              }
+            ];
+          };
+        },
+        {
+          name: "holder";
+          writable: true;
+        },
+        {
+          name: "holderGroup";
+          writable: true;
+        },
+        {
+          name: "group";
+          writable: true;
+        },
+        {
+          name: "transferRestrictionData";
+          writable: true;
+        },
+        {
+          name: "tokenAccount";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "updateExtraAccountMetaList";
+      discriminator: [44, 125, 141, 226, 97, 179, 166, 96];
+      accounts: [
+        {
+          name: "extraMetasAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  101,
+                  120,
+                  116,
+                  114,
+                  97,
+                  45,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  45,
+                  109,
+                  101,
+                  116,
+                  97,
+                  115
+                ];
+              },
+              {
+                kind: "account";
+                path: "securityMint";
+              }
+            ];
+          };
+        },
+        {
+          name: "securityMint";
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "accessControl";
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "updateWalletGroup";
+      discriminator: [225, 33, 252, 93, 186, 129, 24, 241];
+      accounts: [
+        {
+          name: "securityAssociatedAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [115, 97, 97];
+              },
+              {
+                kind: "account";
+                path: "userAssociatedTokenAccount";
+              }
+            ];
+          };
+        },
+        {
+          name: "securityToken";
+        },
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 100];
+              },
+              {
+                kind: "account";
+                path: "securityToken";
+              }
+            ];
+          };
+        },
+        {
+          name: "transferRestrictionGroupCurrent";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 103];
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData";
+              },
+              {
+                kind: "account";
+                path: "transfer_restriction_group_current.id";
+                account: "transferRestrictionGroup";
+              }
+            ];
+          };
+        },
+        {
+          name: "transferRestrictionGroupNew";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 103];
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData";
+              },
+              {
+                kind: "account";
+                path: "transfer_restriction_group_new.id";
+                account: "transferRestrictionGroup";
+              }
+            ];
+          };
+        },
+        {
+          name: "holderGroupCurrent";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 104, 103];
+              },
+              {
+                kind: "account";
+                path: "security_associated_account.holder";
+                account: "securityAssociatedAccount";
+              },
+              {
+                kind: "account";
+                path: "security_associated_account.group";
+                account: "securityAssociatedAccount";
+              }
+            ];
+          };
+        },
+        {
+          name: "holderGroupNew";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 104, 103];
+              },
+              {
+                kind: "account";
+                path: "security_associated_account.holder";
+                account: "securityAssociatedAccount";
+              },
+              {
+                kind: "account";
+                path: "transfer_restriction_group_new.id";
+                account: "transferRestrictionGroup";
+              }
+            ];
+          };
+        },
+        {
+          name: "holder";
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "userWallet";
+        },
+        {
+          name: "userAssociatedTokenAccount";
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "userWallet";
+              },
+              {
+                kind: "const";
+                value: [
+                  6,
+                  221,
+                  246,
+                  225,
+                  238,
+                  117,
+                  143,
+                  222,
+                  24,
+                  66,
+                  93,
+                  188,
+                  228,
+                  108,
+                  205,
+                  218,
+                  182,
+                  26,
+                  252,
+                  77,
+                  131,
+                  185,
+                  13,
+                  39,
+                  254,
+                  189,
+                  249,
+                  40,
+                  216,
+                  161,
+                  139,
+                  252
+                ];
+              },
+              {
+                kind: "account";
+                path: "securityToken";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "updateWalletHolder";
+      discriminator: [141, 240, 243, 231, 210, 214, 210, 124];
+      accounts: [
+        {
+          name: "securityAssociatedAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [115, 97, 97];
+              },
+              {
+                kind: "account";
+                path: "userAssociatedTokenAccount";
+              }
+            ];
+          };
+        },
+        {
+          name: "securityToken";
+        },
+        {
+          name: "transferRestrictionData";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 100];
+              },
+              {
+                kind: "account";
+                path: "securityToken";
+              }
+            ];
+          };
+        },
+        {
+          name: "group";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 103];
+              },
+              {
+                kind: "account";
+                path: "transferRestrictionData";
+              },
+              {
+                kind: "account";
+                path: "group.id";
+                account: "transferRestrictionGroup";
+              }
+            ];
+          };
+        },
+        {
+          name: "holderCurrent";
+          writable: true;
+        },
+        {
+          name: "holderNew";
+          writable: true;
+        },
+        {
+          name: "holderGroupCurrent";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 104, 103];
+              },
+              {
+                kind: "account";
+                path: "holderCurrent";
+              },
+              {
+                kind: "account";
+                path: "group.id";
+                account: "transferRestrictionGroup";
+              }
+            ];
+          };
+        },
+        {
+          name: "holderGroupNew";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 104, 103];
+              },
+              {
+                kind: "account";
+                path: "holderNew";
+              },
+              {
+                kind: "account";
+                path: "group.id";
+                account: "transferRestrictionGroup";
+              }
+            ];
+          };
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "userWallet";
+        },
+        {
+          name: "userAssociatedTokenAccount";
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "userWallet";
+              },
+              {
+                kind: "const";
+                value: [
+                  6,
+                  221,
+                  246,
+                  225,
+                  238,
+                  117,
+                  143,
+                  222,
+                  24,
+                  66,
+                  93,
+                  188,
+                  228,
+                  108,
+                  205,
+                  218,
+                  182,
+                  26,
+                  252,
+                  77,
+                  131,
+                  185,
+                  13,
+                  39,
+                  254,
+                  189,
+                  249,
+                  40,
+                  216,
+                  161,
+                  139,
+                  252
+                ];
+              },
+              {
+                kind: "account";
+                path: "securityToken";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "updateWalletTransferBlocks";
+      discriminator: [78, 61, 93, 92, 16, 211, 123, 194];
+      accounts: [
+        {
+          name: "securityAssociatedAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [115, 97, 97];
+              },
+              {
+                kind: "account";
+                path: "userAssociatedTokenAccount";
+              }
+            ];
+          };
+        },
+        {
+          name: "securityToken";
+        },
+        {
+          name: "transferRestrictionData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [116, 114, 100];
+              },
+              {
+                kind: "account";
+                path: "securityToken";
+              }
+            ];
+          };
+        },
+        {
+          name: "authorityWalletRole";
+        },
+        {
+          name: "userWallet";
+        },
+        {
+          name: "userAssociatedTokenAccount";
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "userWallet";
+              },
+              {
+                kind: "const";
+                value: [
+                  6,
+                  221,
+                  246,
+                  225,
+                  238,
+                  117,
+                  143,
+                  222,
+                  24,
+                  66,
+                  93,
+                  188,
+                  228,
+                  108,
+                  205,
+                  218,
+                  182,
+                  26,
+                  252,
+                  77,
+                  131,
+                  185,
+                  13,
+                  39,
+                  254,
+                  189,
+                  249,
+                  40,
+                  216,
+                  161,
+                  139,
+                  252
+                ];
+              },
+              {
+                kind: "account";
+                path: "securityToken";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        }
+      ];
+      args: [
+        {
+          name: "sendBlocked";
+          type: "bool";
+        },
+        {
+          name: "receiveBlocked";
+          type: "bool";
+        }
+      ];
+    }
+  ];
+  accounts: [
+    {
+      name: "accessControl";
+      discriminator: [147, 81, 178, 92, 223, 66, 181, 132];
     },
     {
       name: "holderGroup";
       discriminator: [136, 231, 252, 48, 92, 187, 25, 164];
     },
     {
-      name: "securityAssociatedAccount";
-      discriminator: [68, 169, 137, 56, 226, 21, 69, 124];
+      name: "securityAssociatedAccount";
+      discriminator: [68, 169, 137, 56, 226, 21, 69, 124];
+    },
+    {
+      name: "transferRestrictionData";
+      discriminator: [166, 184, 205, 98, 165, 224, 174, 148];
+    },
+    {
+      name: "transferRestrictionGroup";
+      discriminator: [61, 120, 96, 96, 113, 210, 205, 223];
+    },
+    {
+      name: "transferRestrictionHolder";
+      discriminator: [196, 226, 112, 46, 157, 122, 48, 157];
+    },
+    {
+      name: "transferRule";
+      discriminator: [200, 231, 114, 91, 84, 241, 109, 172];
+    },
+    {
+      name: "walletRole";
+      discriminator: [219, 71, 35, 217, 102, 248, 173, 9];
+    }
+  ];
+  events: [
+    {
+      name: "allowTransferRuleSetEvent";
+      discriminator: [42, 203, 127, 63, 48, 175, 49, 55];
+    },
+    {
+      name: "defaultTransferRuleSetEvent";
+      discriminator: [133, 149, 210, 107, 62, 8, 203, 188];
+    },
+    {
+      name: "extraAccountMetaListInitializedEvent";
+      discriminator: [134, 89, 123, 108, 69, 201, 32, 215];
+    },
+    {
+      name: "extraAccountMetaListUpdatedEvent";
+      discriminator: [118, 92, 201, 76, 27, 191, 40, 231];
+    },
+    {
+      name: "groupInitializedEvent";
+      discriminator: [208, 204, 150, 161, 241, 156, 239, 165];
+    },
+    {
+      name: "groupLockupSetEvent";
+      discriminator: [94, 115, 163, 198, 96, 34, 144, 247];
+    },
+    {
+      name: "groupMaxBalanceSetEvent";
+      discriminator: [103, 56, 130, 84, 238, 151, 239, 23];
+    },
+    {
+      name: "groupMetadataSetEvent";
+      discriminator: [149, 45, 123, 233, 35, 185, 97, 11];
+    },
+    {
+      name: "groupPausedEvent";
+      discriminator: [245, 34, 122, 141, 94, 4, 98, 220];
+    },
+    {
+      name: "groupVelocityLimitSetEvent";
+      discriminator: [143, 130, 187, 193, 224, 212, 229, 247];
+    },
+    {
+      name: "holderActiveSetEvent";
+      discriminator: [210, 234, 38, 150, 55, 74, 162, 121];
+    },
+    {
+      name: "holderBalanceSyncedEvent";
+      discriminator: [228, 71, 81, 194, 149, 28, 209, 54];
+    },
+    {
+      name: "holderGroupInitializedEvent";
+      discriminator: [151, 138, 153, 11, 148, 73, 198, 161];
+    },
+    {
+      name: "holderGroupMaxSetEvent";
+      discriminator: [119, 219, 4, 11, 125, 50, 148, 93];
+    },
+    {
+      name: "holderGroupRevokedEvent";
+      discriminator: [176, 41, 231, 177, 35, 12, 165, 21];
+    },
+    {
+      name: "holderInitializedEvent";
+      discriminator: [137, 6, 102, 12, 172, 60, 224, 102];
+    },
+    {
+      name: "holderMaxBalanceSetEvent";
+      discriminator: [155, 214, 128, 69, 187, 104, 160, 218];
+    },
+    {
+      name: "holderMaxSetEvent";
+      discriminator: [194, 4, 41, 224, 86, 19, 67, 125];
+    },
+    {
+      name: "holderRevokedEvent";
+      discriminator: [86, 129, 54, 47, 118, 205, 252, 220];
+    },
+    {
+      name: "holdersOfRecordEnforcementSetEvent";
+      discriminator: [8, 181, 96, 227, 153, 133, 98, 116];
+    },
+    {
+      name: "investorProvisionedEvent";
+      discriminator: [127, 34, 29, 3, 238, 157, 251, 4];
+    },
+    {
+      name: "lockupEscrowAccountSetEvent";
+      discriminator: [155, 156, 6, 80, 235, 100, 236, 196];
+    },
+    {
+      name: "pauseScheduledEvent";
+      discriminator: [231, 215, 123, 125, 197, 232, 156, 232];
+    },
+    {
+      name: "pausedEvent";
+      discriminator: [43, 14, 250, 236, 116, 42, 177, 89];
+    },
+    {
+      name: "securityAssociatedAccountInitializedEvent";
+      discriminator: [145, 125, 250, 120, 197, 207, 122, 80];
+    },
+    {
+      name: "securityAssociatedAccountRevokedEvent";
+      discriminator: [10, 128, 179, 16, 187, 58, 108, 168];
+    },
+    {
+      name: "transferRestrictionDataInitializedEvent";
+      discriminator: [121, 220, 235, 96, 82, 226, 13, 158];
+    },
+    {
+      name: "transferRuleAmountLimitsSetEvent";
+      discriminator: [115, 37, 77, 88, 239, 237, 184, 2];
+    },
+    {
+      name: "transferRuleInitializedEvent";
+      discriminator: [74, 40, 2, 101, 123, 59, 145, 162];
+    },
+    {
+      name: "walletGroupUpdatedEvent";
+      discriminator: [226, 110, 175, 232, 80, 59, 96, 89];
+    },
+    {
+      name: "walletHolderUpdatedEvent";
+      discriminator: [24, 41, 110, 169, 68, 189, 22, 247];
+    },
+    {
+      name: "walletTransferBlocksUpdatedEvent";
+      discriminator: [15, 167, 138, 43, 255, 100, 32, 232];
+    },
+    {
+      name: "walletValidityRenewedEvent";
+      discriminator: [159, 14, 163, 179, 173, 198, 14, 236];
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "unauthorized";
+      msg: "unauthorized";
+    },
+    {
+      code: 6001;
+      name: "maxHoldersReached";
+      msg: "Max holders reached";
+    },
+    {
+      code: 6002;
+      name: "transferRuleNotAllowedUntilLater";
+      msg: "Transfer rule not allowed until later";
+    },
+    {
+      code: 6003;
+      name: "invalidRole";
+      msg: "Invalid role";
+    },
+    {
+      code: 6004;
+      name: "allTransfersPaused";
+      msg: "All transfers are paused";
+    },
+    {
+      code: 6005;
+      name: "invalidPda";
+      msg: "Invalid PDA";
+    },
+    {
+      code: 6006;
+      name: "balanceIsTooLow";
+      msg: "Balance is too low";
+    },
+    {
+      code: 6007;
+      name: "currentWalletsCountMustBeZero";
+      msg: "Current wallets count must be zero";
+    },
+    {
+      code: 6008;
+      name: "mismatchedEscrowAccount";
+      msg: "Mismatched escrow account";
+    },
+    {
+      code: 6009;
+      name: "invalidHolderIndex";
+      msg: "Invalid transfer restriction holder index";
+    },
+    {
+      code: 6010;
+      name: "maxHoldersReachedInsideTheGroup";
+      msg: "Max holders reached inside the group";
+    },
+    {
+      code: 6011;
+      name: "transferGroupNotApproved";
+      msg: "Transfer group not approved";
+    },
+    {
+      code: 6012;
+      name: "incorrectTokenlockAccount";
+      msg: "Wrong tokenlock account";
+    },
+    {
+      code: 6013;
+      name: "transferRuleAccountDataIsEmtpy";
+      msg: "Transfer rule account data is empty";
+    },
+    {
+      code: 6014;
+      name: "securityAssociatedAccountDataIsEmtpy";
+      msg: "Security associated account data is empty";
+    },
+    {
+      code: 6015;
+      name: "transferRestrictionsAccountDataIsEmtpy";
+      msg: "Transfer restrictions account data is empty";
+    },
+    {
+      code: 6016;
+      name: "noWalletsInGroup";
+      msg: "No wallets in group";
+    },
+    {
+      code: 6017;
+      name: "newGroupIsTheSameAsTheCurrentGroup";
+      msg: "New group is the same as the current group";
+    },
+    {
+      code: 6018;
+      name: "newHolderMaxMustExceedCurrentHolderCount";
+      msg: "New holder max must exceed current holder count";
+    },
+    {
+      code: 6019;
+      name: "newHolderGroupMaxMustExceedCurrentHolderGroupCount";
+      msg: "New holder group max must exceed current holder group count";
+    },
+    {
+      code: 6020;
+      name: "zeroGroupHolderGroupMaxCannotBeNonZero";
+      msg: "Zero group holder group max cannot be non-zero";
+    },
+    {
+      code: 6021;
+      name: "nonPositiveHolderGroupCount";
+      msg: "Non-positive holder group count";
+    },
+    {
+      code: 6022;
+      name: "currentHolderGroupCountMustBeZero";
+      msg: "Current holder group count must be zero";
+    },
+    {
+      code: 6023;
+      name: "valueUnchanged";
+      msg: "The provided value is already set. No changes were made";
+    },
+    {
+      code: 6024;
+      name: "holderMaxBalanceExceeded";
+      msg: "Holder max balance exceeded";
+    },
+    {
+      code: 6025;
+      name: "groupMaxBalanceExceeded";
+      msg: "Group max balance exceeded";
+    },
+    {
+      code: 6026;
+      name: "transferAmountBelowMinimum";
+      msg: "Transfer amount is below the minimum allowed by transfer rule";
+    },
+    {
+      code: 6027;
+      name: "transferAmountAboveMaximum";
+      msg: "Transfer amount is above the maximum allowed by transfer rule";
+    },
+    {
+      code: 6028;
+      name: "transferAmountNotMultipleOfLotSize";
+      msg: "Transfer amount is not a multiple of transfer rule lot size";
+    },
+    {
+      code: 6029;
+      name: "invalidTransferRuleAmountLimits";
+      msg: "Invalid transfer rule amount limits";
+    },
+    {
+      code: 6030;
+      name: "velocityLimitExceeded";
+      msg: "Velocity limit exceeded";
+    },
+    {
+      code: 6031;
+      name: "invalidVelocityPeriod";
+      msg: "Velocity period must be non-zero when velocity limit is set";
+    },
+    {
+      code: 6032;
+      name: "transferRuleExpired";
+      msg: "Transfer rule has expired";
+    },
+    {
+      code: 6033;
+      name: "invalidTransferRuleTimeWindow";
+      msg: "Transfer rule locked after must be later than locked until";
+    },
+    {
+      code: 6034;
+      name: "walletSendBlocked";
+      msg: "Sending tokens is blocked for the wallet";
+    },
+    {
+      code: 6035;
+      name: "walletReceiveBlocked";
+      msg: "Receiving tokens is blocked for the wallet";
+    },
+    {
+      code: 6036;
+      name: "walletValidityExpired";
+      msg: "Wallet KYC/accreditation has expired";
+    },
+    {
+      code: 6037;
+      name: "invalidRemainingAccounts";
+      msg: "Invalid remaining accounts";
+    },
+    {
+      code: 6038;
+      name: "newHolderIsTheSameAsTheCurrentHolder";
+      msg: "New holder is the same as the current holder";
+    },
+    {
+      code: 6039;
+      name: "holderInactive";
+      msg: "Holder is inactive";
+    },
+    {
+      code: 6040;
+      name: "reservedTransferGroupId";
+      msg: "Transfer group id is reserved for wildcard transfer rules";
+    },
+    {
+      code: 6041;
+      name: "wildcardTransferRuleWithoutGroup";
+      msg: "Wildcard transfer rule requires a group, use the default transfer rule instead";
+    },
+    {
+      code: 6042;
+      name: "groupNameTooLong";
+      msg: "Group name is too long";
+    },
+    {
+      code: 6043;
+      name: "groupJurisdictionTooLong";
+      msg: "Group jurisdiction is too long";
+    },
+    {
+      code: 6044;
+      name: "invalidGroupFlags";
+      msg: "Invalid group flags";
+    },
+    {
+      code: 6045;
+      name: "groupLocked";
+      msg: "Sender group is locked until later";
+    },
+    {
+      code: 6046;
+      name: "groupFrozen";
+      msg: "Sender group is frozen";
+    },
+    {
+      code: 6047;
+      name: "groupTransfersPaused";
+      msg: "Transfers of the group are paused";
+    },
+    {
+      code: 6048;
+      name: "invalidPauseSchedule";
+      msg: "Scheduled pause must end after it starts and in the future";
+    },
+    {
+      code: 6049;
+      name: "holderBalanceOutOfSync";
+      msg: "Tracked holder balance does not match the wallet balances";
+    },
+    {
+      code: 6050;
+      name: "invalidAccountToMigrate";
+      msg: "Account type cannot be migrated";
+    },
+    {
+      code: 6051;
+      name: "accountAlreadyMigrated";
+      msg: "Account is already migrated";
+    },
+    {
+      code: 6052;
+      name: "invalidHolderAccounts";
+      msg: "Invalid holder accounts";
+    },
+    {
+      code: 6053;
+      name: "holdersOfRecordCountOutOfSync";
+      msg: "Tracked holders of record count does not match the holder balances";
+    },
+    {
+      code: 6054;
+      name: "insufficientSourceBalance";
+      msg: "Source balance is lower than the transfer amount";
+    }
+  ];
+  types: [
+    {
+      name: "accessControl";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            type: "pubkey";
+          },
+          {
+            name: "authority";
+            type: "pubkey";
+          },
+          {
+            name: "maxTotalSupply";
+            type: "u64";
+          },
+          {
+            name: "lockupEscrowAccount";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "pendingAuthority";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "contractAdminCount";
+            type: "u64";
+          },
+          {
+            name: "reserveAdminCount";
+            type: "u64";
+          },
+          {
+            name: "walletsAdminCount";
+            type: "u64";
+          },
+          {
+            name: "transferAdminCount";
+            type: "u64";
+          },
+          {
+            name: "approvalThresholds";
+            type: {
+              array: ["u8", 4];
+            };
+          },
+          {
+            name: "proposalsCount";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "allowTransferRuleSetEvent";
+      docs: ["Emitted when the time window of a transfer rule is changed."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "transferRule";
+            docs: ["Transfer rule account."];
+            type: "pubkey";
+          },
+          {
+            name: "groupIdFrom";
+            docs: ["Id of the sender group."];
+            type: "u64";
+          },
+          {
+            name: "groupIdTo";
+            docs: ["Id of the recipient group."];
+            type: "u64";
+          },
+          {
+            name: "lockedUntilBefore";
+            docs: ["Previous timestamp transfers were allowed from."];
+            type: "u64";
+          },
+          {
+            name: "lockedAfterBefore";
+            docs: ["Previous timestamp transfers were allowed until."];
+            type: "u64";
+          },
+          {
+            name: "lockedUntil";
+            docs: ["New timestamp transfers are allowed from."];
+            type: "u64";
+          },
+          {
+            name: "lockedAfter";
+            docs: ["New timestamp transfers are allowed until, 0 for no end."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who changed the transfer rule."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      name: "transferRestrictionData";
-      discriminator: [166, 184, 205, 98, 165, 224, 174, 148];
+      name: "defaultTransferRuleSetEvent";
+      docs: ["Emitted when the default transfer rule is set or removed."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "defaultTransferRuleBefore";
+            docs: ["Previous default transfer rule."];
+            type: {
+              option: {
+                defined: {
+                  name: "transferRule";
+                };
+              };
+            };
+          },
+          {
+            name: "defaultTransferRule";
+            docs: ["New default transfer rule, `None` if removed."];
+            type: {
+              option: {
+                defined: {
+                  name: "transferRule";
+                };
+              };
+            };
+          },
+          {
+            name: "authority";
+            docs: ["Admin who changed the default transfer rule."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      name: "transferRestrictionGroup";
-      discriminator: [61, 120, 96, 96, 113, 210, 205, 223];
+      name: "extraAccountMetaListInitializedEvent";
+      docs: [
+        "Emitted when the transfer hook extra account metas are initialized."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            docs: ["Security token mint."];
+            type: "pubkey";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who initialized the extra account metas."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      name: "transferRestrictionHolder";
-      discriminator: [196, 226, 112, 46, 157, 122, 48, 157];
+      name: "extraAccountMetaListUpdatedEvent";
+      docs: ["Emitted when the transfer hook extra account metas are updated."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            docs: ["Security token mint."];
+            type: "pubkey";
+          },
+          {
+            name: "sizeBefore";
+            docs: ["Previous size of the extra account metas account."];
+            type: "u64";
+          },
+          {
+            name: "size";
+            docs: ["New size of the extra account metas account."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who updated the extra account metas."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      name: "transferRule";
-      discriminator: [200, 231, 114, 91, 84, 241, 109, 172];
+      name: "groupInitializedEvent";
+      docs: ["Emitted when a transfer restriction group is initialized."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "group";
+            docs: ["Group account."];
+            type: "pubkey";
+          },
+          {
+            name: "groupId";
+            docs: ["Id of the group."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who initialized the group."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      name: "walletRole";
-      discriminator: [219, 71, 35, 217, 102, 248, 173, 9];
-    }
-  ];
-  errors: [
+      name: "groupLockupSetEvent";
+      docs: [
+        "Emitted when the outbound lockup or freeze of a group is changed."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "group";
+            docs: ["Group account."];
+            type: "pubkey";
+          },
+          {
+            name: "groupId";
+            docs: ["Id of the group."];
+            type: "u64";
+          },
+          {
+            name: "lockedUntilBefore";
+            docs: ["Previous timestamp outbound transfers were locked until."];
+            type: "u64";
+          },
+          {
+            name: "frozenBefore";
+            docs: ["Previous freeze flag of the group."];
+            type: "bool";
+          },
+          {
+            name: "lockedUntil";
+            docs: [
+              "New timestamp outbound transfers are locked until, 0 for no lockup."
+            ];
+            type: "u64";
+          },
+          {
+            name: "frozen";
+            docs: ["New freeze flag of the group."];
+            type: "bool";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who changed the lockup."];
+            type: "pubkey";
+          }
+        ];
+      };
+    },
     {
-      code: 6000;
-      name: "unauthorized";
-      msg: "unauthorized";
+      name: "groupMaxBalanceSetEvent";
+      docs: ["Emitted when the maximum balance of a group holder is changed."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "group";
+            docs: ["Group account."];
+            type: "pubkey";
+          },
+          {
+            name: "groupId";
+            docs: ["Id of the group."];
+            type: "u64";
+          },
+          {
+            name: "maxBalanceBefore";
+            docs: ["Previous maximum balance."];
+            type: "u64";
+          },
+          {
+            name: "maxBalance";
+            docs: ["New maximum balance, 0 for no limit."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who changed the maximum balance."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6001;
-      name: "maxHoldersReached";
-      msg: "Max holders reached";
+      name: "groupMetadataSetEvent";
+      docs: [
+        "Emitted when the name, jurisdiction or flags of a group are changed."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "group";
+            docs: ["Group account."];
+            type: "pubkey";
+          },
+          {
+            name: "groupId";
+            docs: ["Id of the group."];
+            type: "u64";
+          },
+          {
+            name: "nameBefore";
+            docs: ["Previous name of the group."];
+            type: "string";
+          },
+          {
+            name: "jurisdictionBefore";
+            docs: ["Previous jurisdiction code of the group."];
+            type: "string";
+          },
+          {
+            name: "flagsBefore";
+            docs: ["Previous flags of the group."];
+            type: "u8";
+          },
+          {
+            name: "name";
+            docs: ["New name of the group."];
+            type: "string";
+          },
+          {
+            name: "jurisdiction";
+            docs: ["New jurisdiction code of the group."];
+            type: "string";
+          },
+          {
+            name: "flags";
+            docs: ["New flags of the group."];
+            type: "u8";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who changed the metadata."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6002;
-      name: "transferRuleNotAllowedUntilLater";
-      msg: "Transfer rule not allowed until later";
+      name: "groupPausedEvent";
+      docs: ["Emitted when transfers of a group are paused or unpaused."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "group";
+            docs: ["Group account."];
+            type: "pubkey";
+          },
+          {
+            name: "groupId";
+            docs: ["Id of the group."];
+            type: "u64";
+          },
+          {
+            name: "pausedBefore";
+            docs: ["Previous pause flag of the group."];
+            type: "bool";
+          },
+          {
+            name: "paused";
+            docs: ["New pause flag of the group."];
+            type: "bool";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who changed the pause flag."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6003;
-      name: "invalidRole";
-      msg: "Invalid role";
+      name: "groupVelocityLimitSetEvent";
+      docs: ["Emitted when the velocity limit of a group is changed."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "group";
+            docs: ["Group account."];
+            type: "pubkey";
+          },
+          {
+            name: "groupId";
+            docs: ["Id of the group."];
+            type: "u64";
+          },
+          {
+            name: "velocityLimitBefore";
+            docs: ["Previous velocity limit."];
+            type: "u64";
+          },
+          {
+            name: "velocityPeriodBefore";
+            docs: ["Previous velocity period in seconds."];
+            type: "u64";
+          },
+          {
+            name: "velocityLimit";
+            docs: ["New velocity limit, 0 for no limit."];
+            type: "u64";
+          },
+          {
+            name: "velocityPeriod";
+            docs: ["New velocity period in seconds."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who changed the velocity limit."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6004;
-      name: "allTransfersPaused";
-      msg: "All transfers are paused";
+      name: "holderActiveSetEvent";
+      docs: ["Emitted when a holder is activated or deactivated."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "holder";
+            docs: ["Holder account."];
+            type: "pubkey";
+          },
+          {
+            name: "holderId";
+            docs: ["Id of the holder."];
+            type: "u64";
+          },
+          {
+            name: "activeBefore";
+            docs: ["Previous active flag."];
+            type: "bool";
+          },
+          {
+            name: "active";
+            docs: ["New active flag."];
+            type: "bool";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who changed the active flag."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6005;
-      name: "invalidPda";
-      msg: "Invalid PDA";
+      name: "holderBalanceSyncedEvent";
+      docs: [
+        "Emitted when a wallet balance changed without a transfer is recorded in its holder balance."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "securityAssociatedAccount";
+            docs: ["Security associated account of the wallet."];
+            type: "pubkey";
+          },
+          {
+            name: "holder";
+            docs: ["Holder account of the wallet."];
+            type: "pubkey";
+          },
+          {
+            name: "balanceBefore";
+            docs: ["Previously recorded wallet balance."];
+            type: "u64";
+          },
+          {
+            name: "balance";
+            docs: ["New wallet balance."];
+            type: "u64";
+          },
+          {
+            name: "holderBalanceBefore";
+            docs: ["Previous holder balance."];
+            type: "u64";
+          },
+          {
+            name: "holderBalance";
+            docs: ["New holder balance."];
+            type: "u64";
+          }
+        ];
+      };
     },
     {
-      code: 6006;
-      name: "balanceIsTooLow";
-      msg: "Balance is too low";
+      name: "holderGroup";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "group";
+            type: "u64";
+          },
+          {
+            name: "holder";
+            type: "pubkey";
+          },
+          {
+            name: "currentWalletsCount";
+            type: "u64";
+          },
+          {
+            name: "currentBalance";
+            type: "u64";
+          }
+        ];
+      };
     },
     {
-      code: 6007;
-      name: "currentWalletsCountMustBeZero";
-      msg: "Current wallets count must be zero";
+      name: "holderGroupInitializedEvent";
+      docs: ["Emitted when a holder group is initialized."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "holderGroup";
+            docs: ["Holder group account."];
+            type: "pubkey";
+          },
+          {
+            name: "holder";
+            docs: ["Holder account."];
+            type: "pubkey";
+          },
+          {
+            name: "groupId";
+            docs: ["Id of the group."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who initialized the holder group."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6008;
-      name: "mismatchedEscrowAccount";
-      msg: "Mismatched escrow account";
+      name: "holderGroupMaxSetEvent";
+      docs: [
+        "Emitted when the maximum number of holders of a group is changed."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "group";
+            docs: ["Group account."];
+            type: "pubkey";
+          },
+          {
+            name: "groupId";
+            docs: ["Id of the group."];
+            type: "u64";
+          },
+          {
+            name: "holderMaxBefore";
+            docs: ["Previous maximum number of holders in the group."];
+            type: "u64";
+          },
+          {
+            name: "holderMax";
+            docs: ["New maximum number of holders in the group."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who changed the maximum."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6009;
-      name: "invalidHolderIndex";
-      msg: "Invalid transfer restriction holder index";
+      name: "holderGroupRevokedEvent";
+      docs: ["Emitted when a holder group is revoked."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "holderGroup";
+            docs: ["Holder group account."];
+            type: "pubkey";
+          },
+          {
+            name: "holder";
+            docs: ["Holder account."];
+            type: "pubkey";
+          },
+          {
+            name: "groupId";
+            docs: ["Id of the group."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who revoked the holder group."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6010;
-      name: "maxHoldersReachedInsideTheGroup";
-      msg: "Max holders reached inside the group";
+      name: "holderInitializedEvent";
+      docs: ["Emitted when a holder is initialized."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "holder";
+            docs: ["Holder account."];
+            type: "pubkey";
+          },
+          {
+            name: "holderId";
+            docs: ["Id of the holder."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who initialized the holder."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6011;
-      name: "transferGroupNotApproved";
-      msg: "Transfer group not approved";
+      name: "holderMaxBalanceSetEvent";
+      docs: ["Emitted when the maximum balance of a holder is changed."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "holder";
+            docs: ["Holder account."];
+            type: "pubkey";
+          },
+          {
+            name: "holderId";
+            docs: ["Id of the holder."];
+            type: "u64";
+          },
+          {
+            name: "maxBalanceBefore";
+            docs: ["Previous maximum balance."];
+            type: "u64";
+          },
+          {
+            name: "maxBalance";
+            docs: ["New maximum balance, 0 for no limit."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who changed the maximum balance."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6012;
-      name: "incorrectTokenlockAccount";
-      msg: "Wrong tokenlock account";
+      name: "holderMaxSetEvent";
+      docs: ["Emitted when the maximum number of holders is changed."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "holderMaxBefore";
+            docs: ["Previous maximum number of holders."];
+            type: "u64";
+          },
+          {
+            name: "holderMax";
+            docs: ["New maximum number of holders."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who changed the maximum."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6013;
-      name: "transferRuleAccountDataIsEmtpy";
-      msg: "Transfer rule account data is empty";
+      name: "holderRevokedEvent";
+      docs: ["Emitted when a holder is revoked."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "holder";
+            docs: ["Holder account."];
+            type: "pubkey";
+          },
+          {
+            name: "holderId";
+            docs: ["Id of the holder."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who revoked the holder."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6014;
-      name: "securityAssociatedAccountDataIsEmtpy";
-      msg: "Security associated account data is empty";
+      name: "holdersOfRecordEnforcementSetEvent";
+      docs: [
+        "Emitted when holders of record enforcement at transfer time is enabled or disabled."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "enforcedBefore";
+            docs: ["Previous enforcement flag."];
+            type: "bool";
+          },
+          {
+            name: "enforced";
+            docs: ["New enforcement flag."];
+            type: "bool";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who changed the enforcement."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6015;
-      name: "transferRestrictionsAccountDataIsEmtpy";
-      msg: "Transfer restrictions account data is empty";
+      name: "investorProvisionedEvent";
+      docs: [
+        "Emitted for every investor onboarded at once with its holder, holder group",
+        "and security associated account."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "holder";
+            docs: ["Holder account."];
+            type: "pubkey";
+          },
+          {
+            name: "holderId";
+            docs: ["Id of the holder."];
+            type: "u64";
+          },
+          {
+            name: "holderGroup";
+            docs: ["Holder group account."];
+            type: "pubkey";
+          },
+          {
+            name: "securityAssociatedAccount";
+            docs: ["Security associated account."];
+            type: "pubkey";
+          },
+          {
+            name: "tokenAccount";
+            docs: ["Token account of the investor wallet."];
+            type: "pubkey";
+          },
+          {
+            name: "groupId";
+            docs: ["Id of the group of the investor."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who provisioned the investor."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6016;
-      name: "noWalletsInGroup";
-      msg: "No wallets in group";
+      name: "lockupEscrowAccountSetEvent";
+      docs: ["Emitted when the tokenlock escrow account is set."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            docs: ["Security token mint."];
+            type: "pubkey";
+          },
+          {
+            name: "lockupEscrowAccountBefore";
+            docs: ["Previous escrow account."];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "lockupEscrowAccount";
+            docs: ["New escrow account."];
+            type: "pubkey";
+          },
+          {
+            name: "tokenlockAccount";
+            docs: ["Tokenlock account of the escrow."];
+            type: "pubkey";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who set the escrow account."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6017;
-      name: "newGroupIsTheSameAsTheCurrentGroup";
-      msg: "New group is the same as the current group";
+      name: "pauseScheduledEvent";
+      docs: ["Emitted when a pause of all transfers is scheduled or canceled."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "pausedFromBefore";
+            docs: ["Previous start of the scheduled pause."];
+            type: "u64";
+          },
+          {
+            name: "pausedUntilBefore";
+            docs: ["Previous end of the scheduled pause."];
+            type: "u64";
+          },
+          {
+            name: "pausedFrom";
+            docs: ["New start of the scheduled pause."];
+            type: "u64";
+          },
+          {
+            name: "pausedUntil";
+            docs: ["New end of the scheduled pause, 0 if canceled."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who scheduled the pause."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6018;
-      name: "newHolderMaxMustExceedCurrentHolderCount";
-      msg: "New holder max must exceed current holder count";
+      name: "pausedEvent";
+      docs: ["Emitted when all transfers are paused or unpaused."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "pausedBefore";
+            docs: ["Previous pause flag."];
+            type: "bool";
+          },
+          {
+            name: "paused";
+            docs: ["New pause flag."];
+            type: "bool";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who changed the pause flag."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6019;
-      name: "newHolderGroupMaxMustExceedCurrentHolderGroupCount";
-      msg: "New holder group max must exceed current holder group count";
+      name: "securityAssociatedAccount";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "group";
+            type: "u64";
+          },
+          {
+            name: "holder";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "holderId";
+            type: "u64";
+          },
+          {
+            name: "balance";
+            type: "u64";
+          },
+          {
+            name: "velocityPeriod";
+            type: "u64";
+          },
+          {
+            name: "velocityBucket";
+            type: "u64";
+          },
+          {
+            name: "velocitySent";
+            type: {
+              array: ["u64", 12];
+            };
+          },
+          {
+            name: "sendBlocked";
+            type: "bool";
+          },
+          {
+            name: "receiveBlocked";
+            type: "bool";
+          },
+          {
+            name: "validUntil";
+            type: "u64";
+          }
+        ];
+      };
     },
     {
-      code: 6020;
-      name: "zeroGroupHolderGroupMaxCannotBeNonZero";
-      msg: "Zero group holder group max cannot be non-zero";
+      name: "securityAssociatedAccountInitializedEvent";
+      docs: [
+        "Emitted when a security associated account of a wallet is initialized."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "securityAssociatedAccount";
+            docs: ["Security associated account."];
+            type: "pubkey";
+          },
+          {
+            name: "tokenAccount";
+            docs: ["Token account of the wallet."];
+            type: "pubkey";
+          },
+          {
+            name: "holderId";
+            docs: ["Id of the holder of the wallet."];
+            type: "u64";
+          },
+          {
+            name: "groupId";
+            docs: ["Id of the group of the wallet."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who initialized the security associated account."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6021;
-      name: "nonPositiveHolderGroupCount";
-      msg: "Non-positive holder group count";
+      name: "securityAssociatedAccountRevokedEvent";
+      docs: [
+        "Emitted when a security associated account of a wallet is revoked."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "securityAssociatedAccount";
+            docs: ["Security associated account."];
+            type: "pubkey";
+          },
+          {
+            name: "tokenAccount";
+            docs: ["Token account of the wallet."];
+            type: "pubkey";
+          },
+          {
+            name: "holderId";
+            docs: ["Id of the holder of the wallet."];
+            type: "u64";
+          },
+          {
+            name: "groupId";
+            docs: ["Id of the group of the wallet."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who revoked the security associated account."];
+            type: "pubkey";
+          }
+        ];
+      };
     },
     {
-      code: 6022;
-      name: "currentHolderGroupCountMustBeZero";
-      msg: "Current holder group count must be zero";
+      name: "transferRestrictionData";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "securityTokenMint";
+            type: "pubkey";
+          },
+          {
+            name: "accessControlAccount";
+            type: "pubkey";
+          },
+          {
+            name: "currentHoldersCount";
+            type: "u64";
+          },
+          {
+            name: "holderIds";
+            type: "u64";
+          },
+          {
+            name: "maxHolders";
+            type: "u64";
+          },
+          {
+            name: "paused";
+            type: "bool";
+          },
+          {
+            name: "lockupEscrowAccount";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "currentHoldersOfRecordCount";
+            type: "u64";
+          },
+          {
+            name: "holdersOfRecordEnforced";
+            type: "bool";
+          },
+          {
+            name: "defaultTransferRule";
+            type: {
+              option: {
+                defined: {
+                  name: "transferRule";
+                };
+              };
+            };
+          },
+          {
+            name: "pausedFrom";
+            type: "u64";
+          },
+          {
+            name: "pausedUntil";
+            type: "u64";
+          }
+        ];
+      };
     },
     {
-      code: 6023;
-      name: "valueUnchanged";
-      msg: "The provided value is already set. No changes were made";
-    }
-  ];
-  types: [
-    {
-      name: "accessControl";
+      name: "transferRestrictionDataInitializedEvent";
+      docs: [
+        "Emitted when the transfer restriction data of the security token is initialized."
+      ];
       type: {
         kind: "struct";
         fields: [
           {
-            name: "mint";
+            name: "transferRestrictionData";
+            docs: ["Transfer restriction data account."];
             type: "pubkey";
           },
           {
-            name: "authority";
+            name: "mint";
+            docs: ["Security token mint."];
             type: "pubkey";
           },
           {
-            name: "maxTotalSupply";
+            name: "maxHolders";
+            docs: ["Maximum number of holders."];
             type: "u64";
           },
           {
-            name: "lockupEscrowAccount";
-            type: {
-              option: "pubkey";
-            };
+            name: "authority";
+            docs: ["Admin who initialized the data."];
+            type: "pubkey";
           }
         ];
       };
     },
     {
-      name: "holderGroup";
+      name: "transferRestrictionGroup";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "group";
+            name: "id";
             type: "u64";
           },
           {
-            name: "holder";
+            name: "currentHoldersCount";
+            type: "u64";
+          },
+          {
+            name: "maxHolders";
+            type: "u64";
+          },
+          {
+            name: "transferRestrictionData";
             type: "pubkey";
           },
           {
-            name: "currentWalletsCount";
+            name: "maxBalance";
+            type: "u64";
+          },
+          {
+            name: "velocityLimit";
+            type: "u64";
+          },
+          {
+            name: "velocityPeriod";
+            type: "u64";
+          },
+          {
+            name: "currentHoldersOfRecordCount";
+            type: "u64";
+          },
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "jurisdiction";
+            type: "string";
+          },
+          {
+            name: "flags";
+            type: "u8";
+          },
+          {
+            name: "lockedUntil";
             type: "u64";
+          },
+          {
+            name: "frozen";
+            type: "bool";
+          },
+          {
+            name: "paused";
+            type: "bool";
           }
         ];
       };
     },
     {
-      name: "securityAssociatedAccount";
+      name: "transferRestrictionHolder";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "group";
+            name: "transferRestrictionData";
+            type: "pubkey";
+          },
+          {
+            name: "currentWalletsCount";
             type: "u64";
           },
           {
-            name: "holder";
-            type: {
-              option: "pubkey";
-            };
+            name: "currentHolderGroupCount";
+            type: "u64";
+          },
+          {
+            name: "id";
+            type: "u64";
+          },
+          {
+            name: "active";
+            type: "bool";
+          },
+          {
+            name: "maxBalance";
+            type: "u64";
+          },
+          {
+            name: "currentBalance";
+            type: "u64";
           }
         ];
       };
     },
     {
-      name: "transferRestrictionData";
+      name: "transferRestrictionReason";
+      docs: [
+        "Reason code returned by `detect_transfer_restriction` (ERC-1404 `detectTransferRestriction`).",
+        "Code 0 means that the transfer is allowed, otherwise it is the error code the transfer would fail with."
+      ];
       type: {
         kind: "struct";
         fields: [
           {
-            name: "securityTokenMint";
-            type: "pubkey";
+            name: "code";
+            type: "u32";
           },
           {
-            name: "accessControlAccount";
+            name: "message";
+            type: "string";
+          }
+        ];
+      };
+    },
+    {
+      name: "transferRule";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "transferRestrictionData";
             type: "pubkey";
           },
           {
-            name: "currentHoldersCount";
+            name: "transferGroupIdFrom";
             type: "u64";
           },
           {
-            name: "holderIds";
+            name: "transferGroupIdTo";
             type: "u64";
           },
           {
-            name: "maxHolders";
+            name: "lockedUntil";
             type: "u64";
           },
           {
-            name: "paused";
-            type: "bool";
+            name: "minAmount";
+            type: "u64";
           },
           {
-            name: "lockupEscrowAccount";
-            type: {
-              option: "pubkey";
-            };
+            name: "maxAmount";
+            type: "u64";
+          },
+          {
+            name: "lotSize";
+            type: "u64";
+          },
+          {
+            name: "lockedAfter";
+            type: "u64";
           }
         ];
       };
     },
     {
-      name: "transferRestrictionGroup";
+      name: "transferRuleAmountLimitsSetEvent";
+      docs: ["Emitted when the amount limits of a transfer rule are changed."];
       type: {
         kind: "struct";
         fields: [
           {
-            name: "id";
+            name: "transferRule";
+            docs: ["Transfer rule account."];
+            type: "pubkey";
+          },
+          {
+            name: "groupIdFrom";
+            docs: ["Id of the sender group."];
             type: "u64";
           },
           {
-            name: "currentHoldersCount";
+            name: "groupIdTo";
+            docs: ["Id of the recipient group."];
             type: "u64";
           },
           {
-            name: "maxHolders";
+            name: "minAmountBefore";
+            docs: ["Previous minimum amount."];
             type: "u64";
           },
           {
-            name: "transferRestrictionData";
+            name: "maxAmountBefore";
+            docs: ["Previous maximum amount."];
+            type: "u64";
+          },
+          {
+            name: "lotSizeBefore";
+            docs: ["Previous lot size."];
+            type: "u64";
+          },
+          {
+            name: "minAmount";
+            docs: ["New minimum amount, 0 for no limit."];
+            type: "u64";
+          },
+          {
+            name: "maxAmount";
+            docs: ["New maximum amount, 0 for no limit."];
+            type: "u64";
+          },
+          {
+            name: "lotSize";
+            docs: ["New lot size, 0 for no limit."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who changed the amount limits."];
             type: "pubkey";
           }
         ];
       };
     },
     {
-      name: "transferRestrictionHolder";
+      name: "transferRuleInitializedEvent";
+      docs: ["Emitted when a transfer rule between groups is initialized."];
       type: {
         kind: "struct";
         fields: [
           {
-            name: "transferRestrictionData";
+            name: "transferRule";
+            docs: ["Transfer rule account."];
             type: "pubkey";
           },
           {
-            name: "currentWalletsCount";
+            name: "groupIdFrom";
+            docs: [
+              "Id of the sender group, `ANY_TRANSFER_GROUP_ID` for any group."
+            ];
             type: "u64";
           },
           {
-            name: "currentHolderGroupCount";
+            name: "groupIdTo";
+            docs: [
+              "Id of the recipient group, `ANY_TRANSFER_GROUP_ID` for any group."
+            ];
             type: "u64";
           },
           {
-            name: "id";
+            name: "lockedUntil";
+            docs: ["Timestamp transfers are allowed from."];
             type: "u64";
           },
           {
-            name: "active";
-            type: "bool";
+            name: "authority";
+            docs: ["Admin who initialized the transfer rule."];
+            type: "pubkey";
           }
         ];
       };
     },
     {
-      name: "transferRule";
+      name: "walletGroupUpdatedEvent";
+      docs: ["Emitted when a wallet is moved to another group."];
       type: {
         kind: "struct";
         fields: [
           {
-            name: "transferRestrictionData";
+            name: "securityAssociatedAccount";
+            docs: ["Security associated account of the wallet."];
             type: "pubkey";
           },
           {
-            name: "transferGroupIdFrom";
+            name: "groupIdBefore";
+            docs: ["Previous group id of the wallet."];
             type: "u64";
           },
           {
-            name: "transferGroupIdTo";
+            name: "groupId";
+            docs: ["New group id of the wallet."];
             type: "u64";
           },
           {
-            name: "lockedUntil";
+            name: "authority";
+            docs: ["Admin who moved the wallet."];
+            type: "pubkey";
+          }
+        ];
+      };
+    },
+    {
+      name: "walletHolderUpdatedEvent";
+      docs: ["Emitted when a wallet is moved to another holder."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "securityAssociatedAccount";
+            docs: ["Security associated account of the wallet."];
+            type: "pubkey";
+          },
+          {
+            name: "holderBefore";
+            docs: ["Previous holder account of the wallet."];
+            type: "pubkey";
+          },
+          {
+            name: "holder";
+            docs: ["New holder account of the wallet."];
+            type: "pubkey";
+          },
+          {
+            name: "holderIdBefore";
+            docs: ["Previous holder id of the wallet."];
+            type: "u64";
+          },
+          {
+            name: "holderId";
+            docs: ["New holder id of the wallet."];
             type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who moved the wallet."];
+            type: "pubkey";
           }
         ];
       };
@@ -1923,6 +5240,78 @@ export type TransferRestrictions = {
           }
         ];
       };
+    },
+    {
+      name: "walletTransferBlocksUpdatedEvent";
+      docs: [
+        "Emitted when sending or receiving is blocked or unblocked for a wallet."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "securityAssociatedAccount";
+            docs: ["Security associated account of the wallet."];
+            type: "pubkey";
+          },
+          {
+            name: "sendBlockedBefore";
+            docs: ["Previous send block flag."];
+            type: "bool";
+          },
+          {
+            name: "receiveBlockedBefore";
+            docs: ["Previous receive block flag."];
+            type: "bool";
+          },
+          {
+            name: "sendBlocked";
+            docs: ["New send block flag."];
+            type: "bool";
+          },
+          {
+            name: "receiveBlocked";
+            docs: ["New receive block flag."];
+            type: "bool";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who changed the transfer blocks."];
+            type: "pubkey";
+          }
+        ];
+      };
+    },
+    {
+      name: "walletValidityRenewedEvent";
+      docs: [
+        "Emitted for every wallet whose KYC/accreditation validity is renewed."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "securityAssociatedAccount";
+            docs: ["Security associated account of the wallet."];
+            type: "pubkey";
+          },
+          {
+            name: "validUntilBefore";
+            docs: ["Previous validity timestamp."];
+            type: "u64";
+          },
+          {
+            name: "validUntil";
+            docs: ["New validity timestamp, 0 for no expiry."];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            docs: ["Admin who renewed the validity."];
+            type: "pubkey";
+          }
+        ];
+      };
     }
   ];
 };
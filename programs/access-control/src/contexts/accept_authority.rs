@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::{errors::AccessControlError, AccessControl, ACCESS_CONTROL_SEED};
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(mut,
+      constraint = access_control_account.authority == authority.key() @ AccessControlError::Unauthorized,
+      constraint = access_control_account.pending_authority == Some(new_authority.key())
+        @ AccessControlError::InvalidPendingAuthority,
+      seeds = [
+        ACCESS_CONTROL_SEED,
+        mint.key().as_ref(),
+      ],
+      bump,
+    )]
+    pub access_control_account: Account<'info, AccessControl>,
+
+    #[account(mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Current authority, signs the transfer hook and metadata update authority changes
+    pub authority: Signer<'info>,
+
+    /// Proposed authority accepting the handover
+    pub new_authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    AccessControl, Proposal, WalletRole, ACCESS_CONTROL_SEED, PROPOSAL_PREFIX, WALLET_ROLE_PREFIX,
+};
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    #[account(mut,
+      constraint = proposal.access_control == access_control.key(),
+      seeds = [
+        PROPOSAL_PREFIX,
+        access_control.key().as_ref(),
+        &proposal.id.to_le_bytes(),
+      ],
+      bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+    #[account(
+      seeds = [
+        ACCESS_CONTROL_SEED,
+        access_control.mint.as_ref(),
+      ],
+      bump,
+    )]
+    pub access_control: Box<Account<'info, AccessControl>>,
+    #[account(
+      seeds = [
+        WALLET_ROLE_PREFIX,
+        &access_control.mint.to_bytes(),
+        &authority.key().to_bytes(),
+      ],
+      bump,
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+    pub authority: Signer<'info>,
+}
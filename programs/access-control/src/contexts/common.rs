@@ -1 +1,3 @@
 pub const DISCRIMINATOR_LEN: usize = 8;
+// Max length of the reason recorded in the events of admin actions, e.g. a court order reference
+pub const MAX_REASON_LEN: usize = 64;
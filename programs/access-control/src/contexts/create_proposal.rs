@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    contexts::common::{DISCRIMINATOR_LEN, MAX_REASON_LEN},
+    errors::AccessControlError,
+    AccessControl, Roles, WalletRole, ACCESS_CONTROL_SEED, WALLET_ROLE_PREFIX,
+};
+
+pub const PROPOSAL_PREFIX: &[u8] = b"proposal";
+pub const MAX_PROPOSAL_APPROVALS: u8 = 10;
+
+#[repr(u8)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum ProposalActionType {
+    MintSecurities = 0,
+    BurnSecurities = 1,
+    ForceTransferBetween = 2,
+    SetMaxTotalSupply = 3,
+}
+
+pub const PROPOSAL_ACTION_TYPES_COUNT: usize = 4;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Debug)]
+pub enum ProposalAction {
+    MintSecurities {
+        destination_account: Pubkey,
+        amount: u64,
+    },
+    BurnSecurities {
+        target_account: Pubkey,
+        amount: u64,
+    },
+    ForceTransferBetween {
+        source_account: Pubkey,
+        destination_account: Pubkey,
+        amount: u64,
+    },
+    SetMaxTotalSupply {
+        max_total_supply: u64,
+    },
+}
+
+impl ProposalAction {
+    pub fn action_type(&self) -> ProposalActionType {
+        match self {
+            ProposalAction::MintSecurities { .. } => ProposalActionType::MintSecurities,
+            ProposalAction::BurnSecurities { .. } => ProposalActionType::BurnSecurities,
+            ProposalAction::ForceTransferBetween { .. } => ProposalActionType::ForceTransferBetween,
+            ProposalAction::SetMaxTotalSupply { .. } => ProposalActionType::SetMaxTotalSupply,
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    pub access_control: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+    #[max_len(MAX_REASON_LEN)]
+    pub reason: String,
+    // Reserve Admins who approved the proposal, including the proposer
+    #[max_len(MAX_PROPOSAL_APPROVALS)]
+    pub approvals: Vec<Pubkey>,
+    // Unix timestamp from which the proposal can no longer be approved or executed
+    pub expires_at: i64,
+}
+
+impl Proposal {
+    pub fn ensure_not_expired(&self) -> Result<()> {
+        if Clock::get()?.unix_timestamp >= self.expires_at {
+            return Err(AccessControlError::ProposalExpired.into());
+        }
+        Ok(())
+    }
+
+    /// Fails if the proposal is expired. Counts the approvals of wallets which still hold the Reserve Admin role. The first
+    /// `approvals.len()` `remaining_accounts` are the wallet roles of the approvers in approval
+    /// order, a revoked wallet role is not counted. Returns the rest of `remaining_accounts`.
+    pub fn ensure_approved<'a, 'info>(
+        &self,
+        access_control: &Account<AccessControl>,
+        remaining_accounts: &'a [AccountInfo<'info>],
+    ) -> Result<&'a [AccountInfo<'info>]> {
+        self.ensure_not_expired()?;
+        if remaining_accounts.len() < self.approvals.len() {
+            return Err(AccessControlError::InvalidWalletRoleAccounts.into());
+        }
+        let (wallet_roles, remaining_accounts) = remaining_accounts.split_at(self.approvals.len());
+
+        let mut approvals = 0;
+        for (approver, wallet_role_info) in self.approvals.iter().zip(wallet_roles) {
+            if *wallet_role_info.owner != crate::ID {
+                continue;
+            }
+            let Ok(wallet_role) =
+                WalletRole::try_deserialize(&mut &wallet_role_info.data.borrow()[..])
+            else {
+                continue;
+            };
+            if wallet_role.owner == *approver
+                && wallet_role.access_control == access_control.key()
+                && wallet_role.has_role(Roles::ReserveAdmin)
+            {
+                approvals += 1;
+            }
+        }
+
+        let threshold = access_control.approval_threshold(self.action.action_type());
+        if approvals < threshold as usize {
+            return Err(AccessControlError::NotEnoughApprovals.into());
+        }
+        Ok(remaining_accounts)
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(action: ProposalAction)]
+pub struct CreateProposal<'info> {
+    #[account(init, payer = payer, space = DISCRIMINATOR_LEN + Proposal::INIT_SPACE,
+      seeds = [
+        PROPOSAL_PREFIX,
+        access_control.key().as_ref(),
+        &access_control.proposals_count.to_le_bytes(),
+      ],
+      bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+    #[account(mut,
+      constraint = security_mint.key() == access_control.mint,
+      seeds = [
+        ACCESS_CONTROL_SEED,
+        security_mint.key().as_ref(),
+      ],
+      bump,
+    )]
+    pub access_control: Box<Account<'info, AccessControl>>,
+    pub security_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      seeds = [
+        WALLET_ROLE_PREFIX,
+        &security_mint.key().to_bytes(),
+        &payer.key().to_bytes(),
+      ],
+      bump,
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,61 @@
+use anchor_lang::{prelude::*, solana_program::program_option::COption};
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{AccessControl, Proposal, ACCESS_CONTROL_SEED, PROPOSAL_PREFIX};
+
+#[derive(Accounts)]
+pub struct ExecuteForceTransferBetweenProposal<'info> {
+    #[account(mut,
+      close = proposer,
+      constraint = proposal.access_control == access_control.key(),
+      seeds = [
+        PROPOSAL_PREFIX,
+        access_control.key().as_ref(),
+        &proposal.id.to_le_bytes(),
+      ],
+      bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+    /// CHECK: Proposer receiving the rent of the executed proposal
+    #[account(mut, address = proposal.proposer)]
+    pub proposer: AccountInfo<'info>,
+    #[account(mut,
+      associated_token::token_program = token_program,
+      associated_token::mint = security_mint,
+      associated_token::authority = source_authority,
+    )]
+    pub source_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut,
+      constraint = security_mint.mint_authority == COption::Some(access_control.key()),
+    )]
+    pub security_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut,
+      associated_token::token_program = token_program,
+      associated_token::mint = security_mint,
+      associated_token::authority = destination_authority,
+    )]
+    pub destination_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+      constraint = security_mint.key() == access_control.mint,
+      seeds = [
+        ACCESS_CONTROL_SEED,
+        security_mint.key().as_ref(),
+      ],
+      bump,
+    )]
+    pub access_control: Box<Account<'info, AccessControl>>,
+
+    /// CHECK: The sender_authority account is the account that owner of the sender_account
+    pub source_authority: UncheckedAccount<'info>,
+
+    /// CHECK: The destination_authority account is the account that owner of the destination_account
+    pub destination_authority: UncheckedAccount<'info>,
+    pub executor: Signer<'info>,
+    pub token_program: Program<'info, Token2022>,
+}
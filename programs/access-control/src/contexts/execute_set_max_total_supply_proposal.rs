@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{token_2022::ID as TOKEN_2022_PROGRAM_ID, token_interface::Mint};
+
+use crate::{AccessControl, Proposal, ACCESS_CONTROL_SEED, PROPOSAL_PREFIX};
+
+#[derive(Accounts)]
+pub struct ExecuteSetMaxTotalSupplyProposal<'info> {
+    #[account(mut,
+      close = proposer,
+      constraint = proposal.access_control == access_control.key(),
+      seeds = [
+        PROPOSAL_PREFIX,
+        access_control.key().as_ref(),
+        &proposal.id.to_le_bytes(),
+      ],
+      bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+    /// CHECK: Proposer receiving the rent of the executed proposal
+    #[account(mut, address = proposal.proposer)]
+    pub proposer: AccountInfo<'info>,
+    #[account(mut,
+      seeds = [
+        ACCESS_CONTROL_SEED,
+        mint.key().as_ref(),
+      ],
+      bump,
+    )]
+    pub access_control: Account<'info, AccessControl>,
+
+    #[account(
+        mint::token_program = TOKEN_2022_PROGRAM_ID,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub executor: Signer<'info>,
+}
@@ -4,7 +4,10 @@ use anchor_spl::token_interface::{
 };
 use num_enum::IntoPrimitive;
 
-use crate::{contexts::common::DISCRIMINATOR_LEN, WalletRole, WALLET_ROLE_PREFIX};
+use crate::{
+    contexts::common::DISCRIMINATOR_LEN, errors::AccessControlError, ProposalActionType,
+    WalletRole, PROPOSAL_ACTION_TYPES_COUNT, WALLET_ROLE_PREFIX,
+};
 
 pub const ACCESS_CONTROL_SEED: &[u8] = b"ac"; // access_control
 
@@ -30,6 +33,65 @@ pub struct AccessControl {
     pub authority: Pubkey,
     pub max_total_supply: u64,
     pub lockup_escrow_account: Option<Pubkey>,
+    // Proposed authority until it accepts the handover
+    pub pending_authority: Option<Pubkey>,
+    // Number of wallets holding each admin role
+    pub contract_admin_count: u64,
+    pub reserve_admin_count: u64,
+    pub wallets_admin_count: u64,
+    pub transfer_admin_count: u64,
+    // Approvals required to execute a proposal per ProposalActionType
+    pub approval_thresholds: [u8; PROPOSAL_ACTION_TYPES_COUNT],
+    pub proposals_count: u64,
+}
+
+impl AccessControl {
+    /// Moves a wallet from the admin counts of `role_before` to the ones of `role`.
+    /// Fails if no Contract Admin or no Reserve Admin would be left, or if fewer Reserve Admins
+    /// would be left than an approval threshold requires.
+    pub fn update_admin_counts(&mut self, role_before: u8, role: u8) -> Result<()> {
+        let reserve_admin_count_before = self.reserve_admin_count;
+        for (admin_role, count, keep_last) in [
+            (Roles::ContractAdmin, &mut self.contract_admin_count, true),
+            (Roles::ReserveAdmin, &mut self.reserve_admin_count, true),
+            (Roles::WalletsAdmin, &mut self.wallets_admin_count, false),
+            (Roles::TransferAdmin, &mut self.transfer_admin_count, false),
+        ] {
+            let admin_role = admin_role as u8;
+            match (role_before & admin_role != 0, role & admin_role != 0) {
+                (false, true) => {
+                    *count = count
+                        .checked_add(1)
+                        .ok_or(AccessControlError::AdminCountOutOfSync)?
+                }
+                (true, false) => {
+                    *count = count
+                        .checked_sub(1)
+                        .ok_or(AccessControlError::AdminCountOutOfSync)?;
+                    if keep_last && *count == 0 {
+                        return Err(AccessControlError::CannotRemoveLastAdmin.into());
+                    }
+                }
+                _ => {}
+            }
+        }
+        let max_approval_threshold = self.approval_thresholds.iter().max().copied().unwrap_or(0);
+        if self.reserve_admin_count < reserve_admin_count_before
+            && self.reserve_admin_count < max_approval_threshold as u64
+        {
+            return Err(AccessControlError::ReserveAdminsBelowApprovalThreshold.into());
+        }
+        Ok(())
+    }
+
+    pub fn approval_threshold(&self, action_type: ProposalActionType) -> u8 {
+        self.approval_thresholds[action_type as usize]
+    }
+
+    /// Actions requiring more than one approval can only be executed through a proposal.
+    pub fn requires_proposal(&self, action_type: ProposalActionType) -> bool {
+        self.approval_threshold(action_type) > 1
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{WalletRole, ACCESS_CONTROL_SEED};
+
+#[derive(Accounts)]
+pub struct MigrateAccessControl<'info> {
+    /// CHECK: access control account created with an older layout, deserialized after realloc
+    #[account(mut,
+        owner = crate::ID,
+        seeds = [
+            ACCESS_CONTROL_SEED,
+            security_token.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub access_control: UncheckedAccount<'info>,
+
+    pub security_token: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = authority_wallet_role.owner == payer.key(),
+        constraint = authority_wallet_role.access_control == access_control.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -29,3 +29,42 @@ pub use set_lockup_escrow_account::*;
 
 pub mod set_max_total_supply;
 pub use set_max_total_supply::*;
+
+pub mod propose_authority;
+pub use propose_authority::*;
+
+pub mod accept_authority;
+pub use accept_authority::*;
+
+pub mod update_token_metadata;
+pub use update_token_metadata::*;
+
+pub mod revoke_wallet_role;
+pub use revoke_wallet_role::*;
+
+pub mod set_approval_threshold;
+pub use set_approval_threshold::*;
+
+pub mod create_proposal;
+pub use create_proposal::*;
+
+pub mod approve_proposal;
+pub use approve_proposal::*;
+
+pub mod execute_mint_securities_proposal;
+pub use execute_mint_securities_proposal::*;
+
+pub mod execute_burn_securities_proposal;
+pub use execute_burn_securities_proposal::*;
+
+pub mod execute_force_transfer_between_proposal;
+pub use execute_force_transfer_between_proposal::*;
+
+pub mod execute_set_max_total_supply_proposal;
+pub use execute_set_max_total_supply_proposal::*;
+
+pub mod migrate_access_control;
+pub use migrate_access_control::*;
+
+pub mod cancel_proposal;
+pub use cancel_proposal::*;
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{token_2022::ID as TOKEN_2022_PROGRAM_ID, token_interface::Mint};
+
+use crate::{errors::AccessControlError, AccessControl, ACCESS_CONTROL_SEED};
+
+#[derive(Accounts)]
+#[instruction(new_authority: Pubkey)]
+pub struct ProposeAuthority<'info> {
+    #[account(mut,
+      constraint = access_control_account.authority == authority.key() @ AccessControlError::Unauthorized,
+      seeds = [
+        ACCESS_CONTROL_SEED,
+        mint.key().as_ref(),
+      ],
+      bump,
+    )]
+    pub access_control_account: Account<'info, AccessControl>,
+
+    #[account(
+        mint::token_program = TOKEN_2022_PROGRAM_ID,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub authority: Signer<'info>,
+}
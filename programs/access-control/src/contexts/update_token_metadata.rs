@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::{errors::AccessControlError, AccessControl, WalletRole, ACCESS_CONTROL_SEED};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TokenMetadataField {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateTokenMetadataArgs {
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub uri: Option<String>,
+    pub additional_metadata: Vec<TokenMetadataField>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: UpdateTokenMetadataArgs)]
+pub struct UpdateTokenMetadata<'info> {
+    #[account(
+      constraint = access_control_account.authority == authority.key() @ AccessControlError::Unauthorized,
+      seeds = [
+        ACCESS_CONTROL_SEED,
+        mint.key().as_ref(),
+      ],
+      bump,
+    )]
+    pub access_control_account: Account<'info, AccessControl>,
+
+    #[account(mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = authority_wallet_role.owner == authority.key(),
+        constraint = authority_wallet_role.access_control == access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+
+    /// Metadata update authority of the mint
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
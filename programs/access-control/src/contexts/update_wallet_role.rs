@@ -28,7 +28,7 @@ pub struct UpdateWalletRole<'info> {
     bump,
   )]
   pub authority_wallet_role: Account<'info, WalletRole>,
-  #[account(
+  #[account(mut,
     constraint = security_token.key() == access_control.mint,
     seeds = [
       ACCESS_CONTROL_SEED,
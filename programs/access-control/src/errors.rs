@@ -22,4 +22,36 @@ pub enum AccessControlError {
     CannotFreezeLockupEscrowAccount,
     #[msg("The provided value is already set. No changes were made")]
     ValueUnchanged,
+    #[msg("New authority was not proposed")]
+    InvalidPendingAuthority,
+    #[msg("Cannot remove the last Contract Admin or Reserve Admin")]
+    CannotRemoveLastAdmin,
+    #[msg("Action requires an approved proposal")]
+    ProposalRequired,
+    #[msg("Invalid approval threshold")]
+    InvalidApprovalThreshold,
+    #[msg("Proposal is already approved by the wallet")]
+    ProposalAlreadyApproved,
+    #[msg("Proposal does not have enough approvals")]
+    NotEnoughApprovals,
+    #[msg("Accounts do not match the proposal action")]
+    ProposalActionMismatch,
+    #[msg("Proposal reached the max number of approvals")]
+    MaxApprovalsReached,
+    #[msg("Missing or invalid accounts to sync the holder balance")]
+    InvalidHolderBalanceAccounts,
+    #[msg("Reason is too long")]
+    ReasonTooLong,
+    #[msg("Tracked admin role count does not match the wallet roles")]
+    AdminCountOutOfSync,
+    #[msg("Account is already migrated")]
+    AccountAlreadyMigrated,
+    #[msg("Missing or invalid wallet role accounts")]
+    InvalidWalletRoleAccounts,
+    #[msg("Fewer Reserve Admins would be left than an approval threshold requires")]
+    ReserveAdminsBelowApprovalThreshold,
+    #[msg("Proposal expiration must be in the future")]
+    InvalidProposalExpiration,
+    #[msg("Proposal is expired")]
+    ProposalExpired,
 }
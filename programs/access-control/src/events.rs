@@ -0,0 +1,252 @@
+use anchor_lang::prelude::*;
+
+use crate::{ProposalAction, ProposalActionType, TokenMetadataField};
+
+/// Emitted when securities are minted.
+#[event]
+pub struct SecuritiesMintedEvent {
+    /// Security token mint.
+    pub mint: Pubkey,
+    /// Token account the securities are minted to.
+    pub destination_account: Pubkey,
+    /// Owner of the destination token account.
+    pub destination_authority: Pubkey,
+    /// Amount of minted securities.
+    pub amount: u64,
+    /// Admin who minted the securities.
+    pub authority: Pubkey,
+    /// Reason of the action, empty if not given.
+    pub reason: String,
+}
+
+/// Emitted when securities are burned.
+#[event]
+pub struct SecuritiesBurnedEvent {
+    /// Security token mint.
+    pub mint: Pubkey,
+    /// Token account the securities are burned from.
+    pub target_account: Pubkey,
+    /// Owner of the target token account.
+    pub target_authority: Pubkey,
+    /// Amount of burned securities.
+    pub amount: u64,
+    /// Admin who burned the securities.
+    pub authority: Pubkey,
+    /// Reason of the action, empty if not given.
+    pub reason: String,
+}
+
+/// Emitted when securities are force transferred between wallets.
+#[event]
+pub struct ForceTransferBetweenEvent {
+    /// Security token mint.
+    pub mint: Pubkey,
+    /// Token account the securities are transferred from.
+    pub source_account: Pubkey,
+    /// Owner of the source token account.
+    pub source_authority: Pubkey,
+    /// Token account the securities are transferred to.
+    pub destination_account: Pubkey,
+    /// Owner of the destination token account.
+    pub destination_authority: Pubkey,
+    /// Amount of transferred securities.
+    pub amount: u64,
+    /// Admin who transferred the securities.
+    pub authority: Pubkey,
+    /// Reason of the action, empty if not given.
+    pub reason: String,
+}
+
+/// Emitted when a wallet is frozen.
+#[event]
+pub struct WalletFrozenEvent {
+    /// Security token mint.
+    pub mint: Pubkey,
+    /// Frozen token account.
+    pub target_account: Pubkey,
+    /// Owner of the frozen token account.
+    pub target_authority: Pubkey,
+    /// Admin who froze the wallet.
+    pub authority: Pubkey,
+    /// Reason of the action, empty if not given.
+    pub reason: String,
+}
+
+/// Emitted when a wallet is thawed.
+#[event]
+pub struct WalletThawedEvent {
+    /// Security token mint.
+    pub mint: Pubkey,
+    /// Thawed token account.
+    pub target_account: Pubkey,
+    /// Owner of the thawed token account.
+    pub target_authority: Pubkey,
+    /// Admin who thawed the wallet.
+    pub authority: Pubkey,
+    /// Reason of the action, empty if not given.
+    pub reason: String,
+}
+
+/// Emitted when a role is granted to a new wallet or the roles of a wallet are changed.
+#[event]
+pub struct WalletRoleUpdatedEvent {
+    /// Wallet role account.
+    pub wallet_role: Pubkey,
+    /// Wallet the roles belong to.
+    pub wallet: Pubkey,
+    /// Previous roles of the wallet, 0 for a new wallet role.
+    pub role_before: u8,
+    /// New roles of the wallet.
+    pub role: u8,
+    /// Admin who changed the roles.
+    pub authority: Pubkey,
+}
+
+/// Emitted when a wallet role is revoked and its account is closed.
+#[event]
+pub struct WalletRoleRevokedEvent {
+    /// Closed wallet role account.
+    pub wallet_role: Pubkey,
+    /// Wallet the roles belonged to.
+    pub wallet: Pubkey,
+    /// Roles of the wallet before the revocation.
+    pub role: u8,
+    /// Admin who revoked the roles.
+    pub authority: Pubkey,
+}
+
+/// Emitted when the max total supply is changed.
+#[event]
+pub struct MaxTotalSupplySetEvent {
+    /// Security token mint.
+    pub mint: Pubkey,
+    /// Previous max total supply.
+    pub max_total_supply_before: u64,
+    /// New max total supply.
+    pub max_total_supply: u64,
+    /// Admin who changed the max total supply.
+    pub authority: Pubkey,
+}
+
+/// Emitted when a new authority of the mint transfer hook and metadata is proposed.
+#[event]
+pub struct AuthorityProposedEvent {
+    /// Security token mint.
+    pub mint: Pubkey,
+    /// Current authority who proposed the new authority.
+    pub authority: Pubkey,
+    /// Proposed authority.
+    pub pending_authority: Pubkey,
+}
+
+/// Emitted when the proposed authority accepts the handover.
+#[event]
+pub struct AuthorityTransferredEvent {
+    /// Security token mint.
+    pub mint: Pubkey,
+    /// Previous authority.
+    pub authority_before: Pubkey,
+    /// New authority.
+    pub authority: Pubkey,
+}
+
+/// Emitted when the mint metadata is updated.
+#[event]
+pub struct TokenMetadataUpdatedEvent {
+    /// Security token mint.
+    pub mint: Pubkey,
+    /// New name, if changed.
+    pub name: Option<String>,
+    /// New symbol, if changed.
+    pub symbol: Option<String>,
+    /// New uri, if changed.
+    pub uri: Option<String>,
+    /// Changed additional key/value pairs.
+    pub additional_metadata: Vec<TokenMetadataField>,
+    /// Admin who updated the metadata.
+    pub authority: Pubkey,
+}
+
+/// Emitted when the approvals required for an action type are changed.
+#[event]
+pub struct ApprovalThresholdSetEvent {
+    /// Security token mint.
+    pub mint: Pubkey,
+    /// Action type the threshold applies to.
+    pub action_type: ProposalActionType,
+    /// Previous number of required approvals.
+    pub threshold_before: u8,
+    /// New number of required approvals.
+    pub threshold: u8,
+    /// Admin who changed the threshold.
+    pub authority: Pubkey,
+}
+
+/// Emitted when a proposal is created.
+#[event]
+pub struct ProposalCreatedEvent {
+    /// Proposal account.
+    pub proposal: Pubkey,
+    /// Id of the proposal.
+    pub proposal_id: u64,
+    /// Proposed action.
+    pub action: ProposalAction,
+    /// Reason of the proposed action, empty if not given.
+    pub reason: String,
+    /// Unix timestamp from which the proposal can no longer be approved or executed.
+    pub expires_at: i64,
+    /// Admin who created the proposal.
+    pub proposer: Pubkey,
+}
+
+/// Emitted when a proposal is approved.
+#[event]
+pub struct ProposalApprovedEvent {
+    /// Proposal account.
+    pub proposal: Pubkey,
+    /// Id of the proposal.
+    pub proposal_id: u64,
+    /// Number of approvals including this one.
+    pub approvals: u8,
+    /// Admin who approved the proposal.
+    pub authority: Pubkey,
+}
+
+/// Emitted when an approved proposal is executed and closed.
+#[event]
+pub struct ProposalExecutedEvent {
+    /// Proposal account.
+    pub proposal: Pubkey,
+    /// Id of the proposal.
+    pub proposal_id: u64,
+    /// Number of approvals of the proposal.
+    pub approvals: u8,
+    /// Wallet which executed the proposal.
+    pub executor: Pubkey,
+}
+
+/// Emitted when a proposal is cancelled and closed.
+#[event]
+pub struct ProposalCancelledEvent {
+    /// Proposal account.
+    pub proposal: Pubkey,
+    /// Id of the proposal.
+    pub proposal_id: u64,
+    /// Proposer or Contract Admin who cancelled the proposal.
+    pub authority: Pubkey,
+}
+
+/// Emitted when the tokenlock escrow account is set.
+#[event]
+pub struct LockupEscrowAccountSetEvent {
+    /// Security token mint.
+    pub mint: Pubkey,
+    /// Previous escrow account.
+    pub lockup_escrow_account_before: Option<Pubkey>,
+    /// New escrow account.
+    pub lockup_escrow_account: Pubkey,
+    /// Tokenlock account of the escrow.
+    pub tokenlock_account: Pubkey,
+    /// Admin who set the escrow account.
+    pub authority: Pubkey,
+}
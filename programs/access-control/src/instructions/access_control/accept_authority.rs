@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::instruction::AuthorityType,
+    token_interface::{
+        set_authority, spl_pod::optional_keys::OptionalNonZeroPubkey,
+        token_metadata_update_authority, SetAuthority, TokenMetadataUpdateAuthority,
+    },
+};
+
+use crate::{events::AuthorityTransferredEvent, AcceptAuthority};
+
+/// Completes the authority handover: moves the transfer hook authority and
+/// the metadata update authority of the mint to the new authority.
+pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let new_authority = ctx.accounts.new_authority.key();
+    let mint_info = ctx.accounts.mint.to_account_info();
+    let authority_info = ctx.accounts.authority.to_account_info();
+    let token_program_info = ctx.accounts.token_program.to_account_info();
+
+    set_authority(
+        CpiContext::new(
+            token_program_info.clone(),
+            SetAuthority {
+                current_authority: authority_info.clone(),
+                account_or_mint: mint_info.clone(),
+            },
+        ),
+        AuthorityType::TransferHookProgramId,
+        Some(new_authority),
+    )?;
+    token_metadata_update_authority(
+        CpiContext::new(
+            token_program_info.clone(),
+            TokenMetadataUpdateAuthority {
+                token_program_id: token_program_info,
+                metadata: mint_info,
+                current_authority: authority_info,
+                new_authority: ctx.accounts.new_authority.to_account_info(),
+            },
+        ),
+        OptionalNonZeroPubkey(new_authority),
+    )?;
+
+    let access_control_account = &mut ctx.accounts.access_control_account;
+    let authority_before = access_control_account.authority;
+    access_control_account.authority = new_authority;
+    access_control_account.pending_authority = None;
+
+    emit!(AuthorityTransferredEvent {
+        mint: ctx.accounts.mint.key(),
+        authority_before,
+        authority: new_authority,
+    });
+    Ok(())
+}
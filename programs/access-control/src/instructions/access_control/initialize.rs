@@ -12,6 +12,8 @@ pub fn initialize(
     access_control.mint = *ctx.accounts.mint.to_account_info().key;
     access_control.authority = *ctx.accounts.authority.to_account_info().key;
     access_control.max_total_supply = args.max_total_supply;
+    access_control.pending_authority = None;
+    access_control.contract_admin_count = 1;
 
     ctx.accounts
         .initialize_token_metadata(ctx.program_id, args.name, args.symbol, args.uri)?;
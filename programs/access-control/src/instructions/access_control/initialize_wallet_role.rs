@@ -1,4 +1,7 @@
-use crate::{contexts::InitializeWalletRole, errors::AccessControlError, Roles};
+use crate::{
+    contexts::InitializeWalletRole, errors::AccessControlError, events::WalletRoleUpdatedEvent,
+    Roles,
+};
 use anchor_lang::prelude::*;
 
 pub fn initialize_wallet_role(ctx: Context<InitializeWalletRole>, role: u8) -> Result<()> {
@@ -13,10 +16,19 @@ pub fn initialize_wallet_role(ctx: Context<InitializeWalletRole>, role: u8) -> R
         return Err(AccessControlError::InvalidRole.into());
     }
 
+    ctx.accounts.access_control.update_admin_counts(0, role)?;
+
     let wallet_role = &mut ctx.accounts.wallet_role;
     wallet_role.role = role;
     wallet_role.owner = ctx.accounts.user_wallet.key();
     wallet_role.access_control = ctx.accounts.access_control.key();
 
+    emit!(WalletRoleUpdatedEvent {
+        wallet_role: wallet_role.key(),
+        wallet: wallet_role.owner,
+        role_before: 0,
+        role,
+        authority: ctx.accounts.payer.key(),
+    });
     Ok(())
 }
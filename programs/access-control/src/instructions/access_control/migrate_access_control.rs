@@ -0,0 +1,60 @@
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{
+    contexts::common::DISCRIMINATOR_LEN, errors::AccessControlError,
+    update_account_lamports_to_minimum_balance, AccessControl, MigrateAccessControl, Roles,
+    WalletRole,
+};
+
+/// Reallocates an access control account created before the authority handover, the admin role
+/// counts and the proposals were added. `remaining_accounts` are all wallet roles of the security
+/// token, which initialize the admin role counts. New fields are otherwise zero-filled, so no
+/// authority is pending after the migration.
+pub fn migrate_access_control(ctx: Context<MigrateAccessControl>) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::ContractAdmin)
+    {
+        return Err(AccessControlError::Unauthorized.into());
+    }
+
+    let account_info = ctx.accounts.access_control.to_account_info();
+    if account_info.try_borrow_data()?.get(..DISCRIMINATOR_LEN)
+        != Some(AccessControl::DISCRIMINATOR.as_slice())
+    {
+        return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+    let space = DISCRIMINATOR_LEN + AccessControl::INIT_SPACE;
+    if account_info.data_len() >= space {
+        return Err(AccessControlError::AccountAlreadyMigrated.into());
+    }
+
+    account_info.realloc(space, true)?;
+    update_account_lamports_to_minimum_balance(
+        account_info.clone(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let mut access_control = AccessControl::try_deserialize(&mut &account_info.data.borrow()[..])?;
+    let mut wallet_roles: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+    for wallet_role_info in ctx.remaining_accounts {
+        if *wallet_role_info.owner != crate::ID || wallet_roles.contains(wallet_role_info.key) {
+            return Err(AccessControlError::InvalidWalletRoleAccounts.into());
+        }
+        let wallet_role = WalletRole::try_deserialize(&mut &wallet_role_info.data.borrow()[..])?;
+        if wallet_role.access_control != account_info.key() {
+            return Err(AccessControlError::InvalidWalletRoleAccounts.into());
+        }
+        access_control.update_admin_counts(0, wallet_role.role)?;
+        wallet_roles.push(wallet_role_info.key());
+    }
+    if !wallet_roles.contains(&ctx.accounts.authority_wallet_role.key()) {
+        return Err(AccessControlError::InvalidWalletRoleAccounts.into());
+    }
+
+    access_control.try_serialize(&mut &mut account_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
@@ -12,3 +12,18 @@ pub use set_lockup_escrow_account::*;
 
 pub mod set_max_total_supply;
 pub use set_max_total_supply::*;
+
+pub mod propose_authority;
+pub use propose_authority::*;
+
+pub mod accept_authority;
+pub use accept_authority::*;
+
+pub mod update_token_metadata;
+pub use update_token_metadata::*;
+
+pub mod revoke_wallet_role;
+pub use revoke_wallet_role::*;
+
+pub mod migrate_access_control;
+pub use migrate_access_control::*;
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::AccessControlError, events::AuthorityProposedEvent, ProposeAuthority};
+
+/// Proposes the new authority of the mint transfer hook and metadata,
+/// which takes over once it accepts the handover with `accept_authority`.
+pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let access_control_account = &mut ctx.accounts.access_control_account;
+    if new_authority == access_control_account.authority
+        || access_control_account.pending_authority == Some(new_authority)
+    {
+        return Err(AccessControlError::ValueUnchanged.into());
+    }
+
+    access_control_account.pending_authority = Some(new_authority);
+
+    emit!(AuthorityProposedEvent {
+        mint: ctx.accounts.mint.key(),
+        authority: access_control_account.authority,
+        pending_authority: new_authority,
+    });
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use crate::{
+    contexts::RevokeWalletRole, errors::AccessControlError, events::WalletRoleRevokedEvent, Roles,
+};
+use anchor_lang::prelude::*;
+
+pub fn revoke_wallet_role(ctx: Context<RevokeWalletRole>) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::ContractAdmin)
+    {
+        return Err(AccessControlError::Unauthorized.into());
+    }
+
+    let wallet_role = &ctx.accounts.wallet_role;
+    ctx.accounts
+        .access_control
+        .update_admin_counts(wallet_role.role, 0)?;
+
+    emit!(WalletRoleRevokedEvent {
+        wallet_role: wallet_role.key(),
+        wallet: wallet_role.owner,
+        role: wallet_role.role,
+        authority: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
@@ -1,7 +1,9 @@
 use anchor_lang::{prelude::*, solana_program::program_memory::sol_memcmp, Discriminator};
 use tokenlock_accounts::{states::TokenLockData, wrappers::TokenLockDataWrapper};
 
-use crate::{errors::AccessControlError, Roles, SetLockupEscrowAccount};
+use crate::{
+    errors::AccessControlError, events::LockupEscrowAccountSetEvent, Roles, SetLockupEscrowAccount,
+};
 
 pub fn set_lockup_escrow_account(ctx: Context<SetLockupEscrowAccount>) -> Result<()> {
     if !ctx
@@ -29,7 +31,15 @@ pub fn set_lockup_escrow_account(ctx: Context<SetLockupEscrowAccount>) -> Result
     }
 
     let access_control_account = &mut ctx.accounts.access_control_account;
+    let lockup_escrow_account_before = access_control_account.lockup_escrow_account;
     access_control_account.lockup_escrow_account = Some(escrow_account);
 
+    emit!(LockupEscrowAccountSetEvent {
+        mint: ctx.accounts.mint.key(),
+        lockup_escrow_account_before,
+        lockup_escrow_account: escrow_account,
+        tokenlock_account: tokenlock_account.key(),
+        authority: ctx.accounts.payer.key(),
+    });
     Ok(())
 }
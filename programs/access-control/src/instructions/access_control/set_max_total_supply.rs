@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::AccessControlError, Roles, SetMaxTotalSupply};
+use crate::{
+    errors::AccessControlError, events::MaxTotalSupplySetEvent, AccessControl, ProposalActionType,
+    Roles, SetMaxTotalSupply,
+};
 
 pub fn set_max_total_supply(ctx: Context<SetMaxTotalSupply>, max_total_supply: u64) -> Result<()> {
     if !ctx
@@ -10,12 +13,36 @@ pub fn set_max_total_supply(ctx: Context<SetMaxTotalSupply>, max_total_supply: u
     {
         return Err(AccessControlError::Unauthorized.into());
     }
-    if max_total_supply <= ctx.accounts.access_control_account.max_total_supply {
-        return Err(AccessControlError::NewMaxTotalSupplyMustExceedCurrentTotalSupply.into());
+    if ctx
+        .accounts
+        .access_control_account
+        .requires_proposal(ProposalActionType::SetMaxTotalSupply)
+    {
+        return Err(AccessControlError::ProposalRequired.into());
     }
 
-    let access_control_account = &mut ctx.accounts.access_control_account;
-    access_control_account.max_total_supply = max_total_supply;
+    let max_total_supply_before =
+        update_max_total_supply(&mut ctx.accounts.access_control_account, max_total_supply)?;
 
+    emit!(MaxTotalSupplySetEvent {
+        mint: ctx.accounts.mint.key(),
+        max_total_supply_before,
+        max_total_supply,
+        authority: ctx.accounts.payer.key(),
+    });
     Ok(())
 }
+
+/// Raises the max total supply, returning the previous one.
+pub fn update_max_total_supply(
+    access_control: &mut AccessControl,
+    max_total_supply: u64,
+) -> Result<u64> {
+    if max_total_supply <= access_control.max_total_supply {
+        return Err(AccessControlError::NewMaxTotalSupplyMustExceedCurrentTotalSupply.into());
+    }
+
+    let max_total_supply_before = access_control.max_total_supply;
+    access_control.max_total_supply = max_total_supply;
+    Ok(max_total_supply_before)
+}
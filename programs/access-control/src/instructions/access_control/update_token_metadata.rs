@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    spl_token_metadata_interface::state::Field, token_metadata_update_field,
+    TokenMetadataUpdateField,
+};
+
+use crate::{
+    errors::AccessControlError, events::TokenMetadataUpdatedEvent,
+    update_account_lamports_to_minimum_balance, Roles, UpdateTokenMetadata,
+    UpdateTokenMetadataArgs,
+};
+
+/// Updates the name, symbol, uri and additional key/value pairs of the mint metadata,
+/// then tops up the mint rent for the resized metadata.
+pub fn update_token_metadata(
+    ctx: Context<UpdateTokenMetadata>,
+    args: UpdateTokenMetadataArgs,
+) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::ContractAdmin)
+    {
+        return Err(AccessControlError::Unauthorized.into());
+    }
+
+    let fields = [
+        (Field::Name, &args.name),
+        (Field::Symbol, &args.symbol),
+        (Field::Uri, &args.uri),
+    ]
+    .into_iter()
+    .filter_map(|(field, value)| value.clone().map(|value| (field, value)))
+    .chain(
+        args.additional_metadata
+            .iter()
+            .map(|field| (Field::Key(field.key.clone()), field.value.clone())),
+    );
+    for (field, value) in fields {
+        let cpi_accounts = TokenMetadataUpdateField {
+            token_program_id: ctx.accounts.token_program.to_account_info(),
+            metadata: ctx.accounts.mint.to_account_info(),
+            update_authority: ctx.accounts.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token_metadata_update_field(cpi_ctx, field, value)?;
+    }
+
+    update_account_lamports_to_minimum_balance(
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(TokenMetadataUpdatedEvent {
+        mint: ctx.accounts.mint.key(),
+        name: args.name,
+        symbol: args.symbol,
+        uri: args.uri,
+        additional_metadata: args.additional_metadata,
+        authority: ctx.accounts.authority.key(),
+    });
+    Ok(())
+}
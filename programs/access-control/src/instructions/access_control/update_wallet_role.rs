@@ -1,4 +1,6 @@
-use crate::{contexts::UpdateWalletRole, errors::AccessControlError, Roles};
+use crate::{
+    contexts::UpdateWalletRole, errors::AccessControlError, events::WalletRoleUpdatedEvent, Roles,
+};
 use anchor_lang::prelude::*;
 
 pub fn update_wallet_role(ctx: Context<UpdateWalletRole>, role: u8) -> Result<()> {
@@ -17,7 +19,18 @@ pub fn update_wallet_role(ctx: Context<UpdateWalletRole>, role: u8) -> Result<()
     }
 
     let wallet_role = &mut ctx.accounts.wallet_role;
+    let role_before = wallet_role.role;
+    ctx.accounts
+        .access_control
+        .update_admin_counts(role_before, role)?;
     wallet_role.role = role;
 
+    emit!(WalletRoleUpdatedEvent {
+        wallet_role: wallet_role.key(),
+        wallet: wallet_role.owner,
+        role_before,
+        role,
+        authority: ctx.accounts.payer.key(),
+    });
     Ok(())
 }
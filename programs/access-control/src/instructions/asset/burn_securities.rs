@@ -1,9 +1,16 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{burn, Burn};
+use anchor_spl::token_interface::{burn, Burn, Mint, TokenAccount};
 
-use crate::{errors::AccessControlError, BurnSecurities, ACCESS_CONTROL_SEED};
+use crate::{
+    check_reason, errors::AccessControlError, events::SecuritiesBurnedEvent, sync_holder_balance,
+    AccessControl, BurnSecurities, ProposalActionType, ACCESS_CONTROL_SEED,
+};
 
-pub fn burn_securities(ctx: Context<BurnSecurities>, amount: u64) -> Result<()> {
+pub fn burn_securities<'info>(
+    ctx: Context<'_, '_, '_, 'info, BurnSecurities<'info>>,
+    amount: u64,
+    reason: String,
+) -> Result<()> {
     if !ctx
         .accounts
         .authority_wallet_role
@@ -12,25 +19,69 @@ pub fn burn_securities(ctx: Context<BurnSecurities>, amount: u64) -> Result<()>
         return Err(AccessControlError::Unauthorized.into());
     }
 
-    if ctx.accounts.access_control.lockup_escrow_account == Some(ctx.accounts.target_account.key())
+    if ctx
+        .accounts
+        .access_control
+        .requires_proposal(ProposalActionType::BurnSecurities)
     {
+        return Err(AccessControlError::ProposalRequired.into());
+    }
+    check_reason(&reason)?;
+
+    burn_from_account(
+        &ctx.accounts.access_control,
+        &ctx.accounts.security_mint,
+        &ctx.accounts.target_account,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.remaining_accounts,
+        ctx.program_id,
+        amount,
+    )?;
+
+    emit!(SecuritiesBurnedEvent {
+        mint: ctx.accounts.security_mint.key(),
+        target_account: ctx.accounts.target_account.key(),
+        target_authority: ctx.accounts.target_authority.key(),
+        amount,
+        authority: ctx.accounts.authority.key(),
+        reason,
+    });
+    Ok(())
+}
+
+/// Burns securities signed by the access control PDA, outside of the lockup escrow,
+/// and syncs the target holder balance with `remaining_accounts` (see `sync_holder_balance`).
+pub fn burn_from_account<'info>(
+    access_control: &Account<'info, AccessControl>,
+    security_mint: &InterfaceAccount<'info, Mint>,
+    target_account: &InterfaceAccount<'info, TokenAccount>,
+    token_program: AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    program_id: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    if access_control.lockup_escrow_account == Some(target_account.key()) {
         return Err(AccessControlError::CantBurnSecuritiesWithinLockup.into());
     }
 
-    let mint = ctx.accounts.security_mint.to_account_info();
+    let mint = security_mint.to_account_info();
     let accounts = Burn {
         mint: mint.clone(),
-        from: ctx.accounts.target_account.to_account_info(),
-        authority: ctx.accounts.access_control.to_account_info(),
+        from: target_account.to_account_info(),
+        authority: access_control.to_account_info(),
     };
-    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), accounts);
+    let cpi_ctx = CpiContext::new(token_program, accounts);
 
     let (_pda, bump_seed) =
-        Pubkey::find_program_address(&[ACCESS_CONTROL_SEED, mint.key.as_ref()], ctx.program_id);
+        Pubkey::find_program_address(&[ACCESS_CONTROL_SEED, mint.key.as_ref()], program_id);
 
     let seeds = &[ACCESS_CONTROL_SEED, mint.key.as_ref(), &[bump_seed]];
 
     burn(cpi_ctx.with_signer(&[&seeds[..]]), amount)?;
 
-    Ok(())
+    sync_holder_balance(
+        security_mint,
+        target_account.to_account_info(),
+        remaining_accounts,
+    )
 }
@@ -1,11 +1,18 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked;
+use anchor_spl::{
+    token_2022::spl_token_2022::onchain::invoke_transfer_checked,
+    token_interface::{Mint, TokenAccount},
+};
 
-use crate::{errors::AccessControlError, ForceTransferBetween, Roles, ACCESS_CONTROL_SEED};
+use crate::{
+    check_reason, errors::AccessControlError, events::ForceTransferBetweenEvent, AccessControl,
+    ForceTransferBetween, ProposalActionType, Roles, ACCESS_CONTROL_SEED,
+};
 
 pub fn force_transfer_beetween<'info>(
     ctx: Context<'_, '_, '_, 'info, ForceTransferBetween<'info>>,
     amount: u64,
+    reason: String,
 ) -> Result<()> {
     if !ctx
         .accounts
@@ -15,41 +22,82 @@ pub fn force_transfer_beetween<'info>(
         return Err(AccessControlError::Unauthorized.into());
     }
 
-    if ctx.accounts.access_control_account.lockup_escrow_account
-        == Some(ctx.accounts.source_account.key())
-        || ctx.accounts.access_control_account.lockup_escrow_account
-            == Some(ctx.accounts.destination_account.key())
+    if ctx
+        .accounts
+        .access_control_account
+        .requires_proposal(ProposalActionType::ForceTransferBetween)
+    {
+        return Err(AccessControlError::ProposalRequired.into());
+    }
+    check_reason(&reason)?;
+
+    force_transfer(
+        &ctx.accounts.access_control_account,
+        &ctx.accounts.security_mint,
+        &ctx.accounts.source_account,
+        &ctx.accounts.destination_account,
+        ctx.accounts.token_program.key,
+        ctx.remaining_accounts,
+        ctx.program_id,
+        amount,
+    )?;
+
+    emit!(ForceTransferBetweenEvent {
+        mint: ctx.accounts.security_mint.key(),
+        source_account: ctx.accounts.source_account.key(),
+        source_authority: ctx.accounts.source_authority.key(),
+        destination_account: ctx.accounts.destination_account.key(),
+        destination_authority: ctx.accounts.destination_authority.key(),
+        amount,
+        authority: ctx.accounts.authority.key(),
+        reason,
+    });
+    Ok(())
+}
+
+/// Transfers securities with the access control PDA as permanent delegate,
+/// outside of the lockup escrow.
+#[allow(clippy::too_many_arguments)]
+pub fn force_transfer<'info>(
+    access_control: &Account<'info, AccessControl>,
+    security_mint: &InterfaceAccount<'info, Mint>,
+    source_account: &InterfaceAccount<'info, TokenAccount>,
+    destination_account: &InterfaceAccount<'info, TokenAccount>,
+    token_program_id: &Pubkey,
+    remaining_accounts: &[AccountInfo<'info>],
+    program_id: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    if access_control.lockup_escrow_account == Some(source_account.key())
+        || access_control.lockup_escrow_account == Some(destination_account.key())
     {
         return Err(AccessControlError::CantForceTransferBetweenLockup.into());
     }
 
-    let mint = ctx.accounts.security_mint.to_account_info();
+    let mint = security_mint.to_account_info();
     let (_pda, bump_seed) =
-        Pubkey::find_program_address(&[ACCESS_CONTROL_SEED, mint.key.as_ref()], ctx.program_id);
+        Pubkey::find_program_address(&[ACCESS_CONTROL_SEED, mint.key.as_ref()], program_id);
 
     let seeds = &[ACCESS_CONTROL_SEED, mint.key.as_ref(), &[bump_seed]];
 
-    let token_program_id = ctx.accounts.token_program.key;
-    let source_info = ctx.accounts.source_account.to_account_info();
-    let mint_info = ctx.accounts.security_mint.to_account_info();
-    let destination_info = ctx.accounts.destination_account.to_account_info();
+    let source_info = source_account.to_account_info();
+    let destination_info = destination_account.to_account_info();
     // https://solana.com/developers/guides/token-extensions/permanent-delegate#transfer-with-permanent-delegate
     // To transfer tokens using the Permanent Delegate, use the transferChecked instruction
     // and specify the Permanent Delegate as the owner of the sourceTokenAccount.
-    let authority_info = ctx.accounts.access_control_account.to_account_info();
-    let decimals = ctx.accounts.security_mint.decimals;
+    let authority_info = access_control.to_account_info();
+    let decimals = security_mint.decimals;
 
     invoke_transfer_checked(
         token_program_id,
-        source_info.clone(),
-        mint_info.clone(),
-        destination_info.clone(),
-        authority_info.clone(),
-        ctx.remaining_accounts,
+        source_info,
+        mint,
+        destination_info,
+        authority_info,
+        remaining_accounts,
         amount,
         decimals,
         &[&seeds[..]],
     )?;
-
     Ok(())
 }
@@ -1,9 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{freeze_account, FreezeAccount};
 
-use crate::{errors::AccessControlError, FreezeWallet, ACCESS_CONTROL_SEED};
+use crate::{
+    check_reason, errors::AccessControlError, events::WalletFrozenEvent, FreezeWallet,
+    ACCESS_CONTROL_SEED,
+};
 
-pub fn freeze_wallet(ctx: Context<FreezeWallet>) -> Result<()> {
+pub fn freeze_wallet(ctx: Context<FreezeWallet>, reason: String) -> Result<()> {
     if !ctx
         .accounts
         .authority_wallet_role
@@ -11,6 +14,7 @@ pub fn freeze_wallet(ctx: Context<FreezeWallet>) -> Result<()> {
     {
         return Err(AccessControlError::Unauthorized.into());
     }
+    check_reason(&reason)?;
     if ctx.accounts.access_control.lockup_escrow_account == Some(ctx.accounts.target_account.key())
     {
         return Err(AccessControlError::CannotFreezeLockupEscrowAccount.into());
@@ -31,5 +35,12 @@ pub fn freeze_wallet(ctx: Context<FreezeWallet>) -> Result<()> {
 
     freeze_account(cpi_ctx.with_signer(&[&seeds[..]]))?;
 
+    emit!(WalletFrozenEvent {
+        mint: mint.key(),
+        target_account: ctx.accounts.target_account.key(),
+        target_authority: ctx.accounts.target_authority.key(),
+        authority: ctx.accounts.authority.key(),
+        reason,
+    });
     Ok(())
 }
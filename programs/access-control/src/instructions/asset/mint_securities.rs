@@ -1,9 +1,16 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{mint_to, MintTo};
+use anchor_spl::token_interface::{mint_to, Mint, MintTo};
 
-use crate::{errors::AccessControlError, MintSecurities, ACCESS_CONTROL_SEED};
+use crate::{
+    check_reason, errors::AccessControlError, events::SecuritiesMintedEvent, sync_holder_balance,
+    AccessControl, MintSecurities, ProposalActionType, ACCESS_CONTROL_SEED,
+};
 
-pub fn mint_securities(ctx: Context<MintSecurities>, amount: u64) -> Result<()> {
+pub fn mint_securities<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintSecurities<'info>>,
+    amount: u64,
+    reason: String,
+) -> Result<()> {
     if !ctx
         .accounts
         .authority_wallet_role
@@ -12,29 +19,69 @@ pub fn mint_securities(ctx: Context<MintSecurities>, amount: u64) -> Result<()>
         return Err(AccessControlError::Unauthorized.into());
     }
 
-    let new_supply = ctx
+    if ctx
         .accounts
-        .security_mint
-        .supply
-        .checked_add(amount)
-        .unwrap();
-    if new_supply > ctx.accounts.access_control.max_total_supply {
+        .access_control
+        .requires_proposal(ProposalActionType::MintSecurities)
+    {
+        return Err(AccessControlError::ProposalRequired.into());
+    }
+    check_reason(&reason)?;
+
+    mint_to_account(
+        &ctx.accounts.access_control,
+        &ctx.accounts.security_mint,
+        ctx.accounts.destination_account.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        ctx.remaining_accounts,
+        ctx.program_id,
+        amount,
+    )?;
+
+    emit!(SecuritiesMintedEvent {
+        mint: ctx.accounts.security_mint.key(),
+        destination_account: ctx.accounts.destination_account.key(),
+        destination_authority: ctx.accounts.destination_authority.key(),
+        amount,
+        authority: ctx.accounts.authority.key(),
+        reason,
+    });
+    Ok(())
+}
+
+/// Mints securities signed by the access control PDA, within the max total supply,
+/// and syncs the destination holder balance with `remaining_accounts` (see `sync_holder_balance`).
+pub fn mint_to_account<'info>(
+    access_control: &Account<'info, AccessControl>,
+    security_mint: &InterfaceAccount<'info, Mint>,
+    destination_account: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    program_id: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let new_supply = security_mint.supply.checked_add(amount).unwrap();
+    if new_supply > access_control.max_total_supply {
         return Err(AccessControlError::MintExceedsMaxTotalSupply.into());
     }
-    let mint = ctx.accounts.security_mint.to_account_info();
+    let mint = security_mint.to_account_info();
     let accounts = MintTo {
         mint: mint.clone(),
-        to: ctx.accounts.destination_account.to_account_info(),
-        authority: ctx.accounts.access_control.to_account_info(),
+        to: destination_account.clone(),
+        authority: access_control.to_account_info(),
     };
-    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), accounts);
+    let cpi_ctx = CpiContext::new(token_program, accounts);
 
     let (_pda, bump_seed) =
-        Pubkey::find_program_address(&[ACCESS_CONTROL_SEED, mint.key.as_ref()], ctx.program_id);
+        Pubkey::find_program_address(&[ACCESS_CONTROL_SEED, mint.key.as_ref()], program_id);
 
     let seeds = &[ACCESS_CONTROL_SEED, mint.key.as_ref(), &[bump_seed]];
 
     mint_to(cpi_ctx.with_signer(&[&seeds[..]]), amount)?;
 
-    Ok(())
+    // lockup escrow balance is not tracked per holder
+    if access_control.lockup_escrow_account == Some(destination_account.key()) {
+        return Ok(());
+    }
+    sync_holder_balance(security_mint, destination_account, remaining_accounts)
 }
@@ -1,9 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{thaw_account, ThawAccount};
 
-use crate::{errors::AccessControlError, ThawWallet, ACCESS_CONTROL_SEED};
+use crate::{
+    check_reason, errors::AccessControlError, events::WalletThawedEvent, ThawWallet,
+    ACCESS_CONTROL_SEED,
+};
 
-pub fn thaw_wallet(ctx: Context<ThawWallet>) -> Result<()> {
+pub fn thaw_wallet(ctx: Context<ThawWallet>, reason: String) -> Result<()> {
     if !ctx
         .accounts
         .authority_wallet_role
@@ -11,6 +14,7 @@ pub fn thaw_wallet(ctx: Context<ThawWallet>) -> Result<()> {
     {
         return Err(AccessControlError::Unauthorized.into());
     }
+    check_reason(&reason)?;
 
     let mint = ctx.accounts.security_mint.to_account_info();
     let accounts = ThawAccount {
@@ -27,5 +31,12 @@ pub fn thaw_wallet(ctx: Context<ThawWallet>) -> Result<()> {
 
     thaw_account(cpi_ctx.with_signer(&[&seeds[..]]))?;
 
+    emit!(WalletThawedEvent {
+        mint: mint.key(),
+        target_account: ctx.accounts.target_account.key(),
+        target_authority: ctx.accounts.target_authority.key(),
+        authority: ctx.accounts.authority.key(),
+        reason,
+    });
     Ok(())
 }
@@ -1,3 +1,5 @@
 pub mod access_control;
 
-pub mod asset;
\ No newline at end of file
+pub mod asset;
+
+pub mod proposal;
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AccessControlError, events::ProposalApprovedEvent, ApproveProposal, Roles,
+    MAX_PROPOSAL_APPROVALS,
+};
+
+pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::ReserveAdmin)
+    {
+        return Err(AccessControlError::Unauthorized.into());
+    }
+
+    let authority = ctx.accounts.authority.key();
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.ensure_not_expired()?;
+    if proposal.approvals.contains(&authority) {
+        return Err(AccessControlError::ProposalAlreadyApproved.into());
+    }
+    if proposal.approvals.len() >= MAX_PROPOSAL_APPROVALS as usize {
+        return Err(AccessControlError::MaxApprovalsReached.into());
+    }
+    proposal.approvals.push(authority);
+
+    emit!(ProposalApprovedEvent {
+        proposal: proposal.key(),
+        proposal_id: proposal.id,
+        approvals: proposal.approvals.len() as u8,
+        authority,
+    });
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::AccessControlError, events::ProposalCancelledEvent, CancelProposal, Roles};
+
+/// Cancels a proposal by its proposer or a Contract Admin. The proposal account is closed and
+/// its rent is refunded to the proposer.
+pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let proposal = &ctx.accounts.proposal;
+    if authority != proposal.proposer
+        && !ctx
+            .accounts
+            .authority_wallet_role
+            .has_role(Roles::ContractAdmin)
+    {
+        return Err(AccessControlError::Unauthorized.into());
+    }
+
+    emit!(ProposalCancelledEvent {
+        proposal: proposal.key(),
+        proposal_id: proposal.id,
+        authority,
+    });
+    Ok(())
+}
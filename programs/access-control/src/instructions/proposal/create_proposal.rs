@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    check_reason, errors::AccessControlError, events::ProposalCreatedEvent, CreateProposal,
+    ProposalAction, Roles,
+};
+
+/// Creates a proposal to execute `action`, approved by the proposer, until `expires_at`.
+/// The reason is recorded in the event of the executed action.
+pub fn create_proposal(
+    ctx: Context<CreateProposal>,
+    action: ProposalAction,
+    reason: String,
+    expires_at: i64,
+) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::ReserveAdmin)
+    {
+        return Err(AccessControlError::Unauthorized.into());
+    }
+    check_reason(&reason)?;
+    if expires_at <= Clock::get()?.unix_timestamp {
+        return Err(AccessControlError::InvalidProposalExpiration.into());
+    }
+
+    let access_control = &mut ctx.accounts.access_control;
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.access_control = access_control.key();
+    proposal.id = access_control.proposals_count;
+    proposal.proposer = ctx.accounts.payer.key();
+    proposal.action = action.clone();
+    proposal.reason = reason.clone();
+    proposal.approvals = vec![ctx.accounts.payer.key()];
+    proposal.expires_at = expires_at;
+    access_control.proposals_count = access_control.proposals_count.checked_add(1).unwrap();
+
+    emit!(ProposalCreatedEvent {
+        proposal: proposal.key(),
+        proposal_id: proposal.id,
+        action,
+        reason,
+        expires_at,
+        proposer: proposal.proposer,
+    });
+    Ok(())
+}
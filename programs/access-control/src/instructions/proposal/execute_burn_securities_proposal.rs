@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AccessControlError,
+    events::{ProposalExecutedEvent, SecuritiesBurnedEvent},
+    instructions::asset::burn_from_account,
+    ExecuteBurnSecuritiesProposal, ProposalAction,
+};
+
+pub fn execute_burn_securities_proposal<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteBurnSecuritiesProposal<'info>>,
+) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    let amount = match proposal.action {
+        ProposalAction::BurnSecurities {
+            target_account,
+            amount,
+        } if target_account == ctx.accounts.target_account.key() => amount,
+        _ => return Err(AccessControlError::ProposalActionMismatch.into()),
+    };
+    let remaining_accounts =
+        proposal.ensure_approved(&ctx.accounts.access_control, ctx.remaining_accounts)?;
+
+    burn_from_account(
+        &ctx.accounts.access_control,
+        &ctx.accounts.security_mint,
+        &ctx.accounts.target_account,
+        ctx.accounts.token_program.to_account_info(),
+        remaining_accounts,
+        ctx.program_id,
+        amount,
+    )?;
+
+    emit!(SecuritiesBurnedEvent {
+        mint: ctx.accounts.security_mint.key(),
+        target_account: ctx.accounts.target_account.key(),
+        target_authority: ctx.accounts.target_authority.key(),
+        amount,
+        authority: proposal.proposer,
+        reason: proposal.reason.clone(),
+    });
+    emit!(ProposalExecutedEvent {
+        proposal: proposal.key(),
+        proposal_id: proposal.id,
+        approvals: proposal.approvals.len() as u8,
+        executor: ctx.accounts.executor.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AccessControlError,
+    events::{ForceTransferBetweenEvent, ProposalExecutedEvent},
+    instructions::asset::force_transfer,
+    ExecuteForceTransferBetweenProposal, ProposalAction,
+};
+
+pub fn execute_force_transfer_between_proposal<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteForceTransferBetweenProposal<'info>>,
+) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    let amount = match proposal.action {
+        ProposalAction::ForceTransferBetween {
+            source_account,
+            destination_account,
+            amount,
+        } if source_account == ctx.accounts.source_account.key()
+            && destination_account == ctx.accounts.destination_account.key() =>
+        {
+            amount
+        }
+        _ => return Err(AccessControlError::ProposalActionMismatch.into()),
+    };
+    let remaining_accounts =
+        proposal.ensure_approved(&ctx.accounts.access_control, ctx.remaining_accounts)?;
+
+    force_transfer(
+        &ctx.accounts.access_control,
+        &ctx.accounts.security_mint,
+        &ctx.accounts.source_account,
+        &ctx.accounts.destination_account,
+        ctx.accounts.token_program.key,
+        remaining_accounts,
+        ctx.program_id,
+        amount,
+    )?;
+
+    emit!(ForceTransferBetweenEvent {
+        mint: ctx.accounts.security_mint.key(),
+        source_account: ctx.accounts.source_account.key(),
+        source_authority: ctx.accounts.source_authority.key(),
+        destination_account: ctx.accounts.destination_account.key(),
+        destination_authority: ctx.accounts.destination_authority.key(),
+        amount,
+        authority: proposal.proposer,
+        reason: proposal.reason.clone(),
+    });
+    emit!(ProposalExecutedEvent {
+        proposal: proposal.key(),
+        proposal_id: proposal.id,
+        approvals: proposal.approvals.len() as u8,
+        executor: ctx.accounts.executor.key(),
+    });
+    Ok(())
+}
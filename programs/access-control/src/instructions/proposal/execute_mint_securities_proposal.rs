@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AccessControlError,
+    events::{ProposalExecutedEvent, SecuritiesMintedEvent},
+    instructions::asset::mint_to_account,
+    ExecuteMintSecuritiesProposal, ProposalAction,
+};
+
+pub fn execute_mint_securities_proposal<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteMintSecuritiesProposal<'info>>,
+) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    let amount = match proposal.action {
+        ProposalAction::MintSecurities {
+            destination_account,
+            amount,
+        } if destination_account == ctx.accounts.destination_account.key() => amount,
+        _ => return Err(AccessControlError::ProposalActionMismatch.into()),
+    };
+    let remaining_accounts =
+        proposal.ensure_approved(&ctx.accounts.access_control, ctx.remaining_accounts)?;
+
+    mint_to_account(
+        &ctx.accounts.access_control,
+        &ctx.accounts.security_mint,
+        ctx.accounts.destination_account.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        remaining_accounts,
+        ctx.program_id,
+        amount,
+    )?;
+
+    emit!(SecuritiesMintedEvent {
+        mint: ctx.accounts.security_mint.key(),
+        destination_account: ctx.accounts.destination_account.key(),
+        destination_authority: ctx.accounts.destination_authority.key(),
+        amount,
+        authority: proposal.proposer,
+        reason: proposal.reason.clone(),
+    });
+    emit!(ProposalExecutedEvent {
+        proposal: proposal.key(),
+        proposal_id: proposal.id,
+        approvals: proposal.approvals.len() as u8,
+        executor: ctx.accounts.executor.key(),
+    });
+    Ok(())
+}
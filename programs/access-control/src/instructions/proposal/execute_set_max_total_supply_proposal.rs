@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AccessControlError,
+    events::{MaxTotalSupplySetEvent, ProposalExecutedEvent},
+    instructions::access_control::update_max_total_supply,
+    ExecuteSetMaxTotalSupplyProposal, ProposalAction,
+};
+
+pub fn execute_set_max_total_supply_proposal(
+    ctx: Context<ExecuteSetMaxTotalSupplyProposal>,
+) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    let max_total_supply = match proposal.action {
+        ProposalAction::SetMaxTotalSupply { max_total_supply } => max_total_supply,
+        _ => return Err(AccessControlError::ProposalActionMismatch.into()),
+    };
+    proposal.ensure_approved(&ctx.accounts.access_control, ctx.remaining_accounts)?;
+
+    let max_total_supply_before =
+        update_max_total_supply(&mut ctx.accounts.access_control, max_total_supply)?;
+
+    emit!(MaxTotalSupplySetEvent {
+        mint: ctx.accounts.mint.key(),
+        max_total_supply_before,
+        max_total_supply,
+        authority: proposal.proposer,
+    });
+    emit!(ProposalExecutedEvent {
+        proposal: proposal.key(),
+        proposal_id: proposal.id,
+        approvals: proposal.approvals.len() as u8,
+        executor: ctx.accounts.executor.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,23 @@
+pub mod set_approval_threshold;
+pub use set_approval_threshold::*;
+
+pub mod create_proposal;
+pub use create_proposal::*;
+
+pub mod approve_proposal;
+pub use approve_proposal::*;
+
+pub mod execute_mint_securities_proposal;
+pub use execute_mint_securities_proposal::*;
+
+pub mod execute_burn_securities_proposal;
+pub use execute_burn_securities_proposal::*;
+
+pub mod execute_force_transfer_between_proposal;
+pub use execute_force_transfer_between_proposal::*;
+
+pub mod execute_set_max_total_supply_proposal;
+pub use execute_set_max_total_supply_proposal::*;
+
+pub mod cancel_proposal;
+pub use cancel_proposal::*;
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AccessControlError, events::ApprovalThresholdSetEvent, ProposalActionType, Roles,
+    SetApprovalThreshold, MAX_PROPOSAL_APPROVALS,
+};
+
+pub fn set_approval_threshold(
+    ctx: Context<SetApprovalThreshold>,
+    action_type: ProposalActionType,
+    threshold: u8,
+) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::ContractAdmin)
+    {
+        return Err(AccessControlError::Unauthorized.into());
+    }
+
+    let access_control_account = &mut ctx.accounts.access_control_account;
+    // Proposals are approved by Reserve Admins, so a higher threshold could never be reached
+    if threshold > MAX_PROPOSAL_APPROVALS
+        || threshold as u64 > access_control_account.reserve_admin_count
+    {
+        return Err(AccessControlError::InvalidApprovalThreshold.into());
+    }
+    let threshold_before = access_control_account.approval_threshold(action_type);
+    if threshold == threshold_before {
+        return Err(AccessControlError::ValueUnchanged.into());
+    }
+    access_control_account.approval_thresholds[action_type as usize] = threshold;
+
+    emit!(ApprovalThresholdSetEvent {
+        mint: ctx.accounts.mint.key(),
+        action_type,
+        threshold_before,
+        threshold,
+        authority: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
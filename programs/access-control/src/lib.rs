@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 
 pub mod contexts;
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod utils;
 
@@ -29,27 +30,40 @@ pub mod access_control {
         instructions::access_control::update_wallet_role(ctx, role)
     }
 
-    pub fn mint_securities(ctx: Context<MintSecurities>, amount: u64) -> Result<()> {
-        instructions::asset::mint_securities(ctx, amount)
+    pub fn revoke_wallet_role(ctx: Context<RevokeWalletRole>) -> Result<()> {
+        instructions::access_control::revoke_wallet_role(ctx)
     }
 
-    pub fn burn_securities(ctx: Context<BurnSecurities>, amount: u64) -> Result<()> {
-        instructions::asset::burn_securities(ctx, amount)
+    pub fn mint_securities<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintSecurities<'info>>,
+        amount: u64,
+        reason: String,
+    ) -> Result<()> {
+        instructions::asset::mint_securities(ctx, amount, reason)
+    }
+
+    pub fn burn_securities<'info>(
+        ctx: Context<'_, '_, '_, 'info, BurnSecurities<'info>>,
+        amount: u64,
+        reason: String,
+    ) -> Result<()> {
+        instructions::asset::burn_securities(ctx, amount, reason)
     }
 
     pub fn force_transfer_between<'info>(
         ctx: Context<'_, '_, '_, 'info, ForceTransferBetween<'info>>,
         amount: u64,
+        reason: String,
     ) -> Result<()> {
-        instructions::asset::force_transfer_beetween(ctx, amount)
+        instructions::asset::force_transfer_beetween(ctx, amount, reason)
     }
 
-    pub fn freeze_wallet(ctx: Context<FreezeWallet>) -> Result<()> {
-        instructions::asset::freeze_wallet(ctx)
+    pub fn freeze_wallet(ctx: Context<FreezeWallet>, reason: String) -> Result<()> {
+        instructions::asset::freeze_wallet(ctx, reason)
     }
 
-    pub fn thaw_wallet(ctx: Context<ThawWallet>) -> Result<()> {
-        instructions::asset::thaw_wallet(ctx)
+    pub fn thaw_wallet(ctx: Context<ThawWallet>, reason: String) -> Result<()> {
+        instructions::asset::thaw_wallet(ctx, reason)
     }
 
     pub fn set_lockup_escrow_account(ctx: Context<SetLockupEscrowAccount>) -> Result<()> {
@@ -59,4 +73,72 @@ pub mod access_control {
     pub fn set_max_total_supply(ctx: Context<SetMaxTotalSupply>, max_total_supply: u64) -> Result<()> {
         instructions::access_control::set_max_total_supply(ctx, max_total_supply)
     }
+
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::access_control::propose_authority(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::access_control::accept_authority(ctx)
+    }
+
+    pub fn update_token_metadata(
+        ctx: Context<UpdateTokenMetadata>,
+        args: UpdateTokenMetadataArgs,
+    ) -> Result<()> {
+        instructions::access_control::update_token_metadata(ctx, args)
+    }
+
+    pub fn set_approval_threshold(
+        ctx: Context<SetApprovalThreshold>,
+        action_type: ProposalActionType,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::proposal::set_approval_threshold(ctx, action_type, threshold)
+    }
+
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        action: ProposalAction,
+        reason: String,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::proposal::create_proposal(ctx, action, reason, expires_at)
+    }
+
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        instructions::proposal::approve_proposal(ctx)
+    }
+
+    pub fn execute_mint_securities_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteMintSecuritiesProposal<'info>>,
+    ) -> Result<()> {
+        instructions::proposal::execute_mint_securities_proposal(ctx)
+    }
+
+    pub fn execute_burn_securities_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteBurnSecuritiesProposal<'info>>,
+    ) -> Result<()> {
+        instructions::proposal::execute_burn_securities_proposal(ctx)
+    }
+
+    pub fn execute_force_transfer_between_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteForceTransferBetweenProposal<'info>>,
+    ) -> Result<()> {
+        instructions::proposal::execute_force_transfer_between_proposal(ctx)
+    }
+
+    pub fn execute_set_max_total_supply_proposal(
+        ctx: Context<ExecuteSetMaxTotalSupplyProposal>,
+    ) -> Result<()> {
+        instructions::proposal::execute_set_max_total_supply_proposal(ctx)
+    }
+
+    pub fn migrate_access_control(ctx: Context<MigrateAccessControl>) -> Result<()> {
+        instructions::access_control::migrate_access_control(ctx)
+    }
+
+    pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+        instructions::proposal::cancel_proposal(ctx)
+    }
 }
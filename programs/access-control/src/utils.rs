@@ -1,18 +1,41 @@
 use anchor_lang::{
-    prelude::Result,
-    solana_program::{program::invoke, system_instruction::transfer},
+    prelude::*,
+    solana_program::{
+        hash::hash,
+        instruction::{AccountMeta, Instruction},
+        program::invoke,
+        system_instruction::transfer,
+    },
     Lamports,
 };
+use anchor_spl::{
+    token_2022::spl_token_2022::extension::transfer_hook::TransferHook,
+    token_interface::{get_mint_extension_data, Mint},
+};
+
+use crate::{contexts::common::MAX_REASON_LEN, errors::AccessControlError};
 
-use crate::{AccountInfo, Rent, SolanaSysvar};
+/// Checks that the reason recorded in the event of an admin action fits `MAX_REASON_LEN`
+pub fn check_reason(reason: &str) -> Result<()> {
+    if reason.len() > MAX_REASON_LEN {
+        return Err(AccessControlError::ReasonTooLong.into());
+    }
+    Ok(())
+}
 
+// security associated account prefix of the transfer hook program
+pub const SECURITY_ASSOCIATED_ACCOUNT_PREFIX: &[u8] = b"saa";
+// offset of the `holder` option tag in the security associated account data (discriminator, group)
+const SECURITY_ASSOCIATED_ACCOUNT_HOLDER_OFFSET: usize = 16;
 
 pub fn update_account_lamports_to_minimum_balance<'info>(
     account: AccountInfo<'info>,
     payer: AccountInfo<'info>,
     system_program: AccountInfo<'info>,
 ) -> Result<()> {
-    let extra_lamports = Rent::get()?.minimum_balance(account.data_len()) - account.get_lamports();
+    let extra_lamports = Rent::get()?
+        .minimum_balance(account.data_len())
+        .saturating_sub(account.get_lamports());
     if extra_lamports > 0 {
         invoke(
             &transfer(payer.key, account.key, extra_lamports),
@@ -22,3 +45,70 @@ pub fn update_account_lamports_to_minimum_balance<'info>(
     Ok(())
 }
 
+/// Syncs the holder balance tracked by the transfer hook program with the token account balance
+/// changed outside of a transfer (mint or burn).
+/// `remaining_accounts` are the transfer hook program, the security associated account of the token account
+/// and, if it has a holder, its holder, holder group, group and transfer restriction data accounts.
+pub fn sync_holder_balance<'info>(
+    security_mint: &InterfaceAccount<'info, Mint>,
+    token_account: AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let mint_info = security_mint.to_account_info();
+    let transfer_hook = get_mint_extension_data::<TransferHook>(&mint_info)?;
+    let Some(hook_program_id) = Option::<Pubkey>::from(transfer_hook.program_id) else {
+        return Ok(());
+    };
+    let [hook_program, security_associated_account, holder_accounts @ ..] = remaining_accounts
+    else {
+        return Err(AccessControlError::InvalidHolderBalanceAccounts.into());
+    };
+    let (security_associated_account_pda, _bump) = Pubkey::find_program_address(
+        &[
+            SECURITY_ASSOCIATED_ACCOUNT_PREFIX,
+            token_account.key.as_ref(),
+        ],
+        &hook_program_id,
+    );
+    if *hook_program.key != hook_program_id
+        || *security_associated_account.key != security_associated_account_pda
+    {
+        return Err(AccessControlError::InvalidHolderBalanceAccounts.into());
+    }
+    // balances of wallets without a security associated account or holder (lockup escrow) are not tracked
+    if security_associated_account.data_is_empty()
+        || security_associated_account.try_borrow_data()?[SECURITY_ASSOCIATED_ACCOUNT_HOLDER_OFFSET]
+            == 0
+    {
+        return Ok(());
+    }
+    let [holder, holder_group, group, transfer_restriction_data, ..] = holder_accounts else {
+        return Err(AccessControlError::InvalidHolderBalanceAccounts.into());
+    };
+
+    let instruction = Instruction {
+        program_id: hook_program_id,
+        accounts: vec![
+            AccountMeta::new(*security_associated_account.key, false),
+            AccountMeta::new(*holder.key, false),
+            AccountMeta::new(*holder_group.key, false),
+            AccountMeta::new(*group.key, false),
+            AccountMeta::new(*transfer_restriction_data.key, false),
+            AccountMeta::new_readonly(*token_account.key, false),
+        ],
+        data: hash(b"global:sync_holder_balance").to_bytes()[..8].to_vec(),
+    };
+    invoke(
+        &instruction,
+        &[
+            security_associated_account.clone(),
+            holder.clone(),
+            holder_group.clone(),
+            group.clone(),
+            transfer_restriction_data.clone(),
+            token_account,
+            hook_program.clone(),
+        ],
+    )?;
+    Ok(())
+}
@@ -219,11 +219,13 @@ pub fn mint_release_schedule<'info>(
         token_program: ctx.accounts.token_program.to_account_info(),
     };
 
+    // remaining accounts are forwarded to sync the escrow holder balance when it is tracked
     access_control::cpi::mint_securities(CpiContext::new(
             ctx.accounts.access_control_program.to_account_info(), 
             cpi_accounts
-        ), 
-        amount
+        ).with_remaining_accounts(ctx.remaining_accounts.to_vec()), 
+        amount,
+        String::new(),
     )?;
 
     //add new cancelables
@@ -1,6 +1,8 @@
 use anchor_lang::{prelude::*, solana_program::program_memory::sol_memcmp, Discriminator};
 use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
-use transfer_restrictions::program::TransferRestrictions;
+use transfer_restrictions::{
+    cpi::accounts::EnforceTransferRestrictions, program::TransferRestrictions,
+};
 
 use crate::{enforce_transfer_restrictions_cpi, error::TokenlockErrors, transfer_spl_from_escrow, utils};
 
@@ -46,11 +48,24 @@ pub struct TransferFrom<'info> {
     /// CHECK: extra account for the authority associated account
     pub authority_account: AccountInfo<'info>,
     /// CHECK: extra account for the authority
+    #[account(mut)]
     pub security_associated_account_from: UncheckedAccount<'info>,
     /// CHECK: extra account for the recipient
     pub security_associated_account_to: UncheckedAccount<'info>,
     /// CHECK: extra account for the transfer rule
     pub transfer_rule: UncheckedAccount<'info>,
+    /// CHECK: extra account for the sender transfer restriction group
+    pub transfer_restriction_group_from: UncheckedAccount<'info>,
+    /// CHECK: extra account for the recipient transfer restriction group
+    pub transfer_restriction_group_to: UncheckedAccount<'info>,
+    /// CHECK: extra account for the sender transfer restriction holder
+    pub transfer_restriction_holder_from: UncheckedAccount<'info>,
+    /// CHECK: extra account for the recipient transfer restriction holder
+    pub transfer_restriction_holder_to: UncheckedAccount<'info>,
+    /// CHECK: extra account for the wildcard transfer rule from the sender group
+    pub transfer_rule_from_group_to_any: UncheckedAccount<'info>,
+    /// CHECK: extra account for the wildcard transfer rule to the recipient group
+    pub transfer_rule_from_any_to_group: UncheckedAccount<'info>,
 }
 
 pub fn transfer<'info>(
@@ -143,18 +158,48 @@ pub fn transfer<'info>(
     } // free borrowed account
 
     enforce_transfer_restrictions_cpi(
-        ctx.accounts.authority_account.clone(),
-        ctx.accounts.mint_address.to_account_info(),
-        ctx.accounts.to.to_account_info(),
-        ctx.remaining_accounts[0].clone(),
-        ctx.accounts
-            .security_associated_account_from
-            .to_account_info(),
-        ctx.accounts
-            .security_associated_account_to
-            .to_account_info(),
-        ctx.accounts.transfer_rule.to_account_info(),
+        EnforceTransferRestrictions {
+            source_account: ctx.accounts.authority_account.clone(),
+            mint: ctx.accounts.mint_address.to_account_info(),
+            destination_account: ctx.accounts.to.to_account_info(),
+            transfer_restriction_data: ctx.remaining_accounts[0].clone(),
+            security_associated_account_from: ctx
+                .accounts
+                .security_associated_account_from
+                .to_account_info(),
+            security_associated_account_to: ctx
+                .accounts
+                .security_associated_account_to
+                .to_account_info(),
+            transfer_rule: ctx.accounts.transfer_rule.to_account_info(),
+            transfer_restriction_group_from: ctx
+                .accounts
+                .transfer_restriction_group_from
+                .to_account_info(),
+            transfer_restriction_group_to: ctx
+                .accounts
+                .transfer_restriction_group_to
+                .to_account_info(),
+            transfer_restriction_holder_from: ctx
+                .accounts
+                .transfer_restriction_holder_from
+                .to_account_info(),
+            transfer_restriction_holder_to: ctx
+                .accounts
+                .transfer_restriction_holder_to
+                .to_account_info(),
+            transfer_rule_from_group_to_any: ctx
+                .accounts
+                .transfer_rule_from_group_to_any
+                .to_account_info(),
+            transfer_rule_from_any_to_group: ctx
+                .accounts
+                .transfer_rule_from_any_to_group
+                .to_account_info(),
+            authority: Some(ctx.accounts.authority.to_account_info()),
+        },
         ctx.accounts.transfer_restrictions_program.to_account_info(),
+        value,
     )?;
 
     transfer_spl_from_escrow(
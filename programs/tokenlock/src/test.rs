@@ -13,7 +13,10 @@ use solana_sdk::{
 use spl_token_2022::state::{Account as TokenAccount, Mint};
 use std::str::FromStr;
 use tokenlock_accounts::states::{Timelock, TimelockData, TokenLockData};
-use transfer_restrictions::{SecurityAssociatedAccount, TransferRestrictionData, TransferRule};
+use transfer_restrictions::{
+    SecurityAssociatedAccount, TransferRestrictionData, TransferRestrictionGroup,
+    TransferRestrictionHolder, TransferRule,
+};
 const TOKENLOCK_SIZE: usize = 10 * 1024 * 1024;
 
 struct TestFixture {
@@ -26,6 +29,12 @@ struct TestFixture {
     security_associated_account_from: (Pubkey, SolanaAccount),
     security_associated_account_to: (Pubkey, SolanaAccount),
     transfer_rule: (Pubkey, SolanaAccount),
+    transfer_restriction_group_from: (Pubkey, SolanaAccount),
+    transfer_restriction_group_to: (Pubkey, SolanaAccount),
+    transfer_restriction_holder_from: (Pubkey, SolanaAccount),
+    transfer_restriction_holder_to: (Pubkey, SolanaAccount),
+    transfer_rule_from_group_to_any: (Pubkey, SolanaAccount),
+    transfer_rule_from_any_to_group: (Pubkey, SolanaAccount),
     authority_wallet_role: (Pubkey, SolanaAccount),
     access_control: (Pubkey, SolanaAccount),
     access_control_program: (Pubkey, SolanaAccount),
@@ -181,6 +190,24 @@ impl Default for TestFixture {
                 &Pubkey::new_unique(),
                 &transfer_restrictions::ID,
             ),
+            transfer_restriction_group_from: Self::create_transfer_restriction_group_account(
+                &Pubkey::new_unique(),
+                &transfer_restrictions::ID,
+            ),
+            transfer_restriction_group_to: Self::create_transfer_restriction_group_account(
+                &Pubkey::new_unique(),
+                &transfer_restrictions::ID,
+            ),
+            transfer_restriction_holder_from: Self::create_transfer_restriction_holder_account(
+                &Pubkey::new_unique(),
+                &transfer_restrictions::ID,
+            ),
+            transfer_restriction_holder_to: Self::create_transfer_restriction_holder_account(
+                &Pubkey::new_unique(),
+                &transfer_restrictions::ID,
+            ),
+            transfer_rule_from_group_to_any: (Pubkey::new_unique(), SolanaAccount::default()),
+            transfer_rule_from_any_to_group: (Pubkey::new_unique(), SolanaAccount::default()),
 
             access_control: Self::create_access_control_account(
                 &access_control_address,
@@ -335,6 +362,12 @@ impl<'a> TestFixture {
         security_associated_account_from_info: &'a AccountInfo<'a>,
         security_associated_account_to_info: &'a AccountInfo<'a>,
         transfer_rule_info: &'a AccountInfo<'a>,
+        transfer_restriction_group_from_info: &'a AccountInfo<'a>,
+        transfer_restriction_group_to_info: &'a AccountInfo<'a>,
+        transfer_restriction_holder_from_info: &'a AccountInfo<'a>,
+        transfer_restriction_holder_to_info: &'a AccountInfo<'a>,
+        transfer_rule_from_group_to_any_info: &'a AccountInfo<'a>,
+        transfer_rule_from_any_to_group_info: &'a AccountInfo<'a>,
     ) -> Result<TransferFrom<'a>, ProgramError> {
         let escrow_account = InterfaceAccount::try_from(&escrow_account_info)?;
         authority_info.is_signer = true;
@@ -360,6 +393,24 @@ impl<'a> TestFixture {
                 security_associated_account_to_info,
             ),
             transfer_rule: UncheckedAccount::try_from(transfer_rule_info),
+            transfer_restriction_group_from: UncheckedAccount::try_from(
+                transfer_restriction_group_from_info,
+            ),
+            transfer_restriction_group_to: UncheckedAccount::try_from(
+                transfer_restriction_group_to_info,
+            ),
+            transfer_restriction_holder_from: UncheckedAccount::try_from(
+                transfer_restriction_holder_from_info,
+            ),
+            transfer_restriction_holder_to: UncheckedAccount::try_from(
+                transfer_restriction_holder_to_info,
+            ),
+            transfer_rule_from_group_to_any: UncheckedAccount::try_from(
+                transfer_rule_from_group_to_any_info,
+            ),
+            transfer_rule_from_any_to_group: UncheckedAccount::try_from(
+                transfer_rule_from_any_to_group_info,
+            ),
         })
     }
 
@@ -377,6 +428,12 @@ impl<'a> TestFixture {
         security_associated_account_from_info: &'a AccountInfo<'a>,
         security_associated_account_to_info: &'a AccountInfo<'a>,
         transfer_rule_info: &'a AccountInfo<'a>,
+        transfer_restriction_group_from_info: &'a AccountInfo<'a>,
+        transfer_restriction_group_to_info: &'a AccountInfo<'a>,
+        transfer_restriction_holder_from_info: &'a AccountInfo<'a>,
+        transfer_restriction_holder_to_info: &'a AccountInfo<'a>,
+        transfer_rule_from_group_to_any_info: &'a AccountInfo<'a>,
+        transfer_rule_from_any_to_group_info: &'a AccountInfo<'a>,
     ) -> Result<TransferTimelock<'a>, ProgramError> {
         let escrow_account = InterfaceAccount::try_from(&escrow_account_info)?;
 
@@ -406,6 +463,24 @@ impl<'a> TestFixture {
                 security_associated_account_to_info,
             ),
             transfer_rule: UncheckedAccount::try_from(transfer_rule_info),
+            transfer_restriction_group_from: UncheckedAccount::try_from(
+                transfer_restriction_group_from_info,
+            ),
+            transfer_restriction_group_to: UncheckedAccount::try_from(
+                transfer_restriction_group_to_info,
+            ),
+            transfer_restriction_holder_from: UncheckedAccount::try_from(
+                transfer_restriction_holder_from_info,
+            ),
+            transfer_restriction_holder_to: UncheckedAccount::try_from(
+                transfer_restriction_holder_to_info,
+            ),
+            transfer_rule_from_group_to_any: UncheckedAccount::try_from(
+                transfer_rule_from_group_to_any_info,
+            ),
+            transfer_rule_from_any_to_group: UncheckedAccount::try_from(
+                transfer_rule_from_any_to_group_info,
+            ),
         })
     }
 
@@ -424,6 +499,12 @@ impl<'a> TestFixture {
         security_associated_account_from_info: &'a AccountInfo<'a>,
         security_associated_account_to_info: &'a AccountInfo<'a>,
         transfer_rule_info: &'a AccountInfo<'a>,
+        transfer_restriction_group_from_info: &'a AccountInfo<'a>,
+        transfer_restriction_group_to_info: &'a AccountInfo<'a>,
+        transfer_restriction_holder_from_info: &'a AccountInfo<'a>,
+        transfer_restriction_holder_to_info: &'a AccountInfo<'a>,
+        transfer_rule_from_group_to_any_info: &'a AccountInfo<'a>,
+        transfer_rule_from_any_to_group_info: &'a AccountInfo<'a>,
     ) -> Result<CancelTimelock<'a>, ProgramError> {
         let escrow_account = InterfaceAccount::try_from(&escrow_account_info)?;
         let mut tokenlock_data: Account<TokenLockData> =
@@ -450,6 +531,24 @@ impl<'a> TestFixture {
                 security_associated_account_to_info,
             ),
             transfer_rule: UncheckedAccount::try_from(transfer_rule_info),
+            transfer_restriction_group_from: UncheckedAccount::try_from(
+                transfer_restriction_group_from_info,
+            ),
+            transfer_restriction_group_to: UncheckedAccount::try_from(
+                transfer_restriction_group_to_info,
+            ),
+            transfer_restriction_holder_from: UncheckedAccount::try_from(
+                transfer_restriction_holder_from_info,
+            ),
+            transfer_restriction_holder_to: UncheckedAccount::try_from(
+                transfer_restriction_holder_to_info,
+            ),
+            transfer_rule_from_group_to_any: UncheckedAccount::try_from(
+                transfer_rule_from_group_to_any_info,
+            ),
+            transfer_rule_from_any_to_group: UncheckedAccount::try_from(
+                transfer_rule_from_any_to_group_info,
+            ),
         })
     }
 }
@@ -567,6 +666,32 @@ impl TestFixture {
         )
     }
 
+    fn create_transfer_restriction_group_account(
+        transfer_restriction_group_address: &Pubkey,
+        program_id: &Pubkey,
+    ) -> (Pubkey, SolanaAccount) {
+        let discriminator = TransferRestrictionGroup::discriminator();
+        Self::create_account(
+            transfer_restriction_group_address,
+            program_id,
+            &discriminator,
+            8 + TransferRestrictionGroup::INIT_SPACE,
+        )
+    }
+
+    fn create_transfer_restriction_holder_account(
+        transfer_restriction_holder_address: &Pubkey,
+        program_id: &Pubkey,
+    ) -> (Pubkey, SolanaAccount) {
+        let discriminator = TransferRestrictionHolder::discriminator();
+        Self::create_account(
+            transfer_restriction_holder_address,
+            program_id,
+            &discriminator,
+            8 + TransferRestrictionHolder::INIT_SPACE,
+        )
+    }
+
     fn create_access_control_account(
         access_control_address: &Pubkey,
         program_id: &Pubkey,
@@ -1058,6 +1183,18 @@ fn test_transfer() {
     let security_associated_account_to_info =
         fixture.security_associated_account_to.into_account_info();
     let transfer_rule_info = fixture.transfer_rule.into_account_info();
+    let transfer_restriction_group_from_info =
+        fixture.transfer_restriction_group_from.into_account_info();
+    let transfer_restriction_group_to_info =
+        fixture.transfer_restriction_group_to.into_account_info();
+    let transfer_restriction_holder_from_info =
+        fixture.transfer_restriction_holder_from.into_account_info();
+    let transfer_restriction_holder_to_info =
+        fixture.transfer_restriction_holder_to.into_account_info();
+    let transfer_rule_from_group_to_any_info =
+        fixture.transfer_rule_from_group_to_any.into_account_info();
+    let transfer_rule_from_any_to_group_info =
+        fixture.transfer_rule_from_any_to_group.into_account_info();
     let mut accounts = TestFixture::transfer(
         &escrow_account_info,
         tokenlock_account_info,
@@ -1072,6 +1209,12 @@ fn test_transfer() {
         &security_associated_account_from_info,
         &security_associated_account_to_info,
         &transfer_rule_info,
+        &transfer_restriction_group_from_info,
+        &transfer_restriction_group_to_info,
+        &transfer_restriction_holder_from_info,
+        &transfer_restriction_holder_to_info,
+        &transfer_rule_from_group_to_any_info,
+        &transfer_rule_from_any_to_group_info,
     )
     .expect("Getting accounts error");
     accounts.tokenlock_account = accounts_create_release.tokenlock_account;
@@ -1159,6 +1302,18 @@ fn test_transfer_timelock() {
     let security_associated_account_to_info =
         fixture.security_associated_account_to.into_account_info();
     let transfer_rule_info = fixture.transfer_rule.into_account_info();
+    let transfer_restriction_group_from_info =
+        fixture.transfer_restriction_group_from.into_account_info();
+    let transfer_restriction_group_to_info =
+        fixture.transfer_restriction_group_to.into_account_info();
+    let transfer_restriction_holder_from_info =
+        fixture.transfer_restriction_holder_from.into_account_info();
+    let transfer_restriction_holder_to_info =
+        fixture.transfer_restriction_holder_to.into_account_info();
+    let transfer_rule_from_group_to_any_info =
+        fixture.transfer_rule_from_group_to_any.into_account_info();
+    let transfer_rule_from_any_to_group_info =
+        fixture.transfer_rule_from_any_to_group.into_account_info();
     let mut accounts = TestFixture::transfer_timelock(
         &escrow_account_info,
         &tokenlock_account_info,
@@ -1173,6 +1328,12 @@ fn test_transfer_timelock() {
         &security_associated_account_from_info,
         &security_associated_account_to_info,
         &transfer_rule_info,
+        &transfer_restriction_group_from_info,
+        &transfer_restriction_group_to_info,
+        &transfer_restriction_holder_from_info,
+        &transfer_restriction_holder_to_info,
+        &transfer_rule_from_group_to_any_info,
+        &transfer_rule_from_any_to_group_info,
     )
     .expect("Getting accounts error");
     let remaining_accounts: &[AccountInfo] = &[fixture_create_release
@@ -1321,6 +1482,18 @@ fn test_cancel_timelock() {
     let security_associated_account_to_info =
         fixture.security_associated_account_to.into_account_info();
     let transfer_rule_info = fixture.transfer_rule.into_account_info();
+    let transfer_restriction_group_from_info =
+        fixture.transfer_restriction_group_from.into_account_info();
+    let transfer_restriction_group_to_info =
+        fixture.transfer_restriction_group_to.into_account_info();
+    let transfer_restriction_holder_from_info =
+        fixture.transfer_restriction_holder_from.into_account_info();
+    let transfer_restriction_holder_to_info =
+        fixture.transfer_restriction_holder_to.into_account_info();
+    let transfer_rule_from_group_to_any_info =
+        fixture.transfer_rule_from_group_to_any.into_account_info();
+    let transfer_rule_from_any_to_group_info =
+        fixture.transfer_rule_from_any_to_group.into_account_info();
     let mut accounts = TestFixture::cancel_timelock(
         &escrow_account_info,
         &tokenlock_account_info,
@@ -1336,6 +1509,12 @@ fn test_cancel_timelock() {
         &security_associated_account_from_info,
         &security_associated_account_to_info,
         &transfer_rule_info,
+        &transfer_restriction_group_from_info,
+        &transfer_restriction_group_to_info,
+        &transfer_restriction_holder_from_info,
+        &transfer_restriction_holder_to_info,
+        &transfer_rule_from_group_to_any_info,
+        &transfer_rule_from_any_to_group_info,
     )
     .expect("Getting accounts error");
     let bumps = CancelTimelockBumps::default();
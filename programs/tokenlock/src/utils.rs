@@ -11,28 +11,14 @@ use transfer_restrictions::cpi::accounts::EnforceTransferRestrictions;
 pub const TOKENLOCK_PDA_SEED: &[u8] = b"tokenlock";
 
 pub fn enforce_transfer_restrictions_cpi<'info>(
-    authority_account_info: AccountInfo<'info>,
-    mint_address_info: AccountInfo<'info>,
-    to_info: AccountInfo<'info>,
-    transfer_restrictions_data: AccountInfo<'info>,
-    security_associated_account_from_info: AccountInfo<'info>,
-    security_associated_account_to_info: AccountInfo<'info>,
-    transfer_rule_info: AccountInfo<'info>,
+    accounts: EnforceTransferRestrictions<'info>,
     transfer_restrictions_program_info: AccountInfo<'info>,
+    amount: u64,
 ) -> Result<()> {
-    let cpi_accounts = EnforceTransferRestrictions {
-        source_account: authority_account_info,
-        mint: mint_address_info,
-        destination_account: to_info,
-        transfer_restriction_data: transfer_restrictions_data,
-        security_associated_account_from: security_associated_account_from_info,
-        security_associated_account_to: security_associated_account_to_info,
-        transfer_rule: transfer_rule_info,
-    };
-    transfer_restrictions::cpi::enforce_transfer_restrictions(CpiContext::new(
-        transfer_restrictions_program_info,
-        cpi_accounts,
-    ))?;
+    transfer_restrictions::cpi::enforce_transfer_restrictions(
+        CpiContext::new(transfer_restrictions_program_info, accounts),
+        amount,
+    )?;
 
     Ok(())
 }
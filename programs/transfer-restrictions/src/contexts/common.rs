@@ -1 +1,16 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TransferRestrictionsError;
+
 pub const DISCRIMINATOR_LEN: usize = 8;
+
+/// Returns the number of holders of record (holders with a non-zero balance)
+/// after a holder balance changed from `previous_balance` to `new_balance`
+pub fn holders_of_record_count(count: u64, previous_balance: u64, new_balance: u64) -> Result<u64> {
+    match (previous_balance, new_balance) {
+        (0, 1..) => count.checked_add(1),
+        (1.., 0) => count.checked_sub(1),
+        _ => Some(count),
+    }
+    .ok_or(TransferRestrictionsError::HoldersOfRecordCountOutOfSync.into())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+/// Reason code returned by `detect_transfer_restriction` (ERC-1404 `detectTransferRestriction`).
+/// Code 0 means that the transfer is allowed, otherwise it is the error code the transfer would fail with.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct TransferRestrictionReason {
+    pub code: u32,
+    pub message: String,
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct DetectTransferRestriction<'info> {
+    #[account(
+      token::mint = mint,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub source_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+      token::mint = mint,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub destination_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Box<Account<'info, TransferRestrictionData>>
+    pub transfer_restriction_data: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, SecurityAssociatedAccount>>
+    pub security_associated_account_from: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, SecurityAssociatedAccount>>
+    pub security_associated_account_to: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRule>>
+    pub transfer_rule: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRestrictionHolder>>
+    pub transfer_restriction_holder_from: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRestrictionHolder>>
+    pub transfer_restriction_holder_to: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRestrictionGroup>>
+    pub transfer_restriction_group_to: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRestrictionGroup>>
+    pub transfer_restriction_group_from: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, HolderGroup>>
+    pub holder_group_from: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, HolderGroup>>
+    pub holder_group_to: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRule>>, wildcard rule from the sender group to any group
+    pub transfer_rule_from_group_to_any: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRule>>, wildcard rule from any group to the recipient group
+    pub transfer_rule_from_any_to_group: UncheckedAccount<'info>,
+}
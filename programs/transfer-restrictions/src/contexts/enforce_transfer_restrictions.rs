@@ -2,12 +2,15 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount};
 
 use crate::{
-    SecurityAssociatedAccount, TransferRestrictionData, TransferRule, TRANSFER_RULE_PREFIX,
+    SecurityAssociatedAccount, TransferRestrictionData, TransferRestrictionGroup,
+    TransferRestrictionHolder, ANY_TRANSFER_GROUP_ID, TRANSFER_RESTRICTION_GROUP_PREFIX,
+    TRANSFER_RULE_PREFIX,
 };
 
 use super::{SECURITY_ASSOCIATED_ACCOUNT_PREFIX, TRANSFER_RESTRICTION_DATA_PREFIX};
 
 #[derive(Accounts)]
+#[instruction(amount: u64)]
 pub struct EnforceTransferRestrictions<'info> {
     #[account(
       token::mint = mint,
@@ -35,7 +38,7 @@ pub struct EnforceTransferRestrictions<'info> {
     )]
     pub transfer_restriction_data: Box<Account<'info, TransferRestrictionData>>,
 
-    #[account(
+    #[account(mut,
       seeds = [
           SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
           &source_account.key().to_bytes(),
@@ -53,14 +56,76 @@ pub struct EnforceTransferRestrictions<'info> {
     )]
     pub security_associated_account_to: Box<Account<'info, SecurityAssociatedAccount>>,
 
+    /// CHECK: Box<Account<'info, TransferRule>>, unchecked to support rules created before amount limits
+    /// and routes covered only by a wildcard or the default transfer rule
+    #[account(
+      seeds = [
+          TRANSFER_RULE_PREFIX.as_bytes(),
+          &transfer_restriction_data.key().to_bytes(),
+          &security_associated_account_from.group.to_le_bytes(),
+          &security_associated_account_to.group.to_le_bytes(),
+      ],
+      bump,
+    )]
+    pub transfer_rule: UncheckedAccount<'info>,
+
+    #[account(
+      seeds = [
+          TRANSFER_RESTRICTION_GROUP_PREFIX.as_bytes(),
+          &transfer_restriction_data.key().to_bytes(),
+          &security_associated_account_from.group.to_le_bytes(),
+      ],
+      bump,
+    )]
+    pub transfer_restriction_group_from: Box<Account<'info, TransferRestrictionGroup>>,
+
+    #[account(
+      seeds = [
+          TRANSFER_RESTRICTION_GROUP_PREFIX.as_bytes(),
+          &transfer_restriction_data.key().to_bytes(),
+          &security_associated_account_to.group.to_le_bytes(),
+      ],
+      bump,
+    )]
+    pub transfer_restriction_group_to: Box<Account<'info, TransferRestrictionGroup>>,
+
+    #[account(
+      constraint = security_associated_account_from.holder == Some(transfer_restriction_holder_from.key()),
+    )]
+    pub transfer_restriction_holder_from: Box<Account<'info, TransferRestrictionHolder>>,
+
+    #[account(
+      constraint = security_associated_account_to.holder == Some(transfer_restriction_holder_to.key()),
+    )]
+    pub transfer_restriction_holder_to: Box<Account<'info, TransferRestrictionHolder>>,
+
+    /// CHECK: wildcard transfer rule from the sender group to any group, may be uninitialized
     #[account(
       seeds = [
           TRANSFER_RULE_PREFIX.as_bytes(),
           &transfer_restriction_data.key().to_bytes(),
           &security_associated_account_from.group.to_le_bytes(),
+          &ANY_TRANSFER_GROUP_ID.to_le_bytes(),
+      ],
+      bump,
+    )]
+    pub transfer_rule_from_group_to_any: UncheckedAccount<'info>,
+
+    /// CHECK: wildcard transfer rule from any group to the recipient group, may be uninitialized
+    #[account(
+      seeds = [
+          TRANSFER_RULE_PREFIX.as_bytes(),
+          &transfer_restriction_data.key().to_bytes(),
+          &ANY_TRANSFER_GROUP_ID.to_le_bytes(),
           &security_associated_account_to.group.to_le_bytes(),
       ],
       bump,
     )]
-    pub transfer_rule: Box<Account<'info, TransferRule>>,
+    pub transfer_rule_from_any_to_group: UncheckedAccount<'info>,
+
+    /// Owner of the source account, records the amount in the sender velocity window when present
+    #[account(
+      constraint = authority.key() == source_account.owner,
+    )]
+    pub authority: Option<Signer<'info>>,
 }
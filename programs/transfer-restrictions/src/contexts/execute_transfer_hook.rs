@@ -33,12 +33,15 @@ pub struct ExecuteTransferHook<'info> {
     pub extra_metas_account: UncheckedAccount<'info>,
 
     /// CHECK: Box<Account<'info, TransferRestrictionData>>
+    #[account(mut)]
     pub transfer_restriction_data: UncheckedAccount<'info>,
 
     /// CHECK: Box<Account<'info, SecurityAssociatedAccount>>
+    #[account(mut)]
     pub security_associated_account_from: UncheckedAccount<'info>,
 
     /// CHECK: Box<Account<'info, SecurityAssociatedAccount>>
+    #[account(mut)]
     pub security_associated_account_to: UncheckedAccount<'info>,
 
     // #[account(
@@ -52,4 +55,34 @@ pub struct ExecuteTransferHook<'info> {
     // )]
     /// CHECK: Box<Account<'info, TransferRule>>
     pub transfer_rule: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRestrictionHolder>>
+    #[account(mut)]
+    pub transfer_restriction_holder_from: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRestrictionHolder>>
+    #[account(mut)]
+    pub transfer_restriction_holder_to: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRestrictionGroup>>
+    #[account(mut)]
+    pub transfer_restriction_group_to: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRestrictionGroup>>
+    #[account(mut)]
+    pub transfer_restriction_group_from: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, HolderGroup>>
+    #[account(mut)]
+    pub holder_group_from: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, HolderGroup>>
+    #[account(mut)]
+    pub holder_group_to: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRule>>, wildcard rule from the sender group to any group
+    pub transfer_rule_from_group_to_any: UncheckedAccount<'info>,
+
+    /// CHECK: Box<Account<'info, TransferRule>>, wildcard rule from any group to the recipient group
+    pub transfer_rule_from_any_to_group: UncheckedAccount<'info>,
 }
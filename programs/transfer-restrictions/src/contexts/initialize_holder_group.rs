@@ -1,6 +1,7 @@
 use crate::{
-    contexts::common::DISCRIMINATOR_LEN, TransferRestrictionData, TransferRestrictionGroup,
-    TransferRestrictionHolder, TRANSFER_RESTRICTION_DATA_PREFIX, TRANSFER_RESTRICTION_GROUP_PREFIX,
+    contexts::common::DISCRIMINATOR_LEN, errors::TransferRestrictionsError,
+    TransferRestrictionData, TransferRestrictionGroup, TransferRestrictionHolder,
+    TRANSFER_RESTRICTION_DATA_PREFIX, TRANSFER_RESTRICTION_GROUP_PREFIX,
 };
 use access_control::{self, WalletRole};
 use anchor_lang::prelude::*;
@@ -14,6 +15,19 @@ pub struct HolderGroup {
     pub group: u64,
     pub holder: Pubkey,
     pub current_wallets_count: u64,
+    pub current_balance: u64,
+}
+
+impl HolderGroup {
+    /// Replaces the previously recorded balance of one of the holder wallets in the group with the new one
+    pub fn update_wallet_balance(&mut self, previous_balance: u64, new_balance: u64) -> Result<()> {
+        self.current_balance = self
+            .current_balance
+            .checked_sub(previous_balance)
+            .and_then(|balance| balance.checked_add(new_balance))
+            .ok_or(TransferRestrictionsError::HolderBalanceOutOfSync)?;
+        Ok(())
+    }
 }
 
 #[derive(Accounts)]
@@ -8,12 +8,65 @@ use crate::{
 };
 
 pub const SECURITY_ASSOCIATED_ACCOUNT_PREFIX: &str = "saa"; // security associated account
+pub const VELOCITY_BUCKETS_COUNT: usize = 12;
 
 #[account]
 #[derive(Default, InitSpace)]
 pub struct SecurityAssociatedAccount {
     pub group: u64,
     pub holder: Option<Pubkey>,
+    pub holder_id: u64,
+    pub balance: u64,
+    pub velocity_period: u64,
+    pub velocity_bucket: u64,
+    pub velocity_sent: [u64; VELOCITY_BUCKETS_COUNT],
+    pub send_blocked: bool,
+    pub receive_blocked: bool,
+    pub valid_until: u64,
+}
+
+impl SecurityAssociatedAccount {
+    /// Returns true if the wallet KYC/accreditation validity has expired (0 means no expiry)
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.valid_until != 0 && self.valid_until <= now
+    }
+
+    /// Adds the sent amount to the rolling velocity window of `period` seconds.
+    /// The window is split into `VELOCITY_BUCKETS_COUNT` buckets of `period / (VELOCITY_BUCKETS_COUNT - 1)`
+    /// seconds, so an amount stops counting between `period` and one bucket length after it was sent.
+    /// Returns false and records nothing if the amount sent within the window would exceed `limit`.
+    pub fn record_sent_amount(&mut self, amount: u64, limit: u64, period: u64, now: u64) -> bool {
+        let bucket_length = period.div_ceil(VELOCITY_BUCKETS_COUNT as u64 - 1).max(1);
+        let bucket = now / bucket_length;
+        if self.velocity_period != period
+            || bucket
+                >= self
+                    .velocity_bucket
+                    .saturating_add(VELOCITY_BUCKETS_COUNT as u64)
+        {
+            self.velocity_period = period;
+            self.velocity_bucket = bucket;
+            self.velocity_sent = [0; VELOCITY_BUCKETS_COUNT];
+        }
+        while self.velocity_bucket < bucket {
+            self.velocity_bucket += 1;
+            self.velocity_sent[self.velocity_bucket as usize % VELOCITY_BUCKETS_COUNT] = 0;
+        }
+
+        let Some(sent) = self
+            .velocity_sent
+            .iter()
+            .try_fold(amount, |sent, bucket_sent| sent.checked_add(*bucket_sent))
+        else {
+            return false;
+        };
+        if sent > limit {
+            return false;
+        }
+        // Fits in u64: the total sent within the window does not exceed `limit`
+        self.velocity_sent[self.velocity_bucket as usize % VELOCITY_BUCKETS_COUNT] += amount;
+        true
+    }
 }
 
 #[derive(Accounts)]
@@ -45,7 +98,7 @@ pub struct InitializeSecurityAssociatedAccount<'info> {
       token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
     )]
     pub security_token: Box<InterfaceAccount<'info, Mint>>,
-    #[account(
+    #[account(mut,
       seeds = [
         TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
         &security_token.key().to_bytes(),
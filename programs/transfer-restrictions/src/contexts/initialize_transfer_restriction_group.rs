@@ -1,9 +1,19 @@
 use anchor_lang::prelude::*;
 use access_control::{self, AccessControl, WalletRole};
-use crate::{contexts::common::DISCRIMINATOR_LEN, TransferRestrictionData, TRANSFER_RESTRICTION_DATA_PREFIX};
+use crate::{contexts::common::{holders_of_record_count, DISCRIMINATOR_LEN}, errors::TransferRestrictionsError, TransferRestrictionData, TRANSFER_RESTRICTION_DATA_PREFIX};
 
 // Short name is required for transfer hook meta account list specification (32 bytes limit)
 pub const TRANSFER_RESTRICTION_GROUP_PREFIX: &str = "trg";
+pub const GROUP_NAME_MAX_LEN: usize = 32;
+pub const GROUP_JURISDICTION_MAX_LEN: usize = 8; // ISO 3166 country or subdivision code, e.g. "US-CA"
+
+#[repr(u8)]
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug)]
+pub enum GroupFlags {
+  AccreditedOnly = 1, // 01
+  Lockup = 2,         // 10
+  All = 3,            // 11
+}
 
 
 #[account]
@@ -14,6 +24,43 @@ pub struct TransferRestrictionGroup {
   pub current_holders_count: u64,
   pub max_holders: u64,
   pub transfer_restriction_data: Pubkey,
+  pub max_balance: u64,
+  pub velocity_limit: u64,
+  pub velocity_period: u64,
+  pub current_holders_of_record_count: u64,
+  #[max_len(GROUP_NAME_MAX_LEN)]
+  pub name: String,
+  #[max_len(GROUP_JURISDICTION_MAX_LEN)]
+  pub jurisdiction: String,
+  pub flags: u8,
+  // Outbound transfers from the group are blocked until this timestamp
+  pub locked_until: u64,
+  pub frozen: bool,
+  // Transfers from and to the group wallets are paused
+  pub paused: bool,
+}
+
+impl TransferRestrictionGroup {
+  /// Updates the number of group holders with a non-zero balance inside the group
+  pub fn update_holders_of_record_count(&mut self, previous_balance: u64, new_balance: u64) -> Result<()> {
+    self.current_holders_of_record_count = holders_of_record_count(
+      self.current_holders_of_record_count,
+      previous_balance,
+      new_balance,
+    )?;
+    Ok(())
+  }
+
+  /// Checks that outbound transfers from the group are not frozen or locked at the current time
+  pub fn enforce_outbound_lockup(&self, now: u64) -> Result<()> {
+    if self.frozen {
+      return Err(TransferRestrictionsError::GroupFrozen.into());
+    }
+    if self.locked_until > now {
+      return Err(TransferRestrictionsError::GroupLocked.into());
+    }
+    Ok(())
+  }
 }
 
 #[derive(Accounts)]
@@ -1,11 +1,15 @@
 use crate::{
-    contexts::common::DISCRIMINATOR_LEN, TransferRestrictionData, TRANSFER_RESTRICTION_DATA_PREFIX,
+    contexts::common::DISCRIMINATOR_LEN, errors::TransferRestrictionsError,
+    TransferRestrictionData, TRANSFER_RESTRICTION_DATA_PREFIX,
 };
 use access_control::{self, AccessControl, WalletRole};
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
 
 pub const TRANSFER_RESTRICTION_HOLDER_PREFIX: &str = "trh"; // transfer_restriction_holder
 
+// Marks holder ids derived from a holder reference, never reached by sequential holder ids
+pub const DERIVED_HOLDER_ID_FLAG: u64 = 1 << 63;
+
 #[account]
 #[derive(Default, InitSpace)]
 pub struct TransferRestrictionHolder {
@@ -14,6 +18,34 @@ pub struct TransferRestrictionHolder {
     pub current_holder_group_count: u64,
     pub id: u64,
     pub active: bool,
+    pub max_balance: u64,
+    pub current_balance: u64,
+}
+
+impl TransferRestrictionHolder {
+    /// Derives the holder id from the holder reference (investor wallet or KYC reference hash).
+    /// Holder accounts are derived from their ids, so the same reference always maps to the same holder.
+    pub fn id_from_reference(reference: &[u8; 32]) -> u64 {
+        let hash = hashv(&[TRANSFER_RESTRICTION_HOLDER_PREFIX.as_bytes(), reference]);
+        u64::from_le_bytes(hash.to_bytes()[..8].try_into().unwrap()) | DERIVED_HOLDER_ID_FLAG
+    }
+
+    /// Returns the holder account seed of the id derived from the holder reference. The IDL build
+    /// can only resolve seeds of constants, arguments and accounts and would evaluate a method call
+    /// on it as a constant.
+    pub fn seed_from_reference(reference: &[u8; 32]) -> [u8; 8] {
+        Self::id_from_reference(reference).to_le_bytes()
+    }
+
+    /// Replaces the previously recorded balance of one of the holder wallets with the new one
+    pub fn update_wallet_balance(&mut self, previous_balance: u64, new_balance: u64) -> Result<()> {
+        self.current_balance = self
+            .current_balance
+            .checked_sub(previous_balance)
+            .and_then(|balance| balance.checked_add(new_balance))
+            .ok_or(TransferRestrictionsError::HolderBalanceOutOfSync)?;
+        Ok(())
+    }
 }
 
 #[derive(Accounts)]
@@ -1,4 +1,4 @@
-use crate::{contexts::common::DISCRIMINATOR_LEN, TransferRestrictionGroup, TRANSFER_RESTRICTION_GROUP_PREFIX};
+use crate::{contexts::common::{holders_of_record_count, DISCRIMINATOR_LEN}, TransferRestrictionGroup, TransferRule, TRANSFER_RESTRICTION_GROUP_PREFIX};
 use access_control::{self, AccessControl, WalletRole};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, Token2022};
@@ -15,6 +15,34 @@ pub struct TransferRestrictionData {
     pub max_holders: u64,
     pub paused: bool,
     pub lockup_escrow_account: Option<Pubkey>,
+    pub current_holders_of_record_count: u64,
+    pub holders_of_record_enforced: bool,
+    // Applied to transfers between groups without an exact or wildcard transfer rule
+    pub default_transfer_rule: Option<TransferRule>,
+    // Transfers are paused within `[paused_from, paused_until)`, 0 `paused_until` means no scheduled pause
+    pub paused_from: u64,
+    pub paused_until: u64,
+}
+
+impl TransferRestrictionData {
+    /// Updates the number of holders with a non-zero balance after a holder balance change
+    pub fn update_holders_of_record_count(
+        &mut self,
+        previous_balance: u64,
+        new_balance: u64,
+    ) -> Result<()> {
+        self.current_holders_of_record_count = holders_of_record_count(
+            self.current_holders_of_record_count,
+            previous_balance,
+            new_balance,
+        )?;
+        Ok(())
+    }
+
+    /// Returns true if transfers are paused manually or by a pause scheduled over `now`
+    pub fn is_paused(&self, now: u64) -> bool {
+        self.paused || (self.paused_from <= now && now < self.paused_until)
+    }
 }
 
 #[derive(Accounts)]
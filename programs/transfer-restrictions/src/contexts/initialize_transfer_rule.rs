@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 use crate::{
   contexts::common::DISCRIMINATOR_LEN,
+  errors::TransferRestrictionsError,
+  update_account_lamports_to_minimum_balance,
   TransferRestrictionData,
   TransferRestrictionGroup,
   TRANSFER_RESTRICTION_DATA_PREFIX,
@@ -8,16 +10,93 @@ use crate::{
 use access_control::{self, AccessControl, WalletRole};
 
 pub const TRANSFER_RULE_PREFIX: &str = "tr"; // transfer_rule
+// Group id of wildcard transfer rules matching any group
+pub const ANY_TRANSFER_GROUP_ID: u64 = u64::MAX;
 
 
 #[account]
-#[derive(Default)]
+#[derive(Default, PartialEq)]
 #[derive(InitSpace)]
 pub struct TransferRule {
   pub transfer_restriction_data: Pubkey,
   pub transfer_group_id_from: u64,
   pub transfer_group_id_to: u64,
   pub locked_until: u64,
+  pub min_amount: u64,
+  pub max_amount: u64,
+  pub lot_size: u64,
+  pub locked_after: u64,
+}
+
+impl TransferRule {
+  /// Deserializes transfer rule account data, zero-filling fields missing from
+  /// accounts created before they were introduced
+  pub fn try_from_account_data(data: &[u8]) -> Result<Self> {
+    let mut buf = [0u8; TransferRule::INIT_SPACE];
+    let stored = &data[DISCRIMINATOR_LEN.min(data.len())..];
+    let len = stored.len().min(buf.len());
+    buf[..len].copy_from_slice(&stored[..len]);
+    Ok(TransferRule::deserialize(&mut &buf[..])?)
+  }
+
+  /// Returns true if the account was created with an older, smaller rule layout
+  pub fn needs_realloc(info: &AccountInfo) -> bool {
+    info.data_len() < DISCRIMINATOR_LEN + TransferRule::INIT_SPACE
+  }
+
+  /// Stores the rule, reallocating accounts created with an older layout first
+  pub fn save<'info>(
+    &self,
+    info: &AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+  ) -> Result<()> {
+    if TransferRule::needs_realloc(info) {
+      info.realloc(DISCRIMINATOR_LEN + TransferRule::INIT_SPACE, true)?;
+      update_account_lamports_to_minimum_balance(info.clone(), payer, system_program)?;
+    }
+    self.serialize(&mut &mut info.try_borrow_mut_data()?[DISCRIMINATOR_LEN..])?;
+    Ok(())
+  }
+
+  /// Checks that the rule is approved and the current time is inside `[locked_until, locked_after)`
+  pub fn enforce_time_window(&self, now: u64) -> Result<()> {
+    if self.locked_until == 0 {
+      return Err(TransferRestrictionsError::TransferGroupNotApproved.into());
+    }
+    if self.locked_until > now {
+      return Err(TransferRestrictionsError::TransferRuleNotAllowedUntilLater.into());
+    }
+    if self.locked_after != 0 && self.locked_after <= now {
+      return Err(TransferRestrictionsError::TransferRuleExpired.into());
+    }
+    Ok(())
+  }
+
+  /// Checks transfer amount against min/max amount and lot size (0 means no limit)
+  pub fn enforce_amount_limits(&self, amount: u64) -> Result<()> {
+    if self.min_amount != 0 && amount < self.min_amount {
+      return Err(TransferRestrictionsError::TransferAmountBelowMinimum.into());
+    }
+    if self.max_amount != 0 && amount > self.max_amount {
+      return Err(TransferRestrictionsError::TransferAmountAboveMaximum.into());
+    }
+    if amount.checked_rem(self.lot_size).is_some_and(|rem| rem != 0) {
+      return Err(TransferRestrictionsError::TransferAmountNotMultipleOfLotSize.into());
+    }
+    Ok(())
+  }
+}
+
+/// Returns the id of the transfer rule group, wildcard rules are initialized without the group
+pub fn transfer_rule_group_id(group: &Option<Account<TransferRestrictionGroup>>) -> u64 {
+  group.as_ref().map_or(ANY_TRANSFER_GROUP_ID, |group| group.id)
+}
+
+/// Returns the transfer rule seed of the group id. The IDL build can only resolve seeds of
+/// constants, arguments and accounts and would evaluate a method call on it as a constant.
+pub fn transfer_rule_group_seed(group: &Option<Account<TransferRestrictionGroup>>) -> [u8; 8] {
+  transfer_rule_group_id(group).to_le_bytes()
 }
 
 #[derive(Accounts)]
@@ -27,8 +106,8 @@ pub struct InitializeTransferRule<'info> {
     seeds = [
       TRANSFER_RULE_PREFIX.as_bytes(),
       &transfer_restriction_data.key().to_bytes(),
-      &transfer_restriction_group_from.id.to_le_bytes(),
-      &transfer_restriction_group_to.id.to_le_bytes(),
+      &transfer_rule_group_seed(&transfer_restriction_group_from),
+      &transfer_rule_group_seed(&transfer_restriction_group_to),
     ],
     bump,
   )]
@@ -42,11 +121,11 @@ pub struct InitializeTransferRule<'info> {
   #[account(
     constraint = transfer_restriction_group_from.transfer_restriction_data == transfer_restriction_data.key(),
   )]  
-  pub transfer_restriction_group_from: Account<'info, TransferRestrictionGroup>,
+  pub transfer_restriction_group_from: Option<Account<'info, TransferRestrictionGroup>>,
   #[account(
     constraint = transfer_restriction_group_to.transfer_restriction_data == transfer_restriction_data.key(),
   )]
-  pub transfer_restriction_group_to: Account<'info, TransferRestrictionGroup>,
+  pub transfer_restriction_group_to: Option<Account<'info, TransferRestrictionGroup>>,
   pub access_control_account: Account<'info, AccessControl>,
   #[account(
     constraint = authority_wallet_role.owner == payer.key(),
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// CHECK: transfer restriction data, group, holder or holder group account created with an
+    /// older layout, the account type is checked by its discriminator
+    #[account(mut, owner = crate::ID)]
+    pub account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    HolderGroup, TransferRestrictionData, TransferRestrictionGroup, TransferRestrictionHolder,
+    SECURITY_ASSOCIATED_ACCOUNT_PREFIX,
+};
+
+#[derive(Accounts)]
+pub struct MigrateSecurityAssociatedAccount<'info> {
+    /// CHECK: Account<'info, SecurityAssociatedAccount>, unchecked so that accounts created
+    /// before the holder balances were tracked can be reallocated
+    #[account(mut,
+      seeds = [
+        SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
+        &token_account.key().to_bytes(),
+      ],
+      bump,
+      owner = crate::ID,
+    )]
+    pub security_associated_account: UncheckedAccount<'info>,
+    #[account(mut,
+      constraint = holder.transfer_restriction_data == transfer_restriction_data.key(),
+    )]
+    pub holder: Option<Account<'info, TransferRestrictionHolder>>,
+    #[account(mut)]
+    pub holder_group: Option<Account<'info, HolderGroup>>,
+    #[account(mut,
+      constraint = group.transfer_restriction_data == transfer_restriction_data.key(),
+    )]
+    pub group: Option<Account<'info, TransferRestrictionGroup>>,
+    #[account(mut,
+      constraint = transfer_restriction_data.security_token_mint == token_account.mint,
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+    #[account(
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,81 @@
+use access_control::WalletRole;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::{
+    contexts::common::DISCRIMINATOR_LEN, HolderGroup, SecurityAssociatedAccount,
+    TransferRestrictionData, TransferRestrictionGroup, TransferRestrictionHolder,
+    SECURITY_ASSOCIATED_ACCOUNT_PREFIX, TRANSFER_RESTRICTION_DATA_PREFIX,
+    TRANSFER_RESTRICTION_GROUP_PREFIX, TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX,
+    TRANSFER_RESTRICTION_HOLDER_PREFIX,
+};
+
+#[derive(Accounts)]
+#[instruction(holder_id: u64)]
+pub struct ProvisionInvestor<'info> {
+    #[account(init, payer = payer, space = DISCRIMINATOR_LEN + TransferRestrictionHolder::INIT_SPACE,
+      seeds = [
+        TRANSFER_RESTRICTION_HOLDER_PREFIX.as_bytes(),
+        &transfer_restriction_data.key().to_bytes(),
+        &holder_id.to_le_bytes(),
+      ],
+      bump,
+    )]
+    pub holder: Box<Account<'info, TransferRestrictionHolder>>,
+    #[account(init, payer = payer, space = DISCRIMINATOR_LEN + HolderGroup::INIT_SPACE,
+      seeds = [
+        TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX.as_bytes(),
+        &holder.key().to_bytes(),
+        &group.id.to_le_bytes(),
+      ],
+      bump,
+    )]
+    pub holder_group: Box<Account<'info, HolderGroup>>,
+    #[account(init, payer = payer, space = DISCRIMINATOR_LEN + SecurityAssociatedAccount::INIT_SPACE,
+      seeds = [
+        SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
+        &associated_token_account.key().to_bytes(),
+      ],
+      bump,
+    )]
+    pub security_associated_account: Box<Account<'info, SecurityAssociatedAccount>>,
+    #[account(mut,
+      seeds = [
+        TRANSFER_RESTRICTION_GROUP_PREFIX.as_bytes(),
+        &transfer_restriction_data.key().to_bytes(),
+        &group.id.to_le_bytes()
+      ],
+      bump,
+      constraint = group.transfer_restriction_data == transfer_restriction_data.key(),
+    )]
+    pub group: Box<Account<'info, TransferRestrictionGroup>>,
+    #[account(
+      constraint = security_token.key() == transfer_restriction_data.security_token_mint,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub security_token: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut,
+      seeds = [
+        TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+        &security_token.key().to_bytes(),
+      ],
+      bump
+    )]
+    pub transfer_restriction_data: Box<Account<'info, TransferRestrictionData>>,
+    /// CHECK: Wallet address
+    pub user_wallet: AccountInfo<'info>,
+    #[account(
+      associated_token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+      associated_token::mint = security_token,
+      associated_token::authority = user_wallet,
+    )]
+    pub associated_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+      constraint = authority_wallet_role.owner == payer.key(),
+      constraint = authority_wallet_role.access_control == transfer_restriction_data.access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,44 @@
+use access_control::WalletRole;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    TransferRestrictionData, TransferRestrictionGroup, TRANSFER_RESTRICTION_DATA_PREFIX,
+    TRANSFER_RESTRICTION_GROUP_PREFIX,
+};
+
+#[derive(Accounts)]
+#[instruction(holder_ids: Vec<u64>)]
+pub struct ProvisionInvestors<'info> {
+    #[account(mut,
+      seeds = [
+        TRANSFER_RESTRICTION_GROUP_PREFIX.as_bytes(),
+        &transfer_restriction_data.key().to_bytes(),
+        &group.id.to_le_bytes()
+      ],
+      bump,
+      constraint = group.transfer_restriction_data == transfer_restriction_data.key(),
+    )]
+    pub group: Box<Account<'info, TransferRestrictionGroup>>,
+    #[account(
+      constraint = security_token.key() == transfer_restriction_data.security_token_mint,
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub security_token: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut,
+      seeds = [
+        TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+        &security_token.key().to_bytes(),
+      ],
+      bump
+    )]
+    pub transfer_restriction_data: Box<Account<'info, TransferRestrictionData>>,
+    #[account(
+      constraint = authority_wallet_role.owner == payer.key(),
+      constraint = authority_wallet_role.access_control == transfer_restriction_data.access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,29 @@
+use access_control::WalletRole;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{TransferRestrictionData, TRANSFER_RESTRICTION_DATA_PREFIX};
+
+#[derive(Accounts)]
+#[instruction(valid_until: u64)]
+pub struct RenewWalletsValidity<'info> {
+    #[account(
+      constraint = security_token.key() == transfer_restriction_data.security_token_mint,
+    )]
+    pub security_token: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      seeds = [
+        TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+        &security_token.key().to_bytes(),
+      ],
+      bump
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+    #[account(
+        constraint = authority_wallet_role.owner == payer.key(),
+        constraint = authority_wallet_role.access_control == transfer_restriction_data.access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
@@ -8,7 +8,7 @@ use anchor_lang::prelude::*;
 #[derive(Accounts)]
 pub struct RevokeHolder<'info> {
     #[account(mut,
-        close = payer,
+        close = rent_receiver,
         seeds = [
             TRANSFER_RESTRICTION_HOLDER_PREFIX.as_bytes(),
             &transfer_restriction_data.key().to_bytes(),
@@ -33,5 +33,8 @@ pub struct RevokeHolder<'info> {
 
     #[account(mut)]
     pub payer: Signer<'info>,
+    /// CHECK: Receiver of the closed account rent
+    #[account(mut)]
+    pub rent_receiver: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
 }
@@ -18,7 +18,7 @@ pub struct RevokeHolderGroup<'info> {
     pub holder: Account<'info, TransferRestrictionHolder>,
 
     #[account(mut,
-        close = payer,
+        close = rent_receiver,
         seeds = [
             TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX.as_bytes(),
             &holder.key().to_bytes(),
@@ -55,5 +55,8 @@ pub struct RevokeHolderGroup<'info> {
 
     #[account(mut)]
     pub payer: Signer<'info>,
+    /// CHECK: Receiver of the closed account rent
+    #[account(mut)]
+    pub rent_receiver: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
 }
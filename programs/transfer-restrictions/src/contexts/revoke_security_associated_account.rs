@@ -12,12 +12,14 @@ use crate::{
 #[instruction()]
 pub struct RevokeSecurityAssociatedAccount<'info> {
     #[account(mut,
-      close = payer,
+      close = rent_receiver,
       seeds = [
         SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
         &associated_token_account.key().to_bytes(),
       ],
       bump,
+      constraint = security_associated_account.group == group.id,
+      constraint = security_associated_account.holder == Some(holder.key()),
     )]
     pub security_associated_account: Account<'info, SecurityAssociatedAccount>,
     #[account(mut,
@@ -38,7 +40,7 @@ pub struct RevokeSecurityAssociatedAccount<'info> {
       token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
     )]
     pub security_token: Box<InterfaceAccount<'info, Mint>>,
-    #[account(
+    #[account(mut,
       seeds = [
         TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
         &security_token.key().to_bytes(),
@@ -61,5 +63,8 @@ pub struct RevokeSecurityAssociatedAccount<'info> {
     pub authority_wallet_role: Account<'info, WalletRole>,
     #[account(mut)]
     pub payer: Signer<'info>,
+    /// CHECK: Receiver of the closed account rent
+    #[account(mut)]
+    pub rent_receiver: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
 }
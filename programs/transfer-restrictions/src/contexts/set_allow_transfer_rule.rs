@@ -1,24 +1,26 @@
 use crate::{
-    TransferRestrictionData, TransferRestrictionGroup, TransferRule,
+    transfer_rule_group_seed, TransferRestrictionData, TransferRestrictionGroup,
     TRANSFER_RESTRICTION_DATA_PREFIX, TRANSFER_RULE_PREFIX,
 };
 use access_control::{self, AccessControl, WalletRole};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
-#[instruction(locked_until: u64)]
+#[instruction(locked_until: u64, locked_after: u64)]
 pub struct SetAllowTransferRule<'info> {
+    /// CHECK: Account<'info, TransferRule>, unchecked so that rules created
+    /// with an older layout can be reallocated
     #[account(mut,
         seeds = [
             TRANSFER_RULE_PREFIX.as_bytes(),
             &transfer_restriction_data.key().to_bytes(),
-            &transfer_restriction_group_from.id.to_le_bytes(),
-            &transfer_restriction_group_to.id.to_le_bytes(),
+            &transfer_rule_group_seed(&transfer_restriction_group_from),
+            &transfer_rule_group_seed(&transfer_restriction_group_to),
         ],
         bump,
-        constraint = transfer_rule.transfer_restriction_data == transfer_restriction_data.key(),
+        owner = crate::ID,
     )]
-    pub transfer_rule: Account<'info, TransferRule>,
+    pub transfer_rule: UncheckedAccount<'info>,
 
     #[account(
         seeds = [TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(), &access_control_account.mint.key().to_bytes()],
@@ -28,15 +30,13 @@ pub struct SetAllowTransferRule<'info> {
 
     #[account(
         constraint = transfer_restriction_group_from.transfer_restriction_data == transfer_restriction_data.key(),
-        constraint = transfer_restriction_group_from.id == transfer_rule.transfer_group_id_from,
     )]
-    pub transfer_restriction_group_from: Account<'info, TransferRestrictionGroup>,
+    pub transfer_restriction_group_from: Option<Account<'info, TransferRestrictionGroup>>,
 
     #[account(
         constraint = transfer_restriction_group_to.transfer_restriction_data == transfer_restriction_data.key(),
-        constraint = transfer_restriction_group_to.id == transfer_rule.transfer_group_id_to,
     )]
-    pub transfer_restriction_group_to: Account<'info, TransferRestrictionGroup>,
+    pub transfer_restriction_group_to: Option<Account<'info, TransferRestrictionGroup>>,
 
     #[account(
         constraint = access_control_account.mint == transfer_restriction_data.security_token_mint,
@@ -52,4 +52,5 @@ pub struct SetAllowTransferRule<'info> {
 
     #[account(mut)]
     pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
@@ -0,0 +1,56 @@
+use crate::{
+    transfer_rule_group_seed, TransferRestrictionData, TransferRestrictionGroup,
+    TRANSFER_RESTRICTION_DATA_PREFIX, TRANSFER_RULE_PREFIX,
+};
+use access_control::{self, AccessControl, WalletRole};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(min_amount: u64, max_amount: u64, lot_size: u64)]
+pub struct SetTransferRuleAmountLimits<'info> {
+    /// CHECK: Account<'info, TransferRule>, unchecked so that rules created
+    /// before the amount limits were introduced can be reallocated
+    #[account(mut,
+        seeds = [
+            TRANSFER_RULE_PREFIX.as_bytes(),
+            &transfer_restriction_data.key().to_bytes(),
+            &transfer_rule_group_seed(&transfer_restriction_group_from),
+            &transfer_rule_group_seed(&transfer_restriction_group_to),
+        ],
+        bump,
+        owner = crate::ID,
+    )]
+    pub transfer_rule: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(), &access_control_account.mint.key().to_bytes()],
+        bump,
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+
+    #[account(
+        constraint = transfer_restriction_group_from.transfer_restriction_data == transfer_restriction_data.key(),
+    )]
+    pub transfer_restriction_group_from: Option<Account<'info, TransferRestrictionGroup>>,
+
+    #[account(
+        constraint = transfer_restriction_group_to.transfer_restriction_data == transfer_restriction_data.key(),
+    )]
+    pub transfer_restriction_group_to: Option<Account<'info, TransferRestrictionGroup>>,
+
+    #[account(
+        constraint = access_control_account.mint == transfer_restriction_data.security_token_mint,
+        constraint = access_control_account.key() == transfer_restriction_data.access_control_account,
+    )]
+    pub access_control_account: Account<'info, AccessControl>,
+
+    #[account(
+        constraint = authority_wallet_role.owner == payer.key(),
+        constraint = authority_wallet_role.access_control == access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    HolderGroup, SecurityAssociatedAccount, TransferRestrictionData, TransferRestrictionGroup,
+    TransferRestrictionHolder, SECURITY_ASSOCIATED_ACCOUNT_PREFIX,
+};
+
+#[derive(Accounts)]
+pub struct SyncHolderBalance<'info> {
+    #[account(mut,
+      seeds = [
+        SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
+        &token_account.key().to_bytes(),
+      ],
+      bump,
+      constraint = security_associated_account.holder == Some(holder.key()),
+    )]
+    pub security_associated_account: Account<'info, SecurityAssociatedAccount>,
+    #[account(mut)]
+    pub holder: Account<'info, TransferRestrictionHolder>,
+    #[account(mut,
+      constraint = holder_group.holder == holder.key(),
+      constraint = holder_group.group == security_associated_account.group,
+    )]
+    pub holder_group: Account<'info, HolderGroup>,
+    #[account(mut,
+      constraint = group.transfer_restriction_data == transfer_restriction_data.key(),
+      constraint = group.id == security_associated_account.group,
+    )]
+    pub group: Account<'info, TransferRestrictionGroup>,
+    #[account(mut,
+      constraint = holder.transfer_restriction_data == transfer_restriction_data.key(),
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+    #[account(
+      token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+}
@@ -4,8 +4,9 @@ use anchor_spl::token_interface::{Mint, TokenAccount};
 
 use crate::{
     HolderGroup, SecurityAssociatedAccount, TransferRestrictionData, TransferRestrictionGroup,
-    SECURITY_ASSOCIATED_ACCOUNT_PREFIX, TRANSFER_RESTRICTION_DATA_PREFIX,
-    TRANSFER_RESTRICTION_GROUP_PREFIX, TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX,
+    TransferRestrictionHolder, SECURITY_ASSOCIATED_ACCOUNT_PREFIX,
+    TRANSFER_RESTRICTION_DATA_PREFIX, TRANSFER_RESTRICTION_GROUP_PREFIX,
+    TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX,
 };
 
 #[derive(Accounts)]
@@ -75,6 +76,10 @@ pub struct UpdateWalletGroup<'info> {
       constraint = holder_group_new.holder == security_associated_account.holder.unwrap(),
     )]
     pub holder_group_new: Account<'info, HolderGroup>,
+    #[account(
+      constraint = holder.key() == security_associated_account.holder.unwrap(),
+    )]
+    pub holder: Account<'info, TransferRestrictionHolder>,
 
     #[account(
         constraint = authority_wallet_role.owner == payer.key(),
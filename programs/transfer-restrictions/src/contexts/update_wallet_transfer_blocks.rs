@@ -0,0 +1,48 @@
+use access_control::WalletRole;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::{
+    SecurityAssociatedAccount, TransferRestrictionData, SECURITY_ASSOCIATED_ACCOUNT_PREFIX,
+    TRANSFER_RESTRICTION_DATA_PREFIX,
+};
+
+#[derive(Accounts)]
+#[instruction(send_blocked: bool, receive_blocked: bool)]
+pub struct UpdateWalletTransferBlocks<'info> {
+    #[account(mut,
+      seeds = [
+        SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
+        &user_associated_token_account.key().to_bytes(),
+      ],
+      bump,
+    )]
+    pub security_associated_account: Account<'info, SecurityAssociatedAccount>,
+    #[account(
+      constraint = security_token.key() == transfer_restriction_data.security_token_mint,
+    )]
+    pub security_token: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      seeds = [
+        TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+        &security_token.key().to_bytes(),
+      ],
+      bump
+    )]
+    pub transfer_restriction_data: Account<'info, TransferRestrictionData>,
+    #[account(
+        constraint = authority_wallet_role.owner == payer.key(),
+        constraint = authority_wallet_role.access_control == transfer_restriction_data.access_control_account.key(),
+    )]
+    pub authority_wallet_role: Account<'info, WalletRole>,
+    /// CHECK: Wallet address
+    pub user_wallet: AccountInfo<'info>,
+    #[account(
+      associated_token::token_program = anchor_spl::token_interface::spl_token_2022::id(),
+      associated_token::mint = security_token,
+      associated_token::authority = user_wallet,
+    )]
+    pub user_associated_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
@@ -50,4 +50,66 @@ pub enum TransferRestrictionsError {
     CurrentHolderGroupCountMustBeZero,
     #[msg("The provided value is already set. No changes were made")]
     ValueUnchanged,
+    #[msg("Holder max balance exceeded")]
+    HolderMaxBalanceExceeded,
+    #[msg("Group max balance exceeded")]
+    GroupMaxBalanceExceeded,
+    #[msg("Transfer amount is below the minimum allowed by transfer rule")]
+    TransferAmountBelowMinimum,
+    #[msg("Transfer amount is above the maximum allowed by transfer rule")]
+    TransferAmountAboveMaximum,
+    #[msg("Transfer amount is not a multiple of transfer rule lot size")]
+    TransferAmountNotMultipleOfLotSize,
+    #[msg("Invalid transfer rule amount limits")]
+    InvalidTransferRuleAmountLimits,
+    #[msg("Velocity limit exceeded")]
+    VelocityLimitExceeded,
+    #[msg("Velocity period must be non-zero when velocity limit is set")]
+    InvalidVelocityPeriod,
+    #[msg("Transfer rule has expired")]
+    TransferRuleExpired,
+    #[msg("Transfer rule locked after must be later than locked until")]
+    InvalidTransferRuleTimeWindow,
+    #[msg("Sending tokens is blocked for the wallet")]
+    WalletSendBlocked,
+    #[msg("Receiving tokens is blocked for the wallet")]
+    WalletReceiveBlocked,
+    #[msg("Wallet KYC/accreditation has expired")]
+    WalletValidityExpired,
+    #[msg("Invalid remaining accounts")]
+    InvalidRemainingAccounts,
+    #[msg("New holder is the same as the current holder")]
+    NewHolderIsTheSameAsTheCurrentHolder,
+    #[msg("Holder is inactive")]
+    HolderInactive,
+    #[msg("Transfer group id is reserved for wildcard transfer rules")]
+    ReservedTransferGroupId,
+    #[msg("Wildcard transfer rule requires a group, use the default transfer rule instead")]
+    WildcardTransferRuleWithoutGroup,
+    #[msg("Group name is too long")]
+    GroupNameTooLong,
+    #[msg("Group jurisdiction is too long")]
+    GroupJurisdictionTooLong,
+    #[msg("Invalid group flags")]
+    InvalidGroupFlags,
+    #[msg("Sender group is locked until later")]
+    GroupLocked,
+    #[msg("Sender group is frozen")]
+    GroupFrozen,
+    #[msg("Transfers of the group are paused")]
+    GroupTransfersPaused,
+    #[msg("Scheduled pause must end after it starts and in the future")]
+    InvalidPauseSchedule,
+    #[msg("Tracked holder balance does not match the wallet balances")]
+    HolderBalanceOutOfSync,
+    #[msg("Account type cannot be migrated")]
+    InvalidAccountToMigrate,
+    #[msg("Account is already migrated")]
+    AccountAlreadyMigrated,
+    #[msg("Invalid holder accounts")]
+    InvalidHolderAccounts,
+    #[msg("Tracked holders of record count does not match the holder balances")]
+    HoldersOfRecordCountOutOfSync,
+    #[msg("Source balance is lower than the transfer amount")]
+    InsufficientSourceBalance,
 }
@@ -0,0 +1,495 @@
+use anchor_lang::prelude::*;
+
+use crate::TransferRule;
+
+/// Emitted when the transfer restriction data of the security token is initialized.
+#[event]
+pub struct TransferRestrictionDataInitializedEvent {
+    /// Transfer restriction data account.
+    pub transfer_restriction_data: Pubkey,
+    /// Security token mint.
+    pub mint: Pubkey,
+    /// Maximum number of holders.
+    pub max_holders: u64,
+    /// Admin who initialized the data.
+    pub authority: Pubkey,
+}
+
+/// Emitted when the transfer hook extra account metas are initialized.
+#[event]
+pub struct ExtraAccountMetaListInitializedEvent {
+    /// Security token mint.
+    pub mint: Pubkey,
+    /// Admin who initialized the extra account metas.
+    pub authority: Pubkey,
+}
+
+/// Emitted when a transfer restriction group is initialized.
+#[event]
+pub struct GroupInitializedEvent {
+    /// Group account.
+    pub group: Pubkey,
+    /// Id of the group.
+    pub group_id: u64,
+    /// Admin who initialized the group.
+    pub authority: Pubkey,
+}
+
+/// Emitted when a holder is initialized.
+#[event]
+pub struct HolderInitializedEvent {
+    /// Holder account.
+    pub holder: Pubkey,
+    /// Id of the holder.
+    pub holder_id: u64,
+    /// Admin who initialized the holder.
+    pub authority: Pubkey,
+}
+
+/// Emitted when a holder group is initialized.
+#[event]
+pub struct HolderGroupInitializedEvent {
+    /// Holder group account.
+    pub holder_group: Pubkey,
+    /// Holder account.
+    pub holder: Pubkey,
+    /// Id of the group.
+    pub group_id: u64,
+    /// Admin who initialized the holder group.
+    pub authority: Pubkey,
+}
+
+/// Emitted when a security associated account of a wallet is initialized.
+#[event]
+pub struct SecurityAssociatedAccountInitializedEvent {
+    /// Security associated account.
+    pub security_associated_account: Pubkey,
+    /// Token account of the wallet.
+    pub token_account: Pubkey,
+    /// Id of the holder of the wallet.
+    pub holder_id: u64,
+    /// Id of the group of the wallet.
+    pub group_id: u64,
+    /// Admin who initialized the security associated account.
+    pub authority: Pubkey,
+}
+
+/// Emitted for every investor onboarded at once with its holder, holder group
+/// and security associated account.
+#[event]
+pub struct InvestorProvisionedEvent {
+    /// Holder account.
+    pub holder: Pubkey,
+    /// Id of the holder.
+    pub holder_id: u64,
+    /// Holder group account.
+    pub holder_group: Pubkey,
+    /// Security associated account.
+    pub security_associated_account: Pubkey,
+    /// Token account of the investor wallet.
+    pub token_account: Pubkey,
+    /// Id of the group of the investor.
+    pub group_id: u64,
+    /// Admin who provisioned the investor.
+    pub authority: Pubkey,
+}
+
+/// Emitted when a transfer rule between groups is initialized.
+#[event]
+pub struct TransferRuleInitializedEvent {
+    /// Transfer rule account.
+    pub transfer_rule: Pubkey,
+    /// Id of the sender group, `ANY_TRANSFER_GROUP_ID` for any group.
+    pub group_id_from: u64,
+    /// Id of the recipient group, `ANY_TRANSFER_GROUP_ID` for any group.
+    pub group_id_to: u64,
+    /// Timestamp transfers are allowed from.
+    pub locked_until: u64,
+    /// Admin who initialized the transfer rule.
+    pub authority: Pubkey,
+}
+
+/// Emitted when the time window of a transfer rule is changed.
+#[event]
+pub struct AllowTransferRuleSetEvent {
+    /// Transfer rule account.
+    pub transfer_rule: Pubkey,
+    /// Id of the sender group.
+    pub group_id_from: u64,
+    /// Id of the recipient group.
+    pub group_id_to: u64,
+    /// Previous timestamp transfers were allowed from.
+    pub locked_until_before: u64,
+    /// Previous timestamp transfers were allowed until.
+    pub locked_after_before: u64,
+    /// New timestamp transfers are allowed from.
+    pub locked_until: u64,
+    /// New timestamp transfers are allowed until, 0 for no end.
+    pub locked_after: u64,
+    /// Admin who changed the transfer rule.
+    pub authority: Pubkey,
+}
+
+/// Emitted when a wallet is moved to another group.
+#[event]
+pub struct WalletGroupUpdatedEvent {
+    /// Security associated account of the wallet.
+    pub security_associated_account: Pubkey,
+    /// Previous group id of the wallet.
+    pub group_id_before: u64,
+    /// New group id of the wallet.
+    pub group_id: u64,
+    /// Admin who moved the wallet.
+    pub authority: Pubkey,
+}
+
+/// Emitted when all transfers are paused or unpaused.
+#[event]
+pub struct PausedEvent {
+    /// Previous pause flag.
+    pub paused_before: bool,
+    /// New pause flag.
+    pub paused: bool,
+    /// Admin who changed the pause flag.
+    pub authority: Pubkey,
+}
+
+/// Emitted when transfers of a group are paused or unpaused.
+#[event]
+pub struct GroupPausedEvent {
+    /// Group account.
+    pub group: Pubkey,
+    /// Id of the group.
+    pub group_id: u64,
+    /// Previous pause flag of the group.
+    pub paused_before: bool,
+    /// New pause flag of the group.
+    pub paused: bool,
+    /// Admin who changed the pause flag.
+    pub authority: Pubkey,
+}
+
+/// Emitted when a pause of all transfers is scheduled or canceled.
+#[event]
+pub struct PauseScheduledEvent {
+    /// Previous start of the scheduled pause.
+    pub paused_from_before: u64,
+    /// Previous end of the scheduled pause.
+    pub paused_until_before: u64,
+    /// New start of the scheduled pause.
+    pub paused_from: u64,
+    /// New end of the scheduled pause, 0 if canceled.
+    pub paused_until: u64,
+    /// Admin who scheduled the pause.
+    pub authority: Pubkey,
+}
+
+/// Emitted when the maximum number of holders is changed.
+#[event]
+pub struct HolderMaxSetEvent {
+    /// Previous maximum number of holders.
+    pub holder_max_before: u64,
+    /// New maximum number of holders.
+    pub holder_max: u64,
+    /// Admin who changed the maximum.
+    pub authority: Pubkey,
+}
+
+/// Emitted when the maximum number of holders of a group is changed.
+#[event]
+pub struct HolderGroupMaxSetEvent {
+    /// Group account.
+    pub group: Pubkey,
+    /// Id of the group.
+    pub group_id: u64,
+    /// Previous maximum number of holders in the group.
+    pub holder_max_before: u64,
+    /// New maximum number of holders in the group.
+    pub holder_max: u64,
+    /// Admin who changed the maximum.
+    pub authority: Pubkey,
+}
+
+/// Emitted when a holder is revoked.
+#[event]
+pub struct HolderRevokedEvent {
+    /// Holder account.
+    pub holder: Pubkey,
+    /// Id of the holder.
+    pub holder_id: u64,
+    /// Admin who revoked the holder.
+    pub authority: Pubkey,
+}
+
+/// Emitted when a holder group is revoked.
+#[event]
+pub struct HolderGroupRevokedEvent {
+    /// Holder group account.
+    pub holder_group: Pubkey,
+    /// Holder account.
+    pub holder: Pubkey,
+    /// Id of the group.
+    pub group_id: u64,
+    /// Admin who revoked the holder group.
+    pub authority: Pubkey,
+}
+
+/// Emitted when a security associated account of a wallet is revoked.
+#[event]
+pub struct SecurityAssociatedAccountRevokedEvent {
+    /// Security associated account.
+    pub security_associated_account: Pubkey,
+    /// Token account of the wallet.
+    pub token_account: Pubkey,
+    /// Id of the holder of the wallet.
+    pub holder_id: u64,
+    /// Id of the group of the wallet.
+    pub group_id: u64,
+    /// Admin who revoked the security associated account.
+    pub authority: Pubkey,
+}
+
+/// Emitted when the outbound lockup or freeze of a group is changed.
+#[event]
+pub struct GroupLockupSetEvent {
+    /// Group account.
+    pub group: Pubkey,
+    /// Id of the group.
+    pub group_id: u64,
+    /// Previous timestamp outbound transfers were locked until.
+    pub locked_until_before: u64,
+    /// Previous freeze flag of the group.
+    pub frozen_before: bool,
+    /// New timestamp outbound transfers are locked until, 0 for no lockup.
+    pub locked_until: u64,
+    /// New freeze flag of the group.
+    pub frozen: bool,
+    /// Admin who changed the lockup.
+    pub authority: Pubkey,
+}
+
+/// Emitted when the maximum balance of a group holder is changed.
+#[event]
+pub struct GroupMaxBalanceSetEvent {
+    /// Group account.
+    pub group: Pubkey,
+    /// Id of the group.
+    pub group_id: u64,
+    /// Previous maximum balance.
+    pub max_balance_before: u64,
+    /// New maximum balance, 0 for no limit.
+    pub max_balance: u64,
+    /// Admin who changed the maximum balance.
+    pub authority: Pubkey,
+}
+
+/// Emitted when the name, jurisdiction or flags of a group are changed.
+#[event]
+pub struct GroupMetadataSetEvent {
+    /// Group account.
+    pub group: Pubkey,
+    /// Id of the group.
+    pub group_id: u64,
+    /// Previous name of the group.
+    pub name_before: String,
+    /// Previous jurisdiction code of the group.
+    pub jurisdiction_before: String,
+    /// Previous flags of the group.
+    pub flags_before: u8,
+    /// New name of the group.
+    pub name: String,
+    /// New jurisdiction code of the group.
+    pub jurisdiction: String,
+    /// New flags of the group.
+    pub flags: u8,
+    /// Admin who changed the metadata.
+    pub authority: Pubkey,
+}
+
+/// Emitted when the velocity limit of a group is changed.
+#[event]
+pub struct GroupVelocityLimitSetEvent {
+    /// Group account.
+    pub group: Pubkey,
+    /// Id of the group.
+    pub group_id: u64,
+    /// Previous velocity limit.
+    pub velocity_limit_before: u64,
+    /// Previous velocity period in seconds.
+    pub velocity_period_before: u64,
+    /// New velocity limit, 0 for no limit.
+    pub velocity_limit: u64,
+    /// New velocity period in seconds.
+    pub velocity_period: u64,
+    /// Admin who changed the velocity limit.
+    pub authority: Pubkey,
+}
+
+/// Emitted when a holder is activated or deactivated.
+#[event]
+pub struct HolderActiveSetEvent {
+    /// Holder account.
+    pub holder: Pubkey,
+    /// Id of the holder.
+    pub holder_id: u64,
+    /// Previous active flag.
+    pub active_before: bool,
+    /// New active flag.
+    pub active: bool,
+    /// Admin who changed the active flag.
+    pub authority: Pubkey,
+}
+
+/// Emitted when the maximum balance of a holder is changed.
+#[event]
+pub struct HolderMaxBalanceSetEvent {
+    /// Holder account.
+    pub holder: Pubkey,
+    /// Id of the holder.
+    pub holder_id: u64,
+    /// Previous maximum balance.
+    pub max_balance_before: u64,
+    /// New maximum balance, 0 for no limit.
+    pub max_balance: u64,
+    /// Admin who changed the maximum balance.
+    pub authority: Pubkey,
+}
+
+/// Emitted when holders of record enforcement at transfer time is enabled or disabled.
+#[event]
+pub struct HoldersOfRecordEnforcementSetEvent {
+    /// Previous enforcement flag.
+    pub enforced_before: bool,
+    /// New enforcement flag.
+    pub enforced: bool,
+    /// Admin who changed the enforcement.
+    pub authority: Pubkey,
+}
+
+/// Emitted when the default transfer rule is set or removed.
+#[event]
+pub struct DefaultTransferRuleSetEvent {
+    /// Previous default transfer rule.
+    pub default_transfer_rule_before: Option<TransferRule>,
+    /// New default transfer rule, `None` if removed.
+    pub default_transfer_rule: Option<TransferRule>,
+    /// Admin who changed the default transfer rule.
+    pub authority: Pubkey,
+}
+
+/// Emitted when the amount limits of a transfer rule are changed.
+#[event]
+pub struct TransferRuleAmountLimitsSetEvent {
+    /// Transfer rule account.
+    pub transfer_rule: Pubkey,
+    /// Id of the sender group.
+    pub group_id_from: u64,
+    /// Id of the recipient group.
+    pub group_id_to: u64,
+    /// Previous minimum amount.
+    pub min_amount_before: u64,
+    /// Previous maximum amount.
+    pub max_amount_before: u64,
+    /// Previous lot size.
+    pub lot_size_before: u64,
+    /// New minimum amount, 0 for no limit.
+    pub min_amount: u64,
+    /// New maximum amount, 0 for no limit.
+    pub max_amount: u64,
+    /// New lot size, 0 for no limit.
+    pub lot_size: u64,
+    /// Admin who changed the amount limits.
+    pub authority: Pubkey,
+}
+
+/// Emitted when a wallet is moved to another holder.
+#[event]
+pub struct WalletHolderUpdatedEvent {
+    /// Security associated account of the wallet.
+    pub security_associated_account: Pubkey,
+    /// Previous holder account of the wallet.
+    pub holder_before: Pubkey,
+    /// New holder account of the wallet.
+    pub holder: Pubkey,
+    /// Previous holder id of the wallet.
+    pub holder_id_before: u64,
+    /// New holder id of the wallet.
+    pub holder_id: u64,
+    /// Admin who moved the wallet.
+    pub authority: Pubkey,
+}
+
+/// Emitted when sending or receiving is blocked or unblocked for a wallet.
+#[event]
+pub struct WalletTransferBlocksUpdatedEvent {
+    /// Security associated account of the wallet.
+    pub security_associated_account: Pubkey,
+    /// Previous send block flag.
+    pub send_blocked_before: bool,
+    /// Previous receive block flag.
+    pub receive_blocked_before: bool,
+    /// New send block flag.
+    pub send_blocked: bool,
+    /// New receive block flag.
+    pub receive_blocked: bool,
+    /// Admin who changed the transfer blocks.
+    pub authority: Pubkey,
+}
+
+/// Emitted for every wallet whose KYC/accreditation validity is renewed.
+#[event]
+pub struct WalletValidityRenewedEvent {
+    /// Security associated account of the wallet.
+    pub security_associated_account: Pubkey,
+    /// Previous validity timestamp.
+    pub valid_until_before: u64,
+    /// New validity timestamp, 0 for no expiry.
+    pub valid_until: u64,
+    /// Admin who renewed the validity.
+    pub authority: Pubkey,
+}
+
+/// Emitted when the tokenlock escrow account is set.
+#[event]
+pub struct LockupEscrowAccountSetEvent {
+    /// Security token mint.
+    pub mint: Pubkey,
+    /// Previous escrow account.
+    pub lockup_escrow_account_before: Option<Pubkey>,
+    /// New escrow account.
+    pub lockup_escrow_account: Pubkey,
+    /// Tokenlock account of the escrow.
+    pub tokenlock_account: Pubkey,
+    /// Admin who set the escrow account.
+    pub authority: Pubkey,
+}
+
+/// Emitted when a wallet balance changed without a transfer is recorded in its holder balance.
+#[event]
+pub struct HolderBalanceSyncedEvent {
+    /// Security associated account of the wallet.
+    pub security_associated_account: Pubkey,
+    /// Holder account of the wallet.
+    pub holder: Pubkey,
+    /// Previously recorded wallet balance.
+    pub balance_before: u64,
+    /// New wallet balance.
+    pub balance: u64,
+    /// Previous holder balance.
+    pub holder_balance_before: u64,
+    /// New holder balance.
+    pub holder_balance: u64,
+}
+
+/// Emitted when the transfer hook extra account metas are updated.
+#[event]
+pub struct ExtraAccountMetaListUpdatedEvent {
+    /// Security token mint.
+    pub mint: Pubkey,
+    /// Previous size of the extra account metas account.
+    pub size_before: u64,
+    /// New size of the extra account metas account.
+    pub size: u64,
+    /// Admin who updated the extra account metas.
+    pub authority: Pubkey,
+}
@@ -6,95 +6,520 @@ use anchor_spl::{
 };
 
 use crate::{
-    errors::TransferRestrictionsError, verify_pda,
-    ExecuteTransferHook, SecurityAssociatedAccount, TransferRestrictionData, TransferRule,
-    SECURITY_ASSOCIATED_ACCOUNT_PREFIX, TRANSFER_RESTRICTION_DATA_PREFIX, TRANSFER_RULE_PREFIX,
+    errors::TransferRestrictionsError, record_holder_wallet_balance, verify_pda,
+    ExecuteTransferHook, HolderGroup, SecurityAssociatedAccount, TransferRestrictionData,
+    TransferRestrictionGroup, TransferRestrictionHolder, TransferRule, ANY_TRANSFER_GROUP_ID,
+    SECURITY_ASSOCIATED_ACCOUNT_PREFIX, TRANSFER_RESTRICTION_DATA_PREFIX,
+    TRANSFER_RESTRICTION_GROUP_PREFIX, TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX,
+    TRANSFER_RULE_PREFIX,
 };
 
-pub fn handler(ctx: Context<ExecuteTransferHook>, _amount: u64) -> Result<()> {
+pub fn handler(ctx: Context<ExecuteTransferHook>, amount: u64) -> Result<()> {
     let mint_data: &AccountInfo = &ctx.accounts.mint.to_account_info();
+    // token accounts already contain post-transfer balances at this point
+    let check = TransferCheck {
+        mint: mint_data.key(),
+        source_account: ctx.accounts.source_account.key(),
+        source_balance: ctx.accounts.source_account.amount,
+        destination_account: ctx.accounts.destination_account.key(),
+        destination_balance: ctx.accounts.destination_account.amount,
+        amount,
+        transfer_restriction_data: &ctx.accounts.transfer_restriction_data,
+        security_associated_account_from: &ctx.accounts.security_associated_account_from,
+        security_associated_account_to: &ctx.accounts.security_associated_account_to,
+        transfer_rule: &ctx.accounts.transfer_rule,
+        transfer_restriction_holder_from: &ctx.accounts.transfer_restriction_holder_from,
+        transfer_restriction_holder_to: &ctx.accounts.transfer_restriction_holder_to,
+        transfer_restriction_group_from: &ctx.accounts.transfer_restriction_group_from,
+        transfer_restriction_group_to: &ctx.accounts.transfer_restriction_group_to,
+        holder_group_from: &ctx.accounts.holder_group_from,
+        holder_group_to: &ctx.accounts.holder_group_to,
+        transfer_rule_from_group_to_any: &ctx.accounts.transfer_rule_from_group_to_any,
+        transfer_rule_from_any_to_group: &ctx.accounts.transfer_rule_from_any_to_group,
+    };
+
     let permanent_delegate_extension = get_mint_extension_data::<PermanentDelegate>(mint_data)?;
     // if permanent delegate is execute transfer hook owner delegate
     // we don't need to check transfer restrictions because it's force transfer between
     if permanent_delegate_extension.delegate
         == Some(ctx.accounts.owner_delegate.key()).try_into().unwrap()
     {
+        load_transfer_restriction_data(&check, &AccountsData::Persisted)?;
+        record_transfer_balances(&check, &mut AccountsData::Persisted)?;
         return Ok(());
     }
 
-    verify_pda(
-        ctx.accounts.transfer_restriction_data.key,
-        &[
-            TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
-            &mint_data.key().to_bytes(),
-        ],
-        &ctx.program_id,
-    )?;
-    if ctx.accounts.transfer_restriction_data.data_is_empty() {
-        return Err(TransferRestrictionsError::TransferRestrictionsAccountDataIsEmtpy.into());
+    check_transfer(&check, &mut AccountsData::Persisted)
+}
+
+/// Transfer checked against the transfer restrictions with its post-transfer wallet balances
+pub struct TransferCheck<'a, 'info> {
+    pub mint: Pubkey,
+    pub source_account: Pubkey,
+    pub source_balance: u64,
+    pub destination_account: Pubkey,
+    pub destination_balance: u64,
+    pub amount: u64,
+    pub transfer_restriction_data: &'a AccountInfo<'info>,
+    pub security_associated_account_from: &'a AccountInfo<'info>,
+    pub security_associated_account_to: &'a AccountInfo<'info>,
+    pub transfer_rule: &'a AccountInfo<'info>,
+    pub transfer_restriction_holder_from: &'a AccountInfo<'info>,
+    pub transfer_restriction_holder_to: &'a AccountInfo<'info>,
+    pub transfer_restriction_group_from: &'a AccountInfo<'info>,
+    pub transfer_restriction_group_to: &'a AccountInfo<'info>,
+    pub holder_group_from: &'a AccountInfo<'info>,
+    pub holder_group_to: &'a AccountInfo<'info>,
+    pub transfer_rule_from_group_to_any: &'a AccountInfo<'info>,
+    pub transfer_rule_from_any_to_group: &'a AccountInfo<'info>,
+}
+
+/// Destination of the account updates made while checking a transfer
+pub enum AccountsData {
+    /// Updates are written to the accounts (transfer hook)
+    Persisted,
+    /// Updates are only kept in memory (transfer restriction detection)
+    Simulated(Vec<(Pubkey, Vec<u8>)>),
+}
+
+impl AccountsData {
+    fn load<T: AnchorDeserialize>(&self, info: &AccountInfo) -> Result<T> {
+        if let Self::Simulated(updates) = self {
+            if let Some((_, data)) = updates.iter().find(|(key, _)| key == info.key) {
+                return Ok(T::deserialize(&mut &data[DISCRIMINATOR_LEN..])?);
+            }
+        }
+        Ok(T::deserialize(
+            &mut &info.data.borrow()[DISCRIMINATOR_LEN..],
+        )?)
     }
-    let transfer_restriction_data = TransferRestrictionData::deserialize(
-        &mut &ctx.accounts.transfer_restriction_data.data.borrow()[DISCRIMINATOR_LEN..],
-    )?;
-    // transfer restriction for lockup escrow account is validated inside tokenlock program
-    if transfer_restriction_data.lockup_escrow_account == Some(ctx.accounts.source_account.key()) {
+
+    fn store<T: AnchorSerialize>(&mut self, info: &AccountInfo, value: &T) -> Result<()> {
+        match self {
+            Self::Persisted => {
+                value.serialize(&mut &mut info.try_borrow_mut_data()?[DISCRIMINATOR_LEN..])?
+            }
+            Self::Simulated(updates) => {
+                let mut data = info.data.borrow().to_vec();
+                value.serialize(&mut &mut data[DISCRIMINATOR_LEN..])?;
+                match updates.iter_mut().find(|(key, _)| key == info.key) {
+                    Some((_, updated)) => *updated = data,
+                    None => updates.push((*info.key, data)),
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Runs all transfer restriction checks of a regular (not forced) transfer
+pub fn check_transfer(check: &TransferCheck, accounts_data: &mut AccountsData) -> Result<()> {
+    let transfer_restriction_data = load_transfer_restriction_data(check, accounts_data)?;
+    // transfer restriction for lockup escrow account is validated inside tokenlock program,
+    // except for the holders of record caps which depend on the recorded balances
+    if transfer_restriction_data.lockup_escrow_account == Some(check.source_account) {
+        let recorded_to = record_transfer_balances(check, accounts_data)?;
+        if transfer_restriction_data.holders_of_record_enforced {
+            if let Some((_, joined_holders, joined_group_holders)) = recorded_to {
+                let group_to: TransferRestrictionGroup =
+                    accounts_data.load(check.transfer_restriction_group_to)?;
+                enforce_max_holders_of_record(
+                    check,
+                    accounts_data,
+                    &group_to,
+                    joined_holders,
+                    joined_group_holders,
+                )?;
+            }
+        }
         return Ok(());
     }
-    if transfer_restriction_data.paused {
+    let now = Clock::get()?.unix_timestamp as u64;
+    if transfer_restriction_data.is_paused(now) {
         return Err(TransferRestrictionsError::AllTransfersPaused.into());
     }
 
     verify_pda(
-        ctx.accounts.security_associated_account_from.key,
+        check.security_associated_account_from.key,
         &[
             SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
-            &ctx.accounts.source_account.key().to_bytes(),
+            &check.source_account.to_bytes(),
         ],
-        &ctx.program_id,
+        &crate::ID,
     )?;
-    if ctx.accounts.security_associated_account_from.data_is_empty() {
+    if check.security_associated_account_from.data_is_empty() {
         return Err(TransferRestrictionsError::SecurityAssociatedAccountDataIsEmtpy.into());
     }
-    let security_associated_account_from = SecurityAssociatedAccount::deserialize(
-        &mut &ctx.accounts.security_associated_account_from.data.borrow()[DISCRIMINATOR_LEN..],
-    )?;
+    let security_associated_account_from: SecurityAssociatedAccount =
+        accounts_data.load(check.security_associated_account_from)?;
     verify_pda(
-        ctx.accounts.security_associated_account_to.key,
+        check.security_associated_account_to.key,
         &[
             SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
-            &ctx.accounts.destination_account.key().to_bytes(),
+            &check.destination_account.to_bytes(),
         ],
-        &ctx.program_id,
+        &crate::ID,
     )?;
-    if ctx.accounts.security_associated_account_to.data_is_empty() {
+    if check.security_associated_account_to.data_is_empty() {
         return Err(TransferRestrictionsError::SecurityAssociatedAccountDataIsEmtpy.into());
     }
-    let security_associated_account_to = SecurityAssociatedAccount::deserialize(
-        &mut &ctx.accounts.security_associated_account_to.data.borrow()[DISCRIMINATOR_LEN..],
+    let security_associated_account_to: SecurityAssociatedAccount =
+        accounts_data.load(check.security_associated_account_to)?;
+    if security_associated_account_from.send_blocked {
+        return Err(TransferRestrictionsError::WalletSendBlocked.into());
+    }
+    if security_associated_account_to.receive_blocked {
+        return Err(TransferRestrictionsError::WalletReceiveBlocked.into());
+    }
+    for (security_associated_account, holder_info) in [
+        (
+            &security_associated_account_from,
+            check.transfer_restriction_holder_from,
+        ),
+        (
+            &security_associated_account_to,
+            check.transfer_restriction_holder_to,
+        ),
+    ] {
+        let Some(holder_key) = security_associated_account.holder else {
+            continue;
+        };
+        if *holder_info.key != holder_key {
+            return Err(TransferRestrictionsError::InvalidPDA.into());
+        }
+        let holder: TransferRestrictionHolder = accounts_data.load(holder_info)?;
+        if !holder.active {
+            return Err(TransferRestrictionsError::HolderInactive.into());
+        }
+    }
+    if security_associated_account_from.is_expired(now)
+        || security_associated_account_to.is_expired(now)
+    {
+        return Err(TransferRestrictionsError::WalletValidityExpired.into());
+    }
+
+    let transfer_rule = resolve_transfer_rule(
+        check.transfer_restriction_data.key,
+        &transfer_restriction_data,
+        [
+            check.transfer_rule,
+            check.transfer_rule_from_group_to_any,
+            check.transfer_rule_from_any_to_group,
+        ],
+        security_associated_account_from.group,
+        security_associated_account_to.group,
     )?;
+    transfer_rule.enforce_time_window(now)?;
+    transfer_rule.enforce_amount_limits(check.amount)?;
 
     verify_pda(
-        ctx.accounts.transfer_rule.key,
+        check.transfer_restriction_group_from.key,
         &[
-            TRANSFER_RULE_PREFIX.as_bytes(),
-            &ctx.accounts.transfer_restriction_data.key().to_bytes(),
+            TRANSFER_RESTRICTION_GROUP_PREFIX.as_bytes(),
+            &check.transfer_restriction_data.key().to_bytes(),
             &security_associated_account_from.group.to_le_bytes(),
+        ],
+        &crate::ID,
+    )?;
+    let group_from: TransferRestrictionGroup =
+        accounts_data.load(check.transfer_restriction_group_from)?;
+    verify_pda(
+        check.transfer_restriction_group_to.key,
+        &[
+            TRANSFER_RESTRICTION_GROUP_PREFIX.as_bytes(),
+            &check.transfer_restriction_data.key().to_bytes(),
             &security_associated_account_to.group.to_le_bytes(),
         ],
-        &ctx.program_id,
+        &crate::ID,
     )?;
-    if ctx.accounts.transfer_rule.data_is_empty() {
-        return Err(TransferRestrictionsError::TransferRuleAccountDataIsEmtpy.into());
+    let group_to_paused = accounts_data
+        .load::<TransferRestrictionGroup>(check.transfer_restriction_group_to)?
+        .paused;
+    if group_from.paused || group_to_paused {
+        return Err(TransferRestrictionsError::GroupTransfersPaused.into());
+    }
+    group_from.enforce_outbound_lockup(now)?;
+    if group_from.velocity_limit != 0 {
+        let mut security_associated_account: SecurityAssociatedAccount =
+            accounts_data.load(check.security_associated_account_from)?;
+        if !security_associated_account.record_sent_amount(
+            check.amount,
+            group_from.velocity_limit,
+            group_from.velocity_period,
+            now,
+        ) {
+            return Err(TransferRestrictionsError::VelocityLimitExceeded.into());
+        }
+        accounts_data.store(
+            check.security_associated_account_from,
+            &security_associated_account,
+        )?;
     }
-    let transfer_rule = TransferRule::deserialize(
-        &mut &ctx.accounts.transfer_rule.data.borrow()[DISCRIMINATOR_LEN..],
+
+    record_wallet_balance(
+        accounts_data,
+        check.transfer_restriction_data,
+        check.transfer_restriction_group_from,
+        check.transfer_restriction_holder_from,
+        check.holder_group_from,
+        check.security_associated_account_from,
+        check.source_balance,
+    )?;
+    let recorded_to = record_wallet_balance(
+        accounts_data,
+        check.transfer_restriction_data,
+        check.transfer_restriction_group_to,
+        check.transfer_restriction_holder_to,
+        check.holder_group_to,
+        check.security_associated_account_to,
+        check.destination_balance,
     )?;
-    if transfer_rule.locked_until == 0 {
-        return Err(TransferRestrictionsError::TransferGroupNotApproved.into());
+    let Some((holder_to, joined_holders, joined_group_holders)) = recorded_to else {
+        return Ok(());
+    };
+    let group_to: TransferRestrictionGroup =
+        accounts_data.load(check.transfer_restriction_group_to)?;
+
+    // moving tokens between wallets of the same holder does not change its balance
+    let same_holder =
+        security_associated_account_from.holder == security_associated_account_to.holder;
+    if !same_holder {
+        if holder_to.max_balance != 0 && holder_to.current_balance > holder_to.max_balance {
+            return Err(TransferRestrictionsError::HolderMaxBalanceExceeded.into());
+        }
+        if group_to.max_balance != 0 && holder_to.current_balance > group_to.max_balance {
+            return Err(TransferRestrictionsError::GroupMaxBalanceExceeded.into());
+        }
     }
-    if transfer_rule.locked_until > Clock::get()?.unix_timestamp as u64 {
-        return Err(TransferRestrictionsError::TransferRuleNotAllowedUntilLater.into());
+
+    if transfer_restriction_data.holders_of_record_enforced {
+        let same_holder_group = same_holder
+            && security_associated_account_from.group == security_associated_account_to.group;
+        enforce_max_holders_of_record(
+            check,
+            accounts_data,
+            &group_to,
+            joined_holders && !same_holder,
+            joined_group_holders && !same_holder_group,
+        )?;
     }
 
     Ok(())
 }
+
+/// Fails if the recipient became a holder of record beyond the max holders of the token
+/// or of its group, checked against the counts recorded after the transfer.
+fn enforce_max_holders_of_record(
+    check: &TransferCheck,
+    accounts_data: &AccountsData,
+    group_to: &TransferRestrictionGroup,
+    joined_holders: bool,
+    joined_group_holders: bool,
+) -> Result<()> {
+    if joined_holders {
+        let transfer_restriction_data: TransferRestrictionData =
+            accounts_data.load(check.transfer_restriction_data)?;
+        if transfer_restriction_data.current_holders_of_record_count
+            > transfer_restriction_data.max_holders
+        {
+            return Err(TransferRestrictionsError::MaxHoldersReached.into());
+        }
+    }
+    if joined_group_holders
+        && group_to.max_holders != 0
+        && group_to.current_holders_of_record_count > group_to.max_holders
+    {
+        return Err(TransferRestrictionsError::MaxHoldersReachedInsideTheGroup.into());
+    }
+    Ok(())
+}
+
+fn load_transfer_restriction_data(
+    check: &TransferCheck,
+    accounts_data: &AccountsData,
+) -> Result<TransferRestrictionData> {
+    verify_pda(
+        check.transfer_restriction_data.key,
+        &[
+            TRANSFER_RESTRICTION_DATA_PREFIX.as_bytes(),
+            &check.mint.to_bytes(),
+        ],
+        &crate::ID,
+    )?;
+    if check.transfer_restriction_data.data_is_empty() {
+        return Err(TransferRestrictionsError::TransferRestrictionsAccountDataIsEmtpy.into());
+    }
+    accounts_data.load(check.transfer_restriction_data)
+}
+
+/// Records the post-transfer wallet balances of a transfer that is not subject to the transfer
+/// restrictions (force transfer or lockup escrow release), so holder balances stay tracked.
+/// Wallets without a security associated account are skipped.
+/// Returns the recorded recipient holder as `record_wallet_balance` does.
+fn record_transfer_balances(
+    check: &TransferCheck,
+    accounts_data: &mut AccountsData,
+) -> Result<Option<(TransferRestrictionHolder, bool, bool)>> {
+    let mut recorded_to = None;
+    for (
+        is_destination,
+        token_account,
+        wallet_balance,
+        security_associated_account_info,
+        group_info,
+        holder_info,
+        holder_group_info,
+    ) in [
+        (
+            false,
+            check.source_account,
+            check.source_balance,
+            check.security_associated_account_from,
+            check.transfer_restriction_group_from,
+            check.transfer_restriction_holder_from,
+            check.holder_group_from,
+        ),
+        (
+            true,
+            check.destination_account,
+            check.destination_balance,
+            check.security_associated_account_to,
+            check.transfer_restriction_group_to,
+            check.transfer_restriction_holder_to,
+            check.holder_group_to,
+        ),
+    ] {
+        verify_pda(
+            security_associated_account_info.key,
+            &[
+                SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
+                &token_account.to_bytes(),
+            ],
+            &crate::ID,
+        )?;
+        if security_associated_account_info.data_is_empty() {
+            continue;
+        }
+        let recorded = record_wallet_balance(
+            accounts_data,
+            check.transfer_restriction_data,
+            group_info,
+            holder_info,
+            holder_group_info,
+            security_associated_account_info,
+            wallet_balance,
+        )?;
+        if is_destination {
+            recorded_to = recorded;
+        }
+    }
+    Ok(recorded_to)
+}
+
+/// Returns the transfer rule applied to the transfer between the groups, resolved in the order:
+/// exact group pair rule, wildcard rule from the sender group to any group,
+/// wildcard rule from any group to the recipient group and the default transfer rule of the token.
+/// The first initialized rule is applied, so a more specific rule overrides less specific ones.
+/// `transfer_rules` are the exact, sender group wildcard and recipient group wildcard rule PDAs.
+pub fn resolve_transfer_rule(
+    transfer_restriction_data_key: &Pubkey,
+    transfer_restriction_data: &TransferRestrictionData,
+    transfer_rules: [&AccountInfo; 3],
+    group_from: u64,
+    group_to: u64,
+) -> Result<TransferRule> {
+    let rule_groups = [
+        (group_from, group_to),
+        (group_from, ANY_TRANSFER_GROUP_ID),
+        (ANY_TRANSFER_GROUP_ID, group_to),
+    ];
+    for (transfer_rule_info, (rule_group_from, rule_group_to)) in
+        transfer_rules.into_iter().zip(rule_groups)
+    {
+        verify_pda(
+            transfer_rule_info.key,
+            &[
+                TRANSFER_RULE_PREFIX.as_bytes(),
+                &transfer_restriction_data_key.to_bytes(),
+                &rule_group_from.to_le_bytes(),
+                &rule_group_to.to_le_bytes(),
+            ],
+            &crate::ID,
+        )?;
+        if !transfer_rule_info.data_is_empty() {
+            return TransferRule::try_from_account_data(&transfer_rule_info.data.borrow());
+        }
+    }
+    transfer_restriction_data
+        .default_transfer_rule
+        .clone()
+        .ok_or(TransferRestrictionsError::TransferRuleAccountDataIsEmtpy.into())
+}
+
+/// Stores the wallet balance in its security associated account and applies
+/// the difference to the aggregated balances of the wallet holder and holder group.
+/// Returns the updated holder and whether it became a holder of record globally and inside the group,
+/// or `None` for wallets without a holder (e.g. lockup escrow).
+fn record_wallet_balance(
+    accounts_data: &mut AccountsData,
+    transfer_restriction_data_info: &AccountInfo,
+    group_info: &AccountInfo,
+    holder_info: &AccountInfo,
+    holder_group_info: &AccountInfo,
+    security_associated_account_info: &AccountInfo,
+    wallet_balance: u64,
+) -> Result<Option<(TransferRestrictionHolder, bool, bool)>> {
+    let mut security_associated_account: SecurityAssociatedAccount =
+        accounts_data.load(security_associated_account_info)?;
+    let previous_balance = security_associated_account.balance;
+    security_associated_account.balance = wallet_balance;
+    accounts_data.store(
+        security_associated_account_info,
+        &security_associated_account,
+    )?;
+
+    let Some(holder_key) = security_associated_account.holder else {
+        return Ok(None);
+    };
+    if *holder_info.key != holder_key {
+        return Err(TransferRestrictionsError::InvalidPDA.into());
+    }
+    verify_pda(
+        group_info.key,
+        &[
+            TRANSFER_RESTRICTION_GROUP_PREFIX.as_bytes(),
+            &transfer_restriction_data_info.key().to_bytes(),
+            &security_associated_account.group.to_le_bytes(),
+        ],
+        &crate::ID,
+    )?;
+    verify_pda(
+        holder_group_info.key,
+        &[
+            TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX.as_bytes(),
+            &holder_key.to_bytes(),
+            &security_associated_account.group.to_le_bytes(),
+        ],
+        &crate::ID,
+    )?;
+
+    let mut transfer_restriction_data: TransferRestrictionData =
+        accounts_data.load(transfer_restriction_data_info)?;
+    let mut group: TransferRestrictionGroup = accounts_data.load(group_info)?;
+    let mut holder: TransferRestrictionHolder = accounts_data.load(holder_info)?;
+    let mut holder_group: HolderGroup = accounts_data.load(holder_group_info)?;
+    let (joined_holders, joined_group_holders) = record_holder_wallet_balance(
+        &mut transfer_restriction_data,
+        &mut group,
+        &mut holder,
+        &mut holder_group,
+        previous_balance,
+        wallet_balance,
+    )?;
+    accounts_data.store(transfer_restriction_data_info, &transfer_restriction_data)?;
+    accounts_data.store(group_info, &group)?;
+    accounts_data.store(holder_info, &holder)?;
+    accounts_data.store(holder_group_info, &holder_group)?;
+
+    Ok(Some((holder, joined_holders, joined_group_holders)))
+}
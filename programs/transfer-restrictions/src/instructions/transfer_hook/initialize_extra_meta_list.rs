@@ -1,6 +1,6 @@
 use crate::{
     contexts::InitializeExtraAccountMetaList, errors::TransferRestrictionsError,
-    get_extra_account_metas,
+    events::ExtraAccountMetaListInitializedEvent, get_extra_account_metas,
 };
 use access_control::Roles;
 use anchor_lang::prelude::*;
@@ -23,5 +23,9 @@ pub fn initialize_extra_account_meta_list(
     let mut data = extra_metas_account.try_borrow_mut_data()?;
     ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &metas)?;
 
+    emit!(ExtraAccountMetaListInitializedEvent {
+        mint: ctx.accounts.security_mint.key(),
+        authority: ctx.accounts.payer.key(),
+    });
     Ok(())
 }
@@ -3,3 +3,6 @@ pub use execute::*;
 
 pub mod initialize_extra_meta_list;
 pub use initialize_extra_meta_list::*;
+
+pub mod update_extra_meta_list;
+pub use update_extra_meta_list::*;
@@ -0,0 +1,40 @@
+use crate::{
+    contexts::UpdateExtraAccountMetaList, errors::TransferRestrictionsError,
+    events::ExtraAccountMetaListUpdatedEvent, get_extra_account_metas, get_meta_list_size,
+    update_account_lamports_to_minimum_balance,
+};
+use access_control::Roles;
+use anchor_lang::prelude::*;
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+pub fn update_extra_account_meta_list(ctx: Context<UpdateExtraAccountMetaList>) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::ContractAdmin)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+
+    let extra_metas_account = &ctx.accounts.extra_metas_account;
+    let size_before = extra_metas_account.data_len() as u64;
+    extra_metas_account.realloc(get_meta_list_size()?, false)?;
+    update_account_lamports_to_minimum_balance(
+        extra_metas_account.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let metas = get_extra_account_metas()?;
+    let mut data = extra_metas_account.try_borrow_mut_data()?;
+    ExtraAccountMetaList::update::<ExecuteInstruction>(&mut data, &metas)?;
+
+    emit!(ExtraAccountMetaListUpdatedEvent {
+        mint: ctx.accounts.security_mint.key(),
+        size_before,
+        size: data.len() as u64,
+        authority: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
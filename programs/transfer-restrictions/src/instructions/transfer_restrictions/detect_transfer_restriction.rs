@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TransferRestrictionsError,
+    instructions::transfer_hook::{check_transfer, AccountsData, TransferCheck},
+    DetectTransferRestriction, TransferRestrictionReason,
+};
+
+pub const TRANSFER_RESTRICTION_SUCCESS_MESSAGE: &str = "SUCCESS";
+
+/// Runs the transfer hook checks against the current state without changing it
+/// and returns the reason the transfer would be rejected (code 0 if it would succeed).
+/// A source balance below `amount` is reported before the transfer hook checks, as the token
+/// program rejects such a transfer before invoking the hook.
+pub fn detect_transfer_restriction(
+    ctx: Context<DetectTransferRestriction>,
+    amount: u64,
+) -> Result<TransferRestrictionReason> {
+    let source_account = &ctx.accounts.source_account;
+    let destination_account = &ctx.accounts.destination_account;
+    // the transfer hook sees post-transfer balances
+    let (source_balance, destination_balance) = if source_account.key() == destination_account.key()
+    {
+        (source_account.amount, destination_account.amount)
+    } else {
+        (
+            source_account.amount.saturating_sub(amount),
+            destination_account.amount.saturating_add(amount),
+        )
+    };
+
+    let result = if source_account.amount < amount {
+        Err(TransferRestrictionsError::InsufficientSourceBalance.into())
+    } else {
+        check_transfer(
+            &TransferCheck {
+                mint: ctx.accounts.mint.key(),
+                source_account: source_account.key(),
+                source_balance,
+                destination_account: destination_account.key(),
+                destination_balance,
+                amount,
+                transfer_restriction_data: &ctx.accounts.transfer_restriction_data,
+                security_associated_account_from: &ctx.accounts.security_associated_account_from,
+                security_associated_account_to: &ctx.accounts.security_associated_account_to,
+                transfer_rule: &ctx.accounts.transfer_rule,
+                transfer_restriction_holder_from: &ctx.accounts.transfer_restriction_holder_from,
+                transfer_restriction_holder_to: &ctx.accounts.transfer_restriction_holder_to,
+                transfer_restriction_group_from: &ctx.accounts.transfer_restriction_group_from,
+                transfer_restriction_group_to: &ctx.accounts.transfer_restriction_group_to,
+                holder_group_from: &ctx.accounts.holder_group_from,
+                holder_group_to: &ctx.accounts.holder_group_to,
+                transfer_rule_from_group_to_any: &ctx.accounts.transfer_rule_from_group_to_any,
+                transfer_rule_from_any_to_group: &ctx.accounts.transfer_rule_from_any_to_group,
+            },
+            &mut AccountsData::Simulated(Vec::new()),
+        )
+    };
+
+    match result {
+        Ok(()) => Ok(TransferRestrictionReason {
+            code: 0,
+            message: TRANSFER_RESTRICTION_SUCCESS_MESSAGE.to_string(),
+        }),
+        Err(Error::AnchorError(error)) => Ok(TransferRestrictionReason {
+            code: error.error_code_number,
+            message: error.error_msg,
+        }),
+        Err(error) => Err(error),
+    }
+}
@@ -1,21 +1,95 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    errors::TransferRestrictionsError, EnforceTransferRestrictions,
+    errors::TransferRestrictionsError, instructions::transfer_hook::resolve_transfer_rule,
+    EnforceTransferRestrictions,
 };
 
-pub fn enforce_transfer_restrictions(ctx: Context<EnforceTransferRestrictions>) -> Result<()> {
+pub fn enforce_transfer_restrictions(
+    ctx: Context<EnforceTransferRestrictions>,
+    amount: u64,
+) -> Result<()> {
     let transfer_restriction_data = &ctx.accounts.transfer_restriction_data;
-    if transfer_restriction_data.paused {
+    let now = Clock::get()?.unix_timestamp as u64;
+    if transfer_restriction_data.is_paused(now) {
         return Err(TransferRestrictionsError::AllTransfersPaused.into());
     }
+    if ctx.accounts.transfer_restriction_group_from.paused
+        || ctx.accounts.transfer_restriction_group_to.paused
+    {
+        return Err(TransferRestrictionsError::GroupTransfersPaused.into());
+    }
+    ctx.accounts
+        .transfer_restriction_group_from
+        .enforce_outbound_lockup(now)?;
+    let security_associated_account_from = &ctx.accounts.security_associated_account_from;
+    let security_associated_account_to = &ctx.accounts.security_associated_account_to;
+    if security_associated_account_from.send_blocked {
+        return Err(TransferRestrictionsError::WalletSendBlocked.into());
+    }
+    if security_associated_account_to.receive_blocked {
+        return Err(TransferRestrictionsError::WalletReceiveBlocked.into());
+    }
+    if !ctx.accounts.transfer_restriction_holder_from.active
+        || !ctx.accounts.transfer_restriction_holder_to.active
+    {
+        return Err(TransferRestrictionsError::HolderInactive.into());
+    }
+    if security_associated_account_from.is_expired(now)
+        || security_associated_account_to.is_expired(now)
+    {
+        return Err(TransferRestrictionsError::WalletValidityExpired.into());
+    }
+
+    let transfer_rule = resolve_transfer_rule(
+        &transfer_restriction_data.key(),
+        transfer_restriction_data,
+        [
+            &ctx.accounts.transfer_rule,
+            &ctx.accounts.transfer_rule_from_group_to_any,
+            &ctx.accounts.transfer_rule_from_any_to_group,
+        ],
+        security_associated_account_from.group,
+        security_associated_account_to.group,
+    )?;
+    transfer_rule.enforce_time_window(now)?;
+    transfer_rule.enforce_amount_limits(amount)?;
 
-    let transfer_rule = &ctx.accounts.transfer_rule;
-    if transfer_rule.locked_until == 0 {
-        return Err(TransferRestrictionsError::TransferGroupNotApproved.into());
+    // the transfer hook only checks the holders of record caps of tokenlock releases, so the
+    // recipient holder balance is checked here against the balance it will have after the release
+    if security_associated_account_from.holder != security_associated_account_to.holder {
+        let holder_to = &ctx.accounts.transfer_restriction_holder_to;
+        let group_to = &ctx.accounts.transfer_restriction_group_to;
+        let holder_to_balance = holder_to
+            .current_balance
+            .checked_add(amount)
+            .ok_or(TransferRestrictionsError::HolderMaxBalanceExceeded)?;
+        if holder_to.max_balance != 0 && holder_to_balance > holder_to.max_balance {
+            return Err(TransferRestrictionsError::HolderMaxBalanceExceeded.into());
+        }
+        if group_to.max_balance != 0 && holder_to_balance > group_to.max_balance {
+            return Err(TransferRestrictionsError::GroupMaxBalanceExceeded.into());
+        }
     }
-    if transfer_rule.locked_until > Clock::get()?.unix_timestamp as u64 {
-        return Err(TransferRestrictionsError::TransferRuleNotAllowedUntilLater.into());
+
+    let group_from = &ctx.accounts.transfer_restriction_group_from;
+    if group_from.velocity_limit != 0 {
+        let mut security_associated_account_from =
+            (**ctx.accounts.security_associated_account_from).clone();
+        if !security_associated_account_from.record_sent_amount(
+            amount,
+            group_from.velocity_limit,
+            group_from.velocity_period,
+            now,
+        ) {
+            return Err(TransferRestrictionsError::VelocityLimitExceeded.into());
+        }
+        // Only the source account owner may use up its velocity allowance
+        if ctx.accounts.authority.is_some() {
+            ctx.accounts
+                .security_associated_account_from
+                .set_inner(security_associated_account_from);
+        }
     }
 
     Ok(())
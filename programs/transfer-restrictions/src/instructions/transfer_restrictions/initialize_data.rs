@@ -1,7 +1,10 @@
 use access_control::Roles;
 use anchor_lang::prelude::*;
 
-use crate::{errors::TransferRestrictionsError, InitializeTransferRestrictionData};
+use crate::{
+    errors::TransferRestrictionsError, events::TransferRestrictionDataInitializedEvent,
+    InitializeTransferRestrictionData,
+};
 
 pub fn initialize_data(
     ctx: Context<InitializeTransferRestrictionData>,
@@ -20,12 +23,33 @@ pub fn initialize_data(
     transfer_restriction_data.max_holders = max_holders;
     transfer_restriction_data.paused = false;
     transfer_restriction_data.lockup_escrow_account = None;
+    transfer_restriction_data.current_holders_of_record_count = 0;
+    transfer_restriction_data.holders_of_record_enforced = false;
+    transfer_restriction_data.default_transfer_rule = None;
+    transfer_restriction_data.paused_from = 0;
+    transfer_restriction_data.paused_until = 0;
 
     let zero_transfer_restriction_group = &mut ctx.accounts.zero_transfer_restriction_group;
     zero_transfer_restriction_group.id = 0;
     zero_transfer_restriction_group.current_holders_count = 0;
     zero_transfer_restriction_group.max_holders = 0;
+    zero_transfer_restriction_group.max_balance = 0;
+    zero_transfer_restriction_group.velocity_limit = 0;
+    zero_transfer_restriction_group.velocity_period = 0;
+    zero_transfer_restriction_group.current_holders_of_record_count = 0;
+    zero_transfer_restriction_group.name = String::new();
+    zero_transfer_restriction_group.jurisdiction = String::new();
+    zero_transfer_restriction_group.flags = 0;
+    zero_transfer_restriction_group.locked_until = 0;
+    zero_transfer_restriction_group.frozen = false;
+    zero_transfer_restriction_group.paused = false;
     zero_transfer_restriction_group.transfer_restriction_data = transfer_restriction_data.key();
 
+    emit!(TransferRestrictionDataInitializedEvent {
+        transfer_restriction_data: transfer_restriction_data.key(),
+        mint: ctx.accounts.mint.key(),
+        max_holders,
+        authority: ctx.accounts.payer.key(),
+    });
     Ok(())
 }
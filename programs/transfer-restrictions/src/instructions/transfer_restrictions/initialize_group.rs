@@ -1,7 +1,10 @@
 use access_control::Roles;
 use anchor_lang::prelude::*;
 
-use crate::{errors::TransferRestrictionsError, InitializeTransferRestrictionGroup};
+use crate::{
+    errors::TransferRestrictionsError, events::GroupInitializedEvent,
+    InitializeTransferRestrictionGroup, ANY_TRANSFER_GROUP_ID,
+};
 
 pub fn initialize_group(ctx: Context<InitializeTransferRestrictionGroup>, id: u64) -> Result<()> {
     if !ctx
@@ -11,6 +14,10 @@ pub fn initialize_group(ctx: Context<InitializeTransferRestrictionGroup>, id: u6
     {
         return Err(TransferRestrictionsError::Unauthorized.into());
     }
+    require!(
+        id != ANY_TRANSFER_GROUP_ID,
+        TransferRestrictionsError::ReservedTransferGroupId
+    );
 
     let transfer_restriction_group = &mut ctx.accounts.transfer_restriction_group;
     let transfer_restriction_data = &mut ctx.accounts.transfer_restriction_data;
@@ -19,6 +26,21 @@ pub fn initialize_group(ctx: Context<InitializeTransferRestrictionGroup>, id: u6
     transfer_restriction_group.id = id;
     transfer_restriction_group.current_holders_count = 0;
     transfer_restriction_group.max_holders = 0;
+    transfer_restriction_group.max_balance = 0;
+    transfer_restriction_group.velocity_limit = 0;
+    transfer_restriction_group.velocity_period = 0;
+    transfer_restriction_group.current_holders_of_record_count = 0;
+    transfer_restriction_group.name = String::new();
+    transfer_restriction_group.jurisdiction = String::new();
+    transfer_restriction_group.flags = 0;
+    transfer_restriction_group.locked_until = 0;
+    transfer_restriction_group.frozen = false;
+    transfer_restriction_group.paused = false;
 
+    emit!(GroupInitializedEvent {
+        group: transfer_restriction_group.key(),
+        group_id: id,
+        authority: ctx.accounts.payer.key(),
+    });
     Ok(())
 }
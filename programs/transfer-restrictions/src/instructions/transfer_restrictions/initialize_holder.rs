@@ -2,7 +2,11 @@ use access_control::Roles;
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
-use crate::InitializeTransferRestrictionHolder;
+use crate::{
+    events::HolderInitializedEvent, InitializeTransferRestrictionHolder,
+    InitializeTransferRestrictionHolderFromReference, TransferRestrictionData,
+    TransferRestrictionHolder,
+};
 
 pub fn initialize_holder(ctx: Context<InitializeTransferRestrictionHolder>, id: u64) -> Result<()> {
     if !ctx
@@ -13,28 +17,103 @@ pub fn initialize_holder(ctx: Context<InitializeTransferRestrictionHolder>, id:
         return Err(TransferRestrictionsError::Unauthorized.into());
     }
 
-    let transfer_restriction_data = &mut ctx.accounts.transfer_restriction_data;
-    if transfer_restriction_data.current_holders_count >= transfer_restriction_data.max_holders {
-        return Err(TransferRestrictionsError::MaxHoldersReached.into());
+    let transfer_restriction_data_key = ctx.accounts.transfer_restriction_data.key();
+    init_holder(
+        &mut ctx.accounts.transfer_restriction_data,
+        transfer_restriction_data_key,
+        &mut ctx.accounts.transfer_restriction_holder,
+        id,
+    )?;
+
+    emit!(HolderInitializedEvent {
+        holder: ctx.accounts.transfer_restriction_holder.key(),
+        holder_id: id,
+        authority: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
+
+/// Initializes the holder with the id derived from the holder reference, so that admins
+/// onboarding in parallel don't compete for the next sequential holder id
+pub fn initialize_holder_from_reference(
+    ctx: Context<InitializeTransferRestrictionHolderFromReference>,
+    reference: [u8; 32],
+) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_any_role(Roles::TransferAdmin as u8 | Roles::WalletsAdmin as u8)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
     }
+
+    let transfer_restriction_data_key = ctx.accounts.transfer_restriction_data.key();
+    let id = TransferRestrictionHolder::id_from_reference(&reference);
+    init_holder_account(
+        &mut ctx.accounts.transfer_restriction_data,
+        transfer_restriction_data_key,
+        &mut ctx.accounts.transfer_restriction_holder,
+        id,
+    )?;
+
+    emit!(HolderInitializedEvent {
+        holder: ctx.accounts.transfer_restriction_holder.key(),
+        holder_id: id,
+        authority: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
+
+/// Initializes the new holder with the next or previously revoked sequential id
+pub fn init_holder(
+    transfer_restriction_data: &mut TransferRestrictionData,
+    transfer_restriction_data_key: Pubkey,
+    transfer_restriction_holder: &mut TransferRestrictionHolder,
+    id: u64,
+) -> Result<()> {
     if transfer_restriction_data.holder_ids < id {
         return Err(TransferRestrictionsError::InvalidHolderIndex.into());
     }
 
-    let transfer_restriction_holder = &mut ctx.accounts.transfer_restriction_holder;
-    transfer_restriction_holder.transfer_restriction_data = transfer_restriction_data.key();
+    init_holder_account(
+        transfer_restriction_data,
+        transfer_restriction_data_key,
+        transfer_restriction_holder,
+        id,
+    )?;
+    if transfer_restriction_data.holder_ids == id {
+        transfer_restriction_data.holder_ids =
+            transfer_restriction_data.holder_ids.checked_add(1).unwrap();
+    }
+
+    Ok(())
+}
+
+/// Initializes the new holder and accounts it in the transfer restriction data
+fn init_holder_account(
+    transfer_restriction_data: &mut TransferRestrictionData,
+    transfer_restriction_data_key: Pubkey,
+    transfer_restriction_holder: &mut TransferRestrictionHolder,
+    id: u64,
+) -> Result<()> {
+    // holders of record are capped at transfer time instead when enforced
+    if !transfer_restriction_data.holders_of_record_enforced
+        && transfer_restriction_data.current_holders_count >= transfer_restriction_data.max_holders
+    {
+        return Err(TransferRestrictionsError::MaxHoldersReached.into());
+    }
+
+    transfer_restriction_holder.transfer_restriction_data = transfer_restriction_data_key;
     transfer_restriction_holder.id = id;
     transfer_restriction_holder.current_wallets_count = 0;
     transfer_restriction_holder.current_holder_group_count = 0;
     transfer_restriction_holder.active = true;
+    transfer_restriction_holder.max_balance = 0;
+    transfer_restriction_holder.current_balance = 0;
     transfer_restriction_data.current_holders_count = transfer_restriction_data
         .current_holders_count
         .checked_add(1)
         .unwrap();
-    if transfer_restriction_data.holder_ids == id {
-        transfer_restriction_data.holder_ids =
-            transfer_restriction_data.holder_ids.checked_add(1).unwrap();
-    }
 
     Ok(())
 }
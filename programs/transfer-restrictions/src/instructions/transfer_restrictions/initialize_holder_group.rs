@@ -1,7 +1,10 @@
 use access_control::Roles;
 use anchor_lang::prelude::*;
 
-use crate::{errors::TransferRestrictionsError, InitializeHolderGroup};
+use crate::{
+    errors::TransferRestrictionsError, events::HolderGroupInitializedEvent, HolderGroup,
+    InitializeHolderGroup, TransferRestrictionHolder,
+};
 
 pub fn initialize_holder_group(ctx: Context<InitializeHolderGroup>) -> Result<()> {
     if !ctx
@@ -12,14 +15,34 @@ pub fn initialize_holder_group(ctx: Context<InitializeHolderGroup>) -> Result<()
         return Err(TransferRestrictionsError::Unauthorized.into());
     }
 
-    let holder_group = &mut ctx.accounts.holder_group;
+    let holder_key = ctx.accounts.holder.key();
+    init_holder_group(
+        &mut ctx.accounts.holder_group,
+        ctx.accounts.group.id,
+        &mut ctx.accounts.holder,
+        holder_key,
+    );
 
-    holder_group.group = ctx.accounts.group.id;
-    holder_group.holder = ctx.accounts.holder.key();
+    emit!(HolderGroupInitializedEvent {
+        holder_group: ctx.accounts.holder_group.key(),
+        holder: holder_key,
+        group_id: ctx.accounts.group.id,
+        authority: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
+
+/// Initializes the new holder group and accounts it in the holder
+pub fn init_holder_group(
+    holder_group: &mut HolderGroup,
+    group_id: u64,
+    holder: &mut TransferRestrictionHolder,
+    holder_key: Pubkey,
+) {
+    holder_group.group = group_id;
+    holder_group.holder = holder_key;
     holder_group.current_wallets_count = 0;
+    holder_group.current_balance = 0;
 
-    let holder = &mut ctx.accounts.holder;
     holder.current_holder_group_count = holder.current_holder_group_count.checked_add(1).unwrap();
-
-    Ok(())
 }
@@ -1,7 +1,12 @@
 use access_control::Roles;
 use anchor_lang::prelude::*;
 
-use crate::{errors::TransferRestrictionsError, InitializeSecurityAssociatedAccount};
+use crate::{
+    errors::TransferRestrictionsError, events::SecurityAssociatedAccountInitializedEvent,
+    record_holder_wallet_balance, HolderGroup, InitializeSecurityAssociatedAccount,
+    SecurityAssociatedAccount, TransferRestrictionData, TransferRestrictionGroup,
+    TransferRestrictionHolder, VELOCITY_BUCKETS_COUNT,
+};
 
 pub fn initialize_security_associated_account(
     ctx: Context<InitializeSecurityAssociatedAccount>,
@@ -11,23 +16,84 @@ pub fn initialize_security_associated_account(
         return Err(TransferRestrictionsError::Unauthorized.into());
     }
 
-    let security_associated_account = &mut ctx.accounts.security_associated_account;
+    let holder_key = ctx.accounts.holder.key();
+    init_security_associated_account(
+        &mut ctx.accounts.transfer_restriction_data,
+        &mut ctx.accounts.group,
+        &mut ctx.accounts.holder,
+        holder_key,
+        &mut ctx.accounts.holder_group,
+        &mut ctx.accounts.security_associated_account,
+        ctx.accounts.associated_token_account.amount,
+    )?;
 
-    security_associated_account.group = ctx.accounts.group.id;
-    security_associated_account.holder = Some(ctx.accounts.holder.key());
+    emit!(SecurityAssociatedAccountInitializedEvent {
+        security_associated_account: ctx.accounts.security_associated_account.key(),
+        token_account: ctx.accounts.associated_token_account.key(),
+        holder_id: ctx.accounts.holder.id,
+        group_id: ctx.accounts.group.id,
+        authority: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
+
+/// Initializes the new security associated account of the holder wallet in the group
+/// and accounts the wallet and its balance in the holder, holder group and group
+pub fn init_security_associated_account(
+    transfer_restriction_data: &mut TransferRestrictionData,
+    group: &mut TransferRestrictionGroup,
+    holder: &mut TransferRestrictionHolder,
+    holder_key: Pubkey,
+    holder_group: &mut HolderGroup,
+    security_associated_account: &mut SecurityAssociatedAccount,
+    balance: u64,
+) -> Result<()> {
+    security_associated_account.group = group.id;
+    security_associated_account.holder = Some(holder_key);
+    security_associated_account.holder_id = holder.id;
+    security_associated_account.balance = balance;
+    security_associated_account.velocity_period = 0;
+    security_associated_account.velocity_bucket = 0;
+    security_associated_account.velocity_sent = [0; VELOCITY_BUCKETS_COUNT];
+    security_associated_account.send_blocked = false;
+    security_associated_account.receive_blocked = false;
+    security_associated_account.valid_until = 0;
 
-    let group = &mut ctx.accounts.group;
-    let holder_group = &mut ctx.accounts.holder_group;
     holder_group.current_wallets_count = holder_group.current_wallets_count.checked_add(1).unwrap();
     if holder_group.current_wallets_count == 1 {
         group.current_holders_count = group.current_holders_count.checked_add(1).unwrap();
     }
-    if group.current_holders_count > group.max_holders && group.max_holders != 0 {
+    let holders_of_record_enforced = transfer_restriction_data.holders_of_record_enforced;
+    if !holders_of_record_enforced
+        && group.current_holders_count > group.max_holders
+        && group.max_holders != 0
+    {
         return Err(TransferRestrictionsError::MaxHoldersReachedInsideTheGroup.into());
     }
 
-    let holder = &mut ctx.accounts.holder;
     holder.current_wallets_count = holder.current_wallets_count.checked_add(1).unwrap();
-    
+    let (joined_holders, joined_group_holders) = record_holder_wallet_balance(
+        transfer_restriction_data,
+        group,
+        holder,
+        holder_group,
+        0,
+        balance,
+    )?;
+    if holders_of_record_enforced {
+        if joined_holders
+            && transfer_restriction_data.current_holders_of_record_count
+                > transfer_restriction_data.max_holders
+        {
+            return Err(TransferRestrictionsError::MaxHoldersReached.into());
+        }
+        if joined_group_holders
+            && group.max_holders != 0
+            && group.current_holders_of_record_count > group.max_holders
+        {
+            return Err(TransferRestrictionsError::MaxHoldersReachedInsideTheGroup.into());
+        }
+    }
+
     Ok(())
 }
@@ -1,7 +1,10 @@
 use access_control::Roles;
 use anchor_lang::prelude::*;
 
-use crate::{errors::TransferRestrictionsError, InitializeTransferRule};
+use crate::{
+    errors::TransferRestrictionsError, events::TransferRuleInitializedEvent,
+    transfer_rule_group_id, InitializeTransferRule,
+};
 
 pub fn initialize_transfer_rule(
     ctx: Context<InitializeTransferRule>,
@@ -14,13 +17,31 @@ pub fn initialize_transfer_rule(
     {
         return Err(TransferRestrictionsError::Unauthorized.into());
     }
+    require!(
+        ctx.accounts.transfer_restriction_group_from.is_some()
+            || ctx.accounts.transfer_restriction_group_to.is_some(),
+        TransferRestrictionsError::WildcardTransferRuleWithoutGroup
+    );
     let transfer_rule = &mut ctx.accounts.transfer_rule;
     let transfer_restriction_data = &mut ctx.accounts.transfer_restriction_data;
 
     transfer_rule.transfer_restriction_data = transfer_restriction_data.key();
-    transfer_rule.transfer_group_id_from = ctx.accounts.transfer_restriction_group_from.id;
-    transfer_rule.transfer_group_id_to = ctx.accounts.transfer_restriction_group_to.id;
+    transfer_rule.transfer_group_id_from =
+        transfer_rule_group_id(&ctx.accounts.transfer_restriction_group_from);
+    transfer_rule.transfer_group_id_to =
+        transfer_rule_group_id(&ctx.accounts.transfer_restriction_group_to);
     transfer_rule.locked_until = locked_until;
+    transfer_rule.min_amount = 0;
+    transfer_rule.max_amount = 0;
+    transfer_rule.lot_size = 0;
+    transfer_rule.locked_after = 0;
 
+    emit!(TransferRuleInitializedEvent {
+        transfer_rule: transfer_rule.key(),
+        group_id_from: transfer_rule.transfer_group_id_from,
+        group_id_to: transfer_rule.transfer_group_id_to,
+        locked_until,
+        authority: ctx.accounts.payer.key(),
+    });
     Ok(())
 }
@@ -0,0 +1,43 @@
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{
+    contexts::common::DISCRIMINATOR_LEN, errors::TransferRestrictionsError,
+    update_account_lamports_to_minimum_balance, HolderGroup, MigrateAccount,
+    TransferRestrictionData, TransferRestrictionGroup, TransferRestrictionHolder,
+};
+
+/// Reallocates a transfer restriction data, group, holder or holder group account created with an
+/// older layout. New fields are zero-filled, so tracked balances and holders of record counts start
+/// at zero and are recorded when the security associated accounts are migrated.
+pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+    let account_info = ctx.accounts.account.to_account_info();
+    let space = DISCRIMINATOR_LEN
+        + match account_info.try_borrow_data()?.get(..DISCRIMINATOR_LEN) {
+            Some(discriminator) if discriminator == TransferRestrictionData::DISCRIMINATOR => {
+                TransferRestrictionData::INIT_SPACE
+            }
+            Some(discriminator) if discriminator == TransferRestrictionGroup::DISCRIMINATOR => {
+                TransferRestrictionGroup::INIT_SPACE
+            }
+            Some(discriminator) if discriminator == TransferRestrictionHolder::DISCRIMINATOR => {
+                TransferRestrictionHolder::INIT_SPACE
+            }
+            Some(discriminator) if discriminator == HolderGroup::DISCRIMINATOR => {
+                HolderGroup::INIT_SPACE
+            }
+            _ => return Err(TransferRestrictionsError::InvalidAccountToMigrate.into()),
+        };
+    require!(
+        account_info.data_len() < space,
+        TransferRestrictionsError::AccountAlreadyMigrated
+    );
+
+    account_info.realloc(space, true)?;
+    update_account_lamports_to_minimum_balance(
+        account_info,
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    contexts::common::DISCRIMINATOR_LEN, errors::TransferRestrictionsError,
+    record_holder_wallet_balance, update_account_lamports_to_minimum_balance,
+    MigrateSecurityAssociatedAccount, SecurityAssociatedAccount,
+};
+
+/// Reallocates a security associated account created before the holder balances were tracked.
+/// Stores the holder id read by the transfer hook and records the wallet balance in the holder,
+/// holder group, group and transfer restriction data accounts, which have to be migrated first.
+pub fn migrate_security_associated_account(
+    ctx: Context<MigrateSecurityAssociatedAccount>,
+) -> Result<()> {
+    let account_info = ctx.accounts.security_associated_account.to_account_info();
+    let space = DISCRIMINATOR_LEN + SecurityAssociatedAccount::INIT_SPACE;
+    require!(
+        account_info.data_len() < space,
+        TransferRestrictionsError::AccountAlreadyMigrated
+    );
+
+    account_info.realloc(space, true)?;
+    update_account_lamports_to_minimum_balance(
+        account_info.clone(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let mut security_associated_account =
+        SecurityAssociatedAccount::try_deserialize(&mut &account_info.try_borrow_data()?[..])?;
+    // lockup escrow accounts are not assigned to a holder
+    let Some(holder_key) = security_associated_account.holder else {
+        return Ok(());
+    };
+    let (Some(holder), Some(holder_group), Some(group)) = (
+        ctx.accounts.holder.as_mut(),
+        ctx.accounts.holder_group.as_mut(),
+        ctx.accounts.group.as_mut(),
+    ) else {
+        return Err(TransferRestrictionsError::InvalidHolderAccounts.into());
+    };
+    if holder.key() != holder_key
+        || holder_group.holder != holder_key
+        || holder_group.group != security_associated_account.group
+        || group.id != security_associated_account.group
+    {
+        return Err(TransferRestrictionsError::InvalidHolderAccounts.into());
+    }
+
+    let wallet_balance = ctx.accounts.token_account.amount;
+    record_holder_wallet_balance(
+        &mut ctx.accounts.transfer_restriction_data,
+        group,
+        holder,
+        holder_group,
+        security_associated_account.balance,
+        wallet_balance,
+    )?;
+    security_associated_account.holder_id = holder.id;
+    security_associated_account.balance = wallet_balance;
+    security_associated_account.try_serialize(&mut &mut account_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
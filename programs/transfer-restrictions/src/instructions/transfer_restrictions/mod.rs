@@ -45,3 +45,63 @@ pub use set_lockup_escrow_account::*;
 
 pub mod enforce_transfer_restrictions;
 pub use enforce_transfer_restrictions::*;
+
+pub mod set_group_max_balance;
+pub use set_group_max_balance::*;
+
+pub mod set_holder_max_balance;
+pub use set_holder_max_balance::*;
+
+pub mod sync_holder_balance;
+pub use sync_holder_balance::*;
+
+pub mod set_transfer_rule_amount_limits;
+pub use set_transfer_rule_amount_limits::*;
+
+pub mod set_group_velocity_limit;
+pub use set_group_velocity_limit::*;
+
+pub mod update_wallet_transfer_blocks;
+pub use update_wallet_transfer_blocks::*;
+
+pub mod renew_wallets_validity;
+pub use renew_wallets_validity::*;
+
+pub mod set_holders_of_record_enforcement;
+pub use set_holders_of_record_enforcement::*;
+
+pub mod detect_transfer_restriction;
+pub use detect_transfer_restriction::*;
+
+pub mod update_wallet_holder;
+pub use update_wallet_holder::*;
+
+pub mod provision_investor;
+pub use provision_investor::*;
+
+pub mod provision_investors;
+pub use provision_investors::*;
+
+pub mod set_holder_active;
+pub use set_holder_active::*;
+
+pub mod set_default_transfer_rule;
+pub use set_default_transfer_rule::*;
+
+pub mod set_group_metadata;
+pub use set_group_metadata::*;
+
+pub mod set_group_lockup;
+pub use set_group_lockup::*;
+
+pub mod pause_group;
+pub use pause_group::*;
+
+pub mod schedule_pause;
+pub use schedule_pause::*;
+
+pub mod migrate_account;
+pub use migrate_account::*;
+
+pub mod migrate_security_associated_account;
+pub use migrate_security_associated_account::*;
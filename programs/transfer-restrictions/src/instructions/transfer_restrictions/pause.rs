@@ -1,4 +1,4 @@
-use crate::{contexts::Pause, errors::TransferRestrictionsError};
+use crate::{contexts::Pause, errors::TransferRestrictionsError, events::PausedEvent};
 use access_control::Roles;
 use anchor_lang::prelude::*;
 
@@ -17,5 +17,10 @@ pub fn pause(ctx: Context<Pause>, paused: bool) -> Result<()> {
     let transfer_restriction_data = &mut ctx.accounts.transfer_restriction_data;
     transfer_restriction_data.paused = paused;
 
+    emit!(PausedEvent {
+        paused_before: !paused,
+        paused,
+        authority: ctx.accounts.payer.key(),
+    });
     Ok(())
 }
@@ -0,0 +1,31 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{errors::TransferRestrictionsError, events::GroupPausedEvent, PauseGroup};
+
+/// Pauses or unpauses transfers from and to the wallets of a single group
+pub fn pause_group(ctx: Context<PauseGroup>, paused: bool) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_any_role(Roles::ContractAdmin as u8 | Roles::TransferAdmin as u8)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+
+    let group = &mut ctx.accounts.group;
+    require!(
+        group.paused != paused,
+        TransferRestrictionsError::ValueUnchanged
+    );
+    group.paused = paused;
+
+    emit!(GroupPausedEvent {
+        group: group.key(),
+        group_id: group.id,
+        paused_before: !paused,
+        paused,
+        authority: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
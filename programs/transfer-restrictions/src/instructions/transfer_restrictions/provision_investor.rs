@@ -0,0 +1,55 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use super::{init_holder, init_holder_group, init_security_associated_account};
+use crate::{
+    errors::TransferRestrictionsError, events::InvestorProvisionedEvent, ProvisionInvestor,
+};
+
+/// Onboards the investor at once: initializes the holder, its holder group
+/// and the security associated account of the investor wallet in the group.
+pub fn provision_investor(ctx: Context<ProvisionInvestor>, holder_id: u64) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_any_role(Roles::TransferAdmin as u8 | Roles::WalletsAdmin as u8)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+
+    let transfer_restriction_data_key = ctx.accounts.transfer_restriction_data.key();
+    let holder_key = ctx.accounts.holder.key();
+    let accounts = ctx.accounts;
+    init_holder(
+        &mut accounts.transfer_restriction_data,
+        transfer_restriction_data_key,
+        &mut accounts.holder,
+        holder_id,
+    )?;
+    init_holder_group(
+        &mut accounts.holder_group,
+        accounts.group.id,
+        &mut accounts.holder,
+        holder_key,
+    );
+    init_security_associated_account(
+        &mut accounts.transfer_restriction_data,
+        &mut accounts.group,
+        &mut accounts.holder,
+        holder_key,
+        &mut accounts.holder_group,
+        &mut accounts.security_associated_account,
+        accounts.associated_token_account.amount,
+    )?;
+
+    emit!(InvestorProvisionedEvent {
+        holder: holder_key,
+        holder_id,
+        holder_group: accounts.holder_group.key(),
+        security_associated_account: accounts.security_associated_account.key(),
+        token_account: accounts.associated_token_account.key(),
+        group_id: accounts.group.id,
+        authority: accounts.payer.key(),
+    });
+    Ok(())
+}
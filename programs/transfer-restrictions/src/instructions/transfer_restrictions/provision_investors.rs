@@ -0,0 +1,134 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{spl_token_2022, TokenAccount},
+};
+
+use super::{init_holder, init_holder_group, init_security_associated_account};
+use crate::{
+    contexts::common::DISCRIMINATOR_LEN, create_pda_account, errors::TransferRestrictionsError,
+    events::InvestorProvisionedEvent, HolderGroup, ProvisionInvestors, SecurityAssociatedAccount,
+    TransferRestrictionHolder, SECURITY_ASSOCIATED_ACCOUNT_PREFIX,
+    TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX, TRANSFER_RESTRICTION_HOLDER_PREFIX,
+};
+
+/// Batched `provision_investor` onboarding investors into the same group.
+/// Remaining accounts are `[holder, holder_group, associated_token_account, security_associated_account]`
+/// quadruples in the `holder_ids` order, holder, holder group and security associated accounts
+/// must be writable.
+pub fn provision_investors<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ProvisionInvestors<'info>>,
+    holder_ids: Vec<u64>,
+) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_any_role(Roles::TransferAdmin as u8 | Roles::WalletsAdmin as u8)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+
+    let investors = ctx.remaining_accounts.chunks_exact(4);
+    require!(
+        !holder_ids.is_empty()
+            && investors.remainder().is_empty()
+            && investors.len() == holder_ids.len(),
+        TransferRestrictionsError::InvalidRemainingAccounts
+    );
+
+    let transfer_restriction_data_key = ctx.accounts.transfer_restriction_data.key();
+    let security_token_key = ctx.accounts.security_token.key();
+    let group_id = ctx.accounts.group.id;
+    let payer = ctx.accounts.payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    for (investor, holder_id) in investors.zip(holder_ids) {
+        let (holder_info, holder_group_info, token_account_info, security_associated_account_info) =
+            (&investor[0], &investor[1], &investor[2], &investor[3]);
+
+        let token_account = InterfaceAccount::<TokenAccount>::try_from(token_account_info)?;
+        require!(
+            token_account.mint == security_token_key
+                && token_account_info.key()
+                    == get_associated_token_address_with_program_id(
+                        &token_account.owner,
+                        &security_token_key,
+                        &spl_token_2022::id(),
+                    ),
+            TransferRestrictionsError::InvalidRemainingAccounts
+        );
+
+        create_pda_account(
+            holder_info,
+            &payer,
+            &system_program,
+            DISCRIMINATOR_LEN + TransferRestrictionHolder::INIT_SPACE,
+            &[
+                TRANSFER_RESTRICTION_HOLDER_PREFIX.as_bytes(),
+                &transfer_restriction_data_key.to_bytes(),
+                &holder_id.to_le_bytes(),
+            ],
+            ctx.program_id,
+        )?;
+        create_pda_account(
+            holder_group_info,
+            &payer,
+            &system_program,
+            DISCRIMINATOR_LEN + HolderGroup::INIT_SPACE,
+            &[
+                TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX.as_bytes(),
+                &holder_info.key().to_bytes(),
+                &group_id.to_le_bytes(),
+            ],
+            ctx.program_id,
+        )?;
+        create_pda_account(
+            security_associated_account_info,
+            &payer,
+            &system_program,
+            DISCRIMINATOR_LEN + SecurityAssociatedAccount::INIT_SPACE,
+            &[
+                SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
+                &token_account_info.key().to_bytes(),
+            ],
+            ctx.program_id,
+        )?;
+
+        let mut holder = TransferRestrictionHolder::default();
+        let mut holder_group = HolderGroup::default();
+        let mut security_associated_account = SecurityAssociatedAccount::default();
+        init_holder(
+            &mut ctx.accounts.transfer_restriction_data,
+            transfer_restriction_data_key,
+            &mut holder,
+            holder_id,
+        )?;
+        init_holder_group(&mut holder_group, group_id, &mut holder, holder_info.key());
+        init_security_associated_account(
+            &mut ctx.accounts.transfer_restriction_data,
+            &mut ctx.accounts.group,
+            &mut holder,
+            holder_info.key(),
+            &mut holder_group,
+            &mut security_associated_account,
+            token_account.amount,
+        )?;
+
+        holder.try_serialize(&mut &mut holder_info.try_borrow_mut_data()?[..])?;
+        holder_group.try_serialize(&mut &mut holder_group_info.try_borrow_mut_data()?[..])?;
+        security_associated_account
+            .try_serialize(&mut &mut security_associated_account_info.try_borrow_mut_data()?[..])?;
+
+        emit!(InvestorProvisionedEvent {
+            holder: holder_info.key(),
+            holder_id,
+            holder_group: holder_group_info.key(),
+            security_associated_account: security_associated_account_info.key(),
+            token_account: token_account_info.key(),
+            group_id,
+            authority: payer.key(),
+        });
+    }
+
+    Ok(())
+}
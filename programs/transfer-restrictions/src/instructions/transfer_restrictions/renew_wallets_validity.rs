@@ -0,0 +1,59 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    errors::TransferRestrictionsError, events::WalletValidityRenewedEvent, verify_pda,
+    RenewWalletsValidity, SecurityAssociatedAccount, SECURITY_ASSOCIATED_ACCOUNT_PREFIX,
+};
+
+/// Sets KYC/accreditation validity for multiple wallets at once.
+/// Remaining accounts are `[token_account, security_associated_account]` pairs,
+/// security associated accounts must be writable.
+pub fn renew_wallets_validity<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RenewWalletsValidity<'info>>,
+    valid_until: u64,
+) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::WalletsAdmin)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+
+    let wallets = ctx.remaining_accounts.chunks_exact(2);
+    require!(
+        !ctx.remaining_accounts.is_empty() && wallets.remainder().is_empty(),
+        TransferRestrictionsError::InvalidRemainingAccounts
+    );
+    for wallet in wallets {
+        let token_account = InterfaceAccount::<TokenAccount>::try_from(&wallet[0])?;
+        require!(
+            token_account.mint == ctx.accounts.security_token.key(),
+            TransferRestrictionsError::InvalidRemainingAccounts
+        );
+        verify_pda(
+            wallet[1].key,
+            &[
+                SECURITY_ASSOCIATED_ACCOUNT_PREFIX.as_bytes(),
+                &wallet[0].key().to_bytes(),
+            ],
+            ctx.program_id,
+        )?;
+        let mut security_associated_account =
+            Account::<SecurityAssociatedAccount>::try_from(&wallet[1])?;
+        let valid_until_before = security_associated_account.valid_until;
+        security_associated_account.valid_until = valid_until;
+        security_associated_account.exit(ctx.program_id)?;
+
+        emit!(WalletValidityRenewedEvent {
+            security_associated_account: security_associated_account.key(),
+            valid_until_before,
+            valid_until,
+            authority: ctx.accounts.payer.key(),
+        });
+    }
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use access_control::Roles;
 use anchor_lang::prelude::*;
 
-use crate::{errors::TransferRestrictionsError, RevokeHolder};
+use crate::{errors::TransferRestrictionsError, events::HolderRevokedEvent, RevokeHolder};
 
 pub fn revoke_holder(ctx: Context<RevokeHolder>) -> Result<()> {
     if !ctx
@@ -25,5 +25,10 @@ pub fn revoke_holder(ctx: Context<RevokeHolder>) -> Result<()> {
     let transfer_restriction_data = &mut ctx.accounts.transfer_restriction_data;
     transfer_restriction_data.current_holders_count = transfer_restriction_data.current_holders_count.checked_sub(1).unwrap();
 
+    emit!(HolderRevokedEvent {
+        holder: holder.key(),
+        holder_id: holder.id,
+        authority: ctx.accounts.payer.key(),
+    });
     Ok(())
 }
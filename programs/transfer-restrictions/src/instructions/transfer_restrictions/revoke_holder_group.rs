@@ -1,7 +1,9 @@
 use access_control::Roles;
 use anchor_lang::prelude::*;
 
-use crate::{errors::TransferRestrictionsError, RevokeHolderGroup};
+use crate::{
+    errors::TransferRestrictionsError, events::HolderGroupRevokedEvent, RevokeHolderGroup,
+};
 
 pub fn revoke_holder_group(ctx: Context<RevokeHolderGroup>) -> Result<()> {
     if !ctx
@@ -23,5 +25,11 @@ pub fn revoke_holder_group(ctx: Context<RevokeHolderGroup>) -> Result<()> {
         .checked_sub(1)
         .ok_or(TransferRestrictionsError::NonPositiveHolderGroupCount)?;
 
+    emit!(HolderGroupRevokedEvent {
+        holder_group: ctx.accounts.holder_group.key(),
+        holder: holder.key(),
+        group_id: ctx.accounts.holder_group.group,
+        authority: ctx.accounts.payer.key(),
+    });
     Ok(())
 }
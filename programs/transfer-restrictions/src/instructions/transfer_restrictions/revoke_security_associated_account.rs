@@ -1,7 +1,10 @@
 use access_control::Roles;
 use anchor_lang::prelude::*;
 
-use crate::{errors::TransferRestrictionsError, RevokeSecurityAssociatedAccount};
+use crate::{
+    errors::TransferRestrictionsError, events::SecurityAssociatedAccountRevokedEvent,
+    record_holder_wallet_balance, RevokeSecurityAssociatedAccount,
+};
 
 pub fn revoke_security_associated_account(
     ctx: Context<RevokeSecurityAssociatedAccount>,
@@ -19,12 +22,28 @@ pub fn revoke_security_associated_account(
     }
     holder_group.current_wallets_count = holder_group.current_wallets_count.checked_sub(1).unwrap();
     holder.current_wallets_count = holder.current_wallets_count.checked_sub(1).unwrap();
+    let group = &mut ctx.accounts.group;
+    record_holder_wallet_balance(
+        &mut ctx.accounts.transfer_restriction_data,
+        group,
+        holder,
+        holder_group,
+        ctx.accounts.security_associated_account.balance,
+        0,
+    )?;
 
     if holder_group.current_wallets_count == 0 {
         // Remove holder from group
-        let group = &mut ctx.accounts.group;
         group.current_holders_count = group.current_holders_count.checked_sub(1).unwrap();
     }
 
+    let security_associated_account = &ctx.accounts.security_associated_account;
+    emit!(SecurityAssociatedAccountRevokedEvent {
+        security_associated_account: security_associated_account.key(),
+        token_account: ctx.accounts.associated_token_account.key(),
+        holder_id: security_associated_account.holder_id,
+        group_id: security_associated_account.group,
+        authority: ctx.accounts.payer.key(),
+    });
     Ok(())
 }
@@ -0,0 +1,53 @@
+use crate::{
+    contexts::SchedulePause, errors::TransferRestrictionsError, events::PauseScheduledEvent,
+};
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+/// Schedules a pause of all transfers within `[paused_from, paused_until)`.
+/// `paused_until` of 0 cancels the scheduled pause.
+pub fn schedule_pause(
+    ctx: Context<SchedulePause>,
+    paused_from: u64,
+    paused_until: u64,
+) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_any_role(Roles::ContractAdmin as u8 | Roles::TransferAdmin as u8)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+    if paused_until != 0 {
+        let now = Clock::get()?.unix_timestamp as u64;
+        require!(
+            paused_from < paused_until && now < paused_until,
+            TransferRestrictionsError::InvalidPauseSchedule
+        );
+    } else {
+        require!(
+            paused_from == 0,
+            TransferRestrictionsError::InvalidPauseSchedule
+        );
+    }
+
+    let transfer_restriction_data = &mut ctx.accounts.transfer_restriction_data;
+    require!(
+        transfer_restriction_data.paused_from != paused_from
+            || transfer_restriction_data.paused_until != paused_until,
+        TransferRestrictionsError::ValueUnchanged
+    );
+    let paused_from_before = transfer_restriction_data.paused_from;
+    let paused_until_before = transfer_restriction_data.paused_until;
+    transfer_restriction_data.paused_from = paused_from;
+    transfer_restriction_data.paused_until = paused_until;
+
+    emit!(PauseScheduledEvent {
+        paused_from_before,
+        paused_until_before,
+        paused_from,
+        paused_until,
+        authority: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
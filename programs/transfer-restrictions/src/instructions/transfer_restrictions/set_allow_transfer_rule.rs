@@ -1,21 +1,52 @@
 use access_control::Roles;
 use anchor_lang::prelude::*;
 
-use crate::{errors::TransferRestrictionsError, SetAllowTransferRule};
+use crate::{
+    errors::TransferRestrictionsError, events::AllowTransferRuleSetEvent, SetAllowTransferRule,
+    TransferRule,
+};
 
 pub fn set_allow_transfer_rule(
     ctx: Context<SetAllowTransferRule>,
     locked_until: u64,
+    locked_after: u64,
 ) -> Result<()> {
     if !ctx.accounts.authority_wallet_role.has_role(Roles::TransferAdmin) {
         return Err(TransferRestrictionsError::Unauthorized.into());
     }
-    if ctx.accounts.transfer_rule.locked_until == locked_until {
+    require!(
+        locked_after == 0 || locked_after > locked_until,
+        TransferRestrictionsError::InvalidTransferRuleTimeWindow
+    );
+
+    let transfer_rule_info = ctx.accounts.transfer_rule.to_account_info();
+    let mut transfer_rule = TransferRule::try_from_account_data(&transfer_rule_info.data.borrow())?;
+    if !TransferRule::needs_realloc(&transfer_rule_info)
+        && transfer_rule.locked_until == locked_until
+        && transfer_rule.locked_after == locked_after
+    {
         return Err(TransferRestrictionsError::ValueUnchanged.into());
     }
 
-    let transfer_rule = &mut ctx.accounts.transfer_rule;
+    let locked_until_before = transfer_rule.locked_until;
+    let locked_after_before = transfer_rule.locked_after;
     transfer_rule.locked_until = locked_until;
+    transfer_rule.locked_after = locked_after;
+    transfer_rule.save(
+        &transfer_rule_info,
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
 
+    emit!(AllowTransferRuleSetEvent {
+        transfer_rule: transfer_rule_info.key(),
+        group_id_from: transfer_rule.transfer_group_id_from,
+        group_id_to: transfer_rule.transfer_group_id_to,
+        locked_until_before,
+        locked_after_before,
+        locked_until,
+        locked_after,
+        authority: ctx.accounts.payer.key(),
+    });
     Ok(())
 }
@@ -0,0 +1,61 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TransferRestrictionsError, events::DefaultTransferRuleSetEvent, SetDefaultTransferRule,
+    TransferRule, ANY_TRANSFER_GROUP_ID,
+};
+
+/// Sets the default transfer rule applied to transfers between groups without an exact or wildcard rule.
+/// Zero `locked_until` removes the default rule, so such transfers are rejected.
+pub fn set_default_transfer_rule(
+    ctx: Context<SetDefaultTransferRule>,
+    locked_until: u64,
+    locked_after: u64,
+    min_amount: u64,
+    max_amount: u64,
+    lot_size: u64,
+) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::TransferAdmin)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+    require!(
+        locked_after == 0 || locked_after > locked_until,
+        TransferRestrictionsError::InvalidTransferRuleTimeWindow
+    );
+    require!(
+        max_amount == 0 || min_amount <= max_amount,
+        TransferRestrictionsError::InvalidTransferRuleAmountLimits
+    );
+
+    let transfer_restriction_data = &mut ctx.accounts.transfer_restriction_data;
+    let default_transfer_rule = (locked_until != 0).then(|| TransferRule {
+        transfer_restriction_data: transfer_restriction_data.key(),
+        transfer_group_id_from: ANY_TRANSFER_GROUP_ID,
+        transfer_group_id_to: ANY_TRANSFER_GROUP_ID,
+        locked_until,
+        min_amount,
+        max_amount,
+        lot_size,
+        locked_after,
+    });
+    require!(
+        transfer_restriction_data.default_transfer_rule != default_transfer_rule,
+        TransferRestrictionsError::ValueUnchanged
+    );
+    let default_transfer_rule_before = std::mem::replace(
+        &mut transfer_restriction_data.default_transfer_rule,
+        default_transfer_rule.clone(),
+    );
+
+    emit!(DefaultTransferRuleSetEvent {
+        default_transfer_rule_before,
+        default_transfer_rule,
+        authority: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{errors::TransferRestrictionsError, events::GroupLockupSetEvent, SetGroupLockup};
+
+/// Locks outbound transfers from all group wallets until `locked_until` (0 means no lockup)
+/// or freezes them until the group is unfrozen.
+pub fn set_group_lockup(
+    ctx: Context<SetGroupLockup>,
+    locked_until: u64,
+    frozen: bool,
+) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::TransferAdmin)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+
+    let group = &mut ctx.accounts.group;
+    require!(
+        group.locked_until != locked_until || group.frozen != frozen,
+        TransferRestrictionsError::ValueUnchanged
+    );
+    let locked_until_before = group.locked_until;
+    let frozen_before = group.frozen;
+    group.locked_until = locked_until;
+    group.frozen = frozen;
+
+    emit!(GroupLockupSetEvent {
+        group: group.key(),
+        group_id: group.id,
+        locked_until_before,
+        frozen_before,
+        locked_until,
+        frozen,
+        authority: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TransferRestrictionsError, events::GroupMaxBalanceSetEvent, SetGroupMaxBalance,
+};
+
+pub fn set_group_max_balance(ctx: Context<SetGroupMaxBalance>, max_balance: u64) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::TransferAdmin)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+
+    let group = &mut ctx.accounts.group;
+    require!(
+        group.max_balance != max_balance,
+        TransferRestrictionsError::ValueUnchanged
+    );
+    let max_balance_before = group.max_balance;
+    group.max_balance = max_balance;
+
+    emit!(GroupMaxBalanceSetEvent {
+        group: group.key(),
+        group_id: group.id,
+        max_balance_before,
+        max_balance,
+        authority: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TransferRestrictionsError, events::GroupMetadataSetEvent, GroupFlags, SetGroupMetadata,
+    GROUP_JURISDICTION_MAX_LEN, GROUP_NAME_MAX_LEN,
+};
+
+/// Sets the group name, jurisdiction code and flags describing the group for off-chain tooling.
+/// The metadata is informational and is not enforced on transfers.
+pub fn set_group_metadata(
+    ctx: Context<SetGroupMetadata>,
+    name: String,
+    jurisdiction: String,
+    flags: u8,
+) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::TransferAdmin)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+    require!(
+        name.len() <= GROUP_NAME_MAX_LEN,
+        TransferRestrictionsError::GroupNameTooLong
+    );
+    require!(
+        jurisdiction.len() <= GROUP_JURISDICTION_MAX_LEN,
+        TransferRestrictionsError::GroupJurisdictionTooLong
+    );
+    require!(
+        flags & !(GroupFlags::All as u8) == 0,
+        TransferRestrictionsError::InvalidGroupFlags
+    );
+
+    let group = &mut ctx.accounts.group;
+    require!(
+        group.name != name || group.jurisdiction != jurisdiction || group.flags != flags,
+        TransferRestrictionsError::ValueUnchanged
+    );
+    let name_before = std::mem::replace(&mut group.name, name.clone());
+    let jurisdiction_before = std::mem::replace(&mut group.jurisdiction, jurisdiction.clone());
+    let flags_before = group.flags;
+    group.flags = flags;
+
+    emit!(GroupMetadataSetEvent {
+        group: group.key(),
+        group_id: group.id,
+        name_before,
+        jurisdiction_before,
+        flags_before,
+        name,
+        jurisdiction,
+        flags,
+        authority: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TransferRestrictionsError, events::GroupVelocityLimitSetEvent, SetGroupVelocityLimit,
+};
+
+pub fn set_group_velocity_limit(
+    ctx: Context<SetGroupVelocityLimit>,
+    velocity_limit: u64,
+    velocity_period: u64,
+) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::TransferAdmin)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+    require!(
+        velocity_limit == 0 || velocity_period != 0,
+        TransferRestrictionsError::InvalidVelocityPeriod
+    );
+
+    let group = &mut ctx.accounts.group;
+    require!(
+        group.velocity_limit != velocity_limit || group.velocity_period != velocity_period,
+        TransferRestrictionsError::ValueUnchanged
+    );
+    let velocity_limit_before = group.velocity_limit;
+    let velocity_period_before = group.velocity_period;
+    group.velocity_limit = velocity_limit;
+    group.velocity_period = velocity_period;
+
+    emit!(GroupVelocityLimitSetEvent {
+        group: group.key(),
+        group_id: group.id,
+        velocity_limit_before,
+        velocity_period_before,
+        velocity_limit,
+        velocity_period,
+        authority: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
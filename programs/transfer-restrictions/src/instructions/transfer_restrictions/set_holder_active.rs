@@ -0,0 +1,32 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{errors::TransferRestrictionsError, events::HolderActiveSetEvent, SetHolderActive};
+
+/// Activates or deactivates the holder, transfers from and to wallets of an inactive holder are blocked
+pub fn set_holder_active(ctx: Context<SetHolderActive>, active: bool) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::WalletsAdmin)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+
+    let holder = &mut ctx.accounts.holder;
+    require!(
+        holder.active != active,
+        TransferRestrictionsError::ValueUnchanged
+    );
+    let active_before = holder.active;
+    holder.active = active;
+
+    emit!(HolderActiveSetEvent {
+        holder: holder.key(),
+        holder_id: holder.id,
+        active_before,
+        active,
+        authority: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use access_control::Roles;
 use anchor_lang::prelude::*;
 
-use crate::{errors::TransferRestrictionsError, SetHolderGroupMax};
+use crate::{errors::TransferRestrictionsError, events::HolderGroupMaxSetEvent, SetHolderGroupMax};
 
 pub fn set_holder_group_max(
     ctx: Context<SetHolderGroupMax>,
@@ -20,11 +20,28 @@ pub fn set_holder_group_max(
         group.id != 0,
         TransferRestrictionsError::ZeroGroupHolderGroupMaxCannotBeNonZero
     );
+    let current_holders_count = if ctx
+        .accounts
+        .transfer_restriction_data
+        .holders_of_record_enforced
+    {
+        group.current_holders_of_record_count
+    } else {
+        group.current_holders_count
+    };
     require!(
-        holder_max >= group.current_holders_count,
+        holder_max >= current_holders_count,
         TransferRestrictionsError::NewHolderGroupMaxMustExceedCurrentHolderGroupCount
     );
+    let holder_max_before = group.max_holders;
     group.max_holders = holder_max;
 
+    emit!(HolderGroupMaxSetEvent {
+        group: group.key(),
+        group_id: group.id,
+        holder_max_before,
+        holder_max,
+        authority: ctx.accounts.payer.key(),
+    });
     Ok(())
 }
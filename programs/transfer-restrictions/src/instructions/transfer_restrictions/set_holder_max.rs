@@ -1,7 +1,7 @@
 use access_control::Roles;
 use anchor_lang::prelude::*;
 
-use crate::{errors::TransferRestrictionsError, SetHolderMax};
+use crate::{errors::TransferRestrictionsError, events::HolderMaxSetEvent, SetHolderMax};
 
 pub fn set_holder_max(
     ctx: Context<SetHolderMax>,
@@ -15,11 +15,22 @@ pub fn set_holder_max(
         transfer_restriction_data.max_holders != holder_max,
         TransferRestrictionsError::ValueUnchanged
     );
+    let current_holders_count = if transfer_restriction_data.holders_of_record_enforced {
+        transfer_restriction_data.current_holders_of_record_count
+    } else {
+        transfer_restriction_data.current_holders_count
+    };
     require!(
-        holder_max >= transfer_restriction_data.current_holders_count,
+        holder_max >= current_holders_count,
         TransferRestrictionsError::NewHolderMaxMustExceedCurrentHolderCount
     );
+    let holder_max_before = transfer_restriction_data.max_holders;
     transfer_restriction_data.max_holders = holder_max;
 
+    emit!(HolderMaxSetEvent {
+        holder_max_before,
+        holder_max,
+        authority: ctx.accounts.payer.key(),
+    });
     Ok(())
 }
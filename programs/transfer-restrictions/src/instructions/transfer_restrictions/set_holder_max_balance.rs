@@ -0,0 +1,33 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TransferRestrictionsError, events::HolderMaxBalanceSetEvent, SetHolderMaxBalance,
+};
+
+pub fn set_holder_max_balance(ctx: Context<SetHolderMaxBalance>, max_balance: u64) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::TransferAdmin)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+
+    let holder = &mut ctx.accounts.holder;
+    require!(
+        holder.max_balance != max_balance,
+        TransferRestrictionsError::ValueUnchanged
+    );
+    let max_balance_before = holder.max_balance;
+    holder.max_balance = max_balance;
+
+    emit!(HolderMaxBalanceSetEvent {
+        holder: holder.key(),
+        holder_id: holder.id,
+        max_balance_before,
+        max_balance,
+        authority: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
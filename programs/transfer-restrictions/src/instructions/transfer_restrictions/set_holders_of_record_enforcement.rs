@@ -0,0 +1,37 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TransferRestrictionsError, events::HoldersOfRecordEnforcementSetEvent,
+    SetHoldersOfRecordEnforcement,
+};
+
+/// Enables or disables holder caps enforcement at transfer time.
+/// When enabled, transfers which make the recipient a new holder of record (holder with a non-zero balance)
+/// fail once the global or the recipient group holders of record count exceeds the corresponding max holders.
+pub fn set_holders_of_record_enforcement(
+    ctx: Context<SetHoldersOfRecordEnforcement>,
+    enforced: bool,
+) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::TransferAdmin)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+    let transfer_restriction_data = &mut ctx.accounts.transfer_restriction_data;
+    require!(
+        transfer_restriction_data.holders_of_record_enforced != enforced,
+        TransferRestrictionsError::ValueUnchanged
+    );
+    let enforced_before = transfer_restriction_data.holders_of_record_enforced;
+    transfer_restriction_data.holders_of_record_enforced = enforced;
+
+    emit!(HoldersOfRecordEnforcementSetEvent {
+        enforced_before,
+        enforced,
+        authority: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
@@ -2,7 +2,10 @@ use access_control::Roles;
 use anchor_lang::{prelude::*, solana_program::program_memory::sol_memcmp, Discriminator};
 use tokenlock_accounts::{states::TokenLockData, wrappers::TokenLockDataWrapper};
 
-use crate::{errors::TransferRestrictionsError, SetLockupEscrowAccount};
+use crate::{
+    errors::TransferRestrictionsError, events::LockupEscrowAccountSetEvent, SetLockupEscrowAccount,
+    VELOCITY_BUCKETS_COUNT,
+};
 
 pub fn set_lockup_escrow_account(ctx: Context<SetLockupEscrowAccount>) -> Result<()> {
     if !ctx
@@ -25,12 +28,28 @@ pub fn set_lockup_escrow_account(ctx: Context<SetLockupEscrowAccount>) -> Result
     }
 
     let transfer_restriction_data = &mut ctx.accounts.transfer_restriction_data;
+    let lockup_escrow_account_before = transfer_restriction_data.lockup_escrow_account;
     transfer_restriction_data.lockup_escrow_account = Some(escrow_account);
 
     let escrow_security_associated_token_account =
         &mut ctx.accounts.escrow_security_associated_account;
     escrow_security_associated_token_account.group = 0;
     escrow_security_associated_token_account.holder = None;
+    escrow_security_associated_token_account.holder_id = 0;
+    escrow_security_associated_token_account.balance = 0;
+    escrow_security_associated_token_account.velocity_period = 0;
+    escrow_security_associated_token_account.velocity_bucket = 0;
+    escrow_security_associated_token_account.velocity_sent = [0; VELOCITY_BUCKETS_COUNT];
+    escrow_security_associated_token_account.send_blocked = false;
+    escrow_security_associated_token_account.receive_blocked = false;
+    escrow_security_associated_token_account.valid_until = 0;
 
+    emit!(LockupEscrowAccountSetEvent {
+        mint: ctx.accounts.mint.key(),
+        lockup_escrow_account_before,
+        lockup_escrow_account: escrow_account,
+        tokenlock_account: tokenlock_account.key(),
+        authority: ctx.accounts.payer.key(),
+    });
     Ok(())
 }
@@ -0,0 +1,63 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::TransferRestrictionsError, events::TransferRuleAmountLimitsSetEvent,
+    SetTransferRuleAmountLimits, TransferRule,
+};
+
+/// Sets transfer rule amount limits, reallocating rule accounts created before the limits were introduced
+pub fn set_transfer_rule_amount_limits(
+    ctx: Context<SetTransferRuleAmountLimits>,
+    min_amount: u64,
+    max_amount: u64,
+    lot_size: u64,
+) -> Result<()> {
+    if !ctx
+        .accounts
+        .authority_wallet_role
+        .has_role(Roles::TransferAdmin)
+    {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+    require!(
+        max_amount == 0 || min_amount <= max_amount,
+        TransferRestrictionsError::InvalidTransferRuleAmountLimits
+    );
+
+    let transfer_rule_info = ctx.accounts.transfer_rule.to_account_info();
+    let mut transfer_rule = TransferRule::try_from_account_data(&transfer_rule_info.data.borrow())?;
+    require!(
+        TransferRule::needs_realloc(&transfer_rule_info)
+            || transfer_rule.min_amount != min_amount
+            || transfer_rule.max_amount != max_amount
+            || transfer_rule.lot_size != lot_size,
+        TransferRestrictionsError::ValueUnchanged
+    );
+
+    let min_amount_before = transfer_rule.min_amount;
+    let max_amount_before = transfer_rule.max_amount;
+    let lot_size_before = transfer_rule.lot_size;
+    transfer_rule.min_amount = min_amount;
+    transfer_rule.max_amount = max_amount;
+    transfer_rule.lot_size = lot_size;
+    transfer_rule.save(
+        &transfer_rule_info,
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(TransferRuleAmountLimitsSetEvent {
+        transfer_rule: transfer_rule_info.key(),
+        group_id_from: transfer_rule.transfer_group_id_from,
+        group_id_to: transfer_rule.transfer_group_id_to,
+        min_amount_before,
+        max_amount_before,
+        lot_size_before,
+        min_amount,
+        max_amount,
+        lot_size,
+        authority: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
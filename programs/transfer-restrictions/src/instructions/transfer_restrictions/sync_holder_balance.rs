@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{events::HolderBalanceSyncedEvent, record_holder_wallet_balance, SyncHolderBalance};
+
+/// Syncs the holder balance with the wallet balance changed without a transfer.
+/// Called by the access control program after minting or burning securities, and open to anyone
+/// since the wallet balance is read from the token account.
+pub fn sync_holder_balance(ctx: Context<SyncHolderBalance>) -> Result<()> {
+    let security_associated_account = &mut ctx.accounts.security_associated_account;
+    let wallet_balance = ctx.accounts.token_account.amount;
+    let balance_before = security_associated_account.balance;
+    let holder_balance_before = ctx.accounts.holder.current_balance;
+
+    record_holder_wallet_balance(
+        &mut ctx.accounts.transfer_restriction_data,
+        &mut ctx.accounts.group,
+        &mut ctx.accounts.holder,
+        &mut ctx.accounts.holder_group,
+        security_associated_account.balance,
+        wallet_balance,
+    )?;
+    security_associated_account.balance = wallet_balance;
+
+    emit!(HolderBalanceSyncedEvent {
+        security_associated_account: security_associated_account.key(),
+        holder: ctx.accounts.holder.key(),
+        balance_before,
+        balance: wallet_balance,
+        holder_balance_before,
+        holder_balance: ctx.accounts.holder.current_balance,
+    });
+    Ok(())
+}
@@ -1,4 +1,6 @@
-use crate::{contexts::UpdateWalletGroup, errors::TransferRestrictionsError};
+use crate::{
+    contexts::UpdateWalletGroup, errors::TransferRestrictionsError, events::WalletGroupUpdatedEvent,
+};
 use access_control::Roles;
 use anchor_lang::prelude::*;
 
@@ -21,8 +23,15 @@ pub fn update_wallet_group(ctx: Context<UpdateWalletGroup>) -> Result<()> {
     if holder_group_new.current_wallets_count == 0 {
         group_new.current_holders_count = group_new.current_holders_count.checked_add(1).unwrap();
     }
-    // check group max count
-    if group_new.current_holders_count > group_new.max_holders && group_new.max_holders != 0 {
+    // check group max count, holders of record are capped below instead when enforced
+    let holders_of_record_enforced = ctx
+        .accounts
+        .transfer_restriction_data
+        .holders_of_record_enforced;
+    if !holders_of_record_enforced
+        && group_new.current_holders_count > group_new.max_holders
+        && group_new.max_holders != 0
+    {
         return Err(TransferRestrictionsError::MaxHoldersReached.into());
     }
 
@@ -43,8 +52,44 @@ pub fn update_wallet_group(ctx: Context<UpdateWalletGroup>) -> Result<()> {
             group_current.current_holders_count.checked_sub(1).unwrap();
     }
 
+    // move the wallet balance to the new group, keeping holders of record counts
     let security_associated_account = &mut ctx.accounts.security_associated_account;
+    let wallet_balance = security_associated_account.balance;
+    let holder_group_current_balance = holder_group_current.current_balance;
+    holder_group_current.update_wallet_balance(wallet_balance, 0)?;
+    ctx.accounts
+        .transfer_restriction_group_current
+        .update_holders_of_record_count(
+            holder_group_current_balance,
+            holder_group_current.current_balance,
+        )?;
+    let holder_group_new_balance = holder_group_new.current_balance;
+    holder_group_new.update_wallet_balance(0, wallet_balance)?;
+    group_new.update_holders_of_record_count(
+        holder_group_new_balance,
+        holder_group_new.current_balance,
+    )?;
+    // the holder balance now counts towards the new group, check its max balance as transfers do
+    if group_new.max_balance != 0 && ctx.accounts.holder.current_balance > group_new.max_balance {
+        return Err(TransferRestrictionsError::GroupMaxBalanceExceeded.into());
+    }
+    if holders_of_record_enforced
+        && holder_group_new_balance == 0
+        && holder_group_new.current_balance != 0
+        && group_new.max_holders != 0
+        && group_new.current_holders_of_record_count > group_new.max_holders
+    {
+        return Err(TransferRestrictionsError::MaxHoldersReached.into());
+    }
+
+    let group_id_before = security_associated_account.group;
     security_associated_account.group = group_new.id;
 
+    emit!(WalletGroupUpdatedEvent {
+        security_associated_account: security_associated_account.key(),
+        group_id_before,
+        group_id: group_new.id,
+        authority: ctx.accounts.payer.key(),
+    });
     Ok(())
 }
@@ -0,0 +1,113 @@
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+use crate::{
+    contexts::UpdateWalletHolder, errors::TransferRestrictionsError,
+    events::WalletHolderUpdatedEvent, record_holder_wallet_balance,
+};
+
+/// Moves the wallet (security associated account) to another holder within the same group,
+/// e.g. to merge holders of an investor onboarded twice. Once all wallets are moved,
+/// the empty holder can be retired with `revoke_holder_group` and `revoke_holder`.
+pub fn update_wallet_holder(ctx: Context<UpdateWalletHolder>) -> Result<()> {
+    let wallet_role = &ctx.accounts.authority_wallet_role;
+    if !wallet_role.has_any_role(Roles::WalletsAdmin as u8 | Roles::TransferAdmin as u8) {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+
+    let holder_current = &mut ctx.accounts.holder_current;
+    let holder_new = &mut ctx.accounts.holder_new;
+    require!(
+        holder_new.key() != holder_current.key(),
+        TransferRestrictionsError::NewHolderIsTheSameAsTheCurrentHolder
+    );
+
+    let group = &mut ctx.accounts.group;
+    let holder_group_current = &mut ctx.accounts.holder_group_current;
+    let holder_group_new = &mut ctx.accounts.holder_group_new;
+    let holders_of_record_enforced = ctx
+        .accounts
+        .transfer_restriction_data
+        .holders_of_record_enforced;
+    // new holder joins the group if it is its first wallet
+    if holder_group_new.current_wallets_count == 0 {
+        group.current_holders_count = group.current_holders_count.checked_add(1).unwrap();
+    }
+    holder_group_new.current_wallets_count = holder_group_new
+        .current_wallets_count
+        .checked_add(1)
+        .unwrap();
+    holder_group_current.current_wallets_count = holder_group_current
+        .current_wallets_count
+        .checked_sub(1)
+        .unwrap();
+    // current holder leaves the group if it was its last wallet
+    if holder_group_current.current_wallets_count == 0 {
+        group.current_holders_count = group.current_holders_count.checked_sub(1).unwrap();
+    }
+    if !holders_of_record_enforced
+        && group.current_holders_count > group.max_holders
+        && group.max_holders != 0
+    {
+        return Err(TransferRestrictionsError::MaxHoldersReachedInsideTheGroup.into());
+    }
+
+    holder_new.current_wallets_count = holder_new.current_wallets_count.checked_add(1).unwrap();
+    holder_current.current_wallets_count =
+        holder_current.current_wallets_count.checked_sub(1).unwrap();
+
+    // move the wallet balance to the new holder, keeping holders of record counts
+    let security_associated_account = &mut ctx.accounts.security_associated_account;
+    let wallet_balance = security_associated_account.balance;
+    let transfer_restriction_data = &mut ctx.accounts.transfer_restriction_data;
+    record_holder_wallet_balance(
+        transfer_restriction_data,
+        group,
+        holder_current,
+        holder_group_current,
+        wallet_balance,
+        0,
+    )?;
+    let (joined_holders, joined_group_holders) = record_holder_wallet_balance(
+        transfer_restriction_data,
+        group,
+        holder_new,
+        holder_group_new,
+        0,
+        wallet_balance,
+    )?;
+    if holder_new.max_balance != 0 && holder_new.current_balance > holder_new.max_balance {
+        return Err(TransferRestrictionsError::HolderMaxBalanceExceeded.into());
+    }
+    if group.max_balance != 0 && holder_new.current_balance > group.max_balance {
+        return Err(TransferRestrictionsError::GroupMaxBalanceExceeded.into());
+    }
+    if holders_of_record_enforced {
+        if joined_holders
+            && transfer_restriction_data.current_holders_of_record_count
+                > transfer_restriction_data.max_holders
+        {
+            return Err(TransferRestrictionsError::MaxHoldersReached.into());
+        }
+        if joined_group_holders
+            && group.max_holders != 0
+            && group.current_holders_of_record_count > group.max_holders
+        {
+            return Err(TransferRestrictionsError::MaxHoldersReachedInsideTheGroup.into());
+        }
+    }
+
+    let holder_id_before = security_associated_account.holder_id;
+    security_associated_account.holder = Some(holder_new.key());
+    security_associated_account.holder_id = holder_new.id;
+
+    emit!(WalletHolderUpdatedEvent {
+        security_associated_account: security_associated_account.key(),
+        holder_before: holder_current.key(),
+        holder: holder_new.key(),
+        holder_id_before,
+        holder_id: holder_new.id,
+        authority: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
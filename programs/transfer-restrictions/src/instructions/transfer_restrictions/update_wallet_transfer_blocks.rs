@@ -0,0 +1,40 @@
+use crate::{
+    contexts::UpdateWalletTransferBlocks, errors::TransferRestrictionsError,
+    events::WalletTransferBlocksUpdatedEvent,
+};
+use access_control::Roles;
+use anchor_lang::prelude::*;
+
+/// Blocks or unblocks sending and receiving tokens through the transfer hook for the wallet.
+/// Unlike Token-2022 freeze it does not affect mints and force transfers.
+pub fn update_wallet_transfer_blocks(
+    ctx: Context<UpdateWalletTransferBlocks>,
+    send_blocked: bool,
+    receive_blocked: bool,
+) -> Result<()> {
+    let wallet_role = &ctx.accounts.authority_wallet_role;
+    if !wallet_role.has_any_role(Roles::WalletsAdmin as u8 | Roles::TransferAdmin as u8) {
+        return Err(TransferRestrictionsError::Unauthorized.into());
+    }
+
+    let security_associated_account = &mut ctx.accounts.security_associated_account;
+    require!(
+        security_associated_account.send_blocked != send_blocked
+            || security_associated_account.receive_blocked != receive_blocked,
+        TransferRestrictionsError::ValueUnchanged
+    );
+    let send_blocked_before = security_associated_account.send_blocked;
+    let receive_blocked_before = security_associated_account.receive_blocked;
+    security_associated_account.send_blocked = send_blocked;
+    security_associated_account.receive_blocked = receive_blocked;
+
+    emit!(WalletTransferBlocksUpdatedEvent {
+        security_associated_account: security_associated_account.key(),
+        send_blocked_before,
+        receive_blocked_before,
+        send_blocked,
+        receive_blocked,
+        authority: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
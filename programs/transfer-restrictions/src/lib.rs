@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 
 pub mod contexts;
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod utils;
 
@@ -26,6 +27,10 @@ pub mod transfer_restrictions {
         instructions::transfer_hook::initialize_extra_account_meta_list(ctx)
     }
 
+    pub fn update_extra_account_meta_list(ctx: Context<UpdateExtraAccountMetaList>) -> Result<()> {
+        instructions::transfer_hook::update_extra_account_meta_list(ctx)
+    }
+
     pub fn initialize_transfer_restrictions_data(
         ctx: Context<InitializeTransferRestrictionData>,
         max_holders: u64,
@@ -47,6 +52,13 @@ pub mod transfer_restrictions {
         instructions::transfer_restrictions::initialize_holder(ctx, id)
     }
 
+    pub fn initialize_transfer_restriction_holder_from_reference(
+        ctx: Context<InitializeTransferRestrictionHolderFromReference>,
+        reference: [u8; 32],
+    ) -> Result<()> {
+        instructions::transfer_restrictions::initialize_holder_from_reference(ctx, reference)
+    }
+
     pub fn initialize_transfer_rule(
         ctx: Context<InitializeTransferRule>,
         lock_until: u64,
@@ -60,14 +72,60 @@ pub mod transfer_restrictions {
         instructions::transfer_restrictions::initialize_security_associated_account(ctx)
     }
 
+    pub fn provision_investor(ctx: Context<ProvisionInvestor>, holder_id: u64) -> Result<()> {
+        instructions::transfer_restrictions::provision_investor(ctx, holder_id)
+    }
+
+    pub fn provision_investors<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProvisionInvestors<'info>>,
+        holder_ids: Vec<u64>,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::provision_investors(ctx, holder_ids)
+    }
+
     pub fn update_wallet_group(ctx: Context<UpdateWalletGroup>) -> Result<()> {
         instructions::transfer_restrictions::update_wallet_group(ctx)
     }
 
+    pub fn update_wallet_holder(ctx: Context<UpdateWalletHolder>) -> Result<()> {
+        instructions::transfer_restrictions::update_wallet_holder(ctx)
+    }
+
+    pub fn renew_wallets_validity<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RenewWalletsValidity<'info>>,
+        valid_until: u64,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::renew_wallets_validity(ctx, valid_until)
+    }
+
+    pub fn update_wallet_transfer_blocks(
+        ctx: Context<UpdateWalletTransferBlocks>,
+        send_blocked: bool,
+        receive_blocked: bool,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::update_wallet_transfer_blocks(
+            ctx,
+            send_blocked,
+            receive_blocked,
+        )
+    }
+
     pub fn pause(ctx: Context<Pause>, paused: bool) -> Result<()> {
         instructions::transfer_restrictions::pause(ctx, paused)
     }
 
+    pub fn pause_group(ctx: Context<PauseGroup>, paused: bool) -> Result<()> {
+        instructions::transfer_restrictions::pause_group(ctx, paused)
+    }
+
+    pub fn schedule_pause(
+        ctx: Context<SchedulePause>,
+        paused_from: u64,
+        paused_until: u64,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::schedule_pause(ctx, paused_from, paused_until)
+    }
+
     pub fn initialize_holder_group(ctx: Context<InitializeHolderGroup>) -> Result<()> {
         instructions::transfer_restrictions::initialize_holder_group(ctx)
     }
@@ -83,11 +141,41 @@ pub mod transfer_restrictions {
         instructions::transfer_restrictions::set_holder_group_max(ctx, holder_group_max)
     }
 
+    pub fn set_holders_of_record_enforcement(
+        ctx: Context<SetHoldersOfRecordEnforcement>,
+        enforced: bool,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::set_holders_of_record_enforcement(ctx, enforced)
+    }
+
+    pub fn set_default_transfer_rule(
+        ctx: Context<SetDefaultTransferRule>,
+        locked_until: u64,
+        locked_after: u64,
+        min_amount: u64,
+        max_amount: u64,
+        lot_size: u64,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::set_default_transfer_rule(
+            ctx,
+            locked_until,
+            locked_after,
+            min_amount,
+            max_amount,
+            lot_size,
+        )
+    }
+
     pub fn set_allow_transfer_rule(
         ctx: Context<SetAllowTransferRule>,
         locked_until: u64,
+        locked_after: u64,
     ) -> Result<()> {
-        instructions::transfer_restrictions::set_allow_transfer_rule(ctx, locked_until)
+        instructions::transfer_restrictions::set_allow_transfer_rule(
+            ctx,
+            locked_until,
+            locked_after,
+        )
     }
 
     pub fn revoke_security_associated_account(
@@ -108,7 +196,86 @@ pub mod transfer_restrictions {
         instructions::transfer_restrictions::set_lockup_escrow_account(ctx)
     }
 
-    pub fn enforce_transfer_restrictions(ctx: Context<EnforceTransferRestrictions>) -> Result<()> {
-        instructions::transfer_restrictions::enforce_transfer_restrictions(ctx)
+    pub fn enforce_transfer_restrictions(
+        ctx: Context<EnforceTransferRestrictions>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::enforce_transfer_restrictions(ctx, amount)
+    }
+
+    pub fn detect_transfer_restriction(
+        ctx: Context<DetectTransferRestriction>,
+        amount: u64,
+    ) -> Result<TransferRestrictionReason> {
+        instructions::transfer_restrictions::detect_transfer_restriction(ctx, amount)
+    }
+
+    pub fn set_group_max_balance(ctx: Context<SetGroupMaxBalance>, max_balance: u64) -> Result<()> {
+        instructions::transfer_restrictions::set_group_max_balance(ctx, max_balance)
+    }
+
+    pub fn set_group_metadata(
+        ctx: Context<SetGroupMetadata>,
+        name: String,
+        jurisdiction: String,
+        flags: u8,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::set_group_metadata(ctx, name, jurisdiction, flags)
+    }
+
+    pub fn set_group_lockup(
+        ctx: Context<SetGroupLockup>,
+        locked_until: u64,
+        frozen: bool,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::set_group_lockup(ctx, locked_until, frozen)
+    }
+
+    pub fn set_holder_max_balance(
+        ctx: Context<SetHolderMaxBalance>,
+        max_balance: u64,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::set_holder_max_balance(ctx, max_balance)
+    }
+
+    pub fn set_holder_active(ctx: Context<SetHolderActive>, active: bool) -> Result<()> {
+        instructions::transfer_restrictions::set_holder_active(ctx, active)
+    }
+
+    pub fn sync_holder_balance(ctx: Context<SyncHolderBalance>) -> Result<()> {
+        instructions::transfer_restrictions::sync_holder_balance(ctx)
+    }
+
+    pub fn set_transfer_rule_amount_limits(
+        ctx: Context<SetTransferRuleAmountLimits>,
+        min_amount: u64,
+        max_amount: u64,
+        lot_size: u64,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::set_transfer_rule_amount_limits(
+            ctx, min_amount, max_amount, lot_size,
+        )
+    }
+
+    pub fn set_group_velocity_limit(
+        ctx: Context<SetGroupVelocityLimit>,
+        velocity_limit: u64,
+        velocity_period: u64,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::set_group_velocity_limit(
+            ctx,
+            velocity_limit,
+            velocity_period,
+        )
+    }
+
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::transfer_restrictions::migrate_account(ctx)
+    }
+
+    pub fn migrate_security_associated_account(
+        ctx: Context<MigrateSecurityAssociatedAccount>,
+    ) -> Result<()> {
+        instructions::transfer_restrictions::migrate_security_associated_account(ctx)
     }
 }
@@ -1,6 +1,10 @@
 use anchor_lang::{
     prelude::Result,
-    solana_program::{program::invoke, pubkey::Pubkey, system_instruction::transfer},
+    solana_program::{
+        program::{invoke, invoke_signed},
+        pubkey::Pubkey,
+        system_instruction::{allocate, assign, create_account, transfer},
+    },
     Lamports,
 };
 use spl_tlv_account_resolution::{
@@ -8,8 +12,11 @@ use spl_tlv_account_resolution::{
 };
 
 use crate::{
-    errors::TransferRestrictionsError, SECURITY_ASSOCIATED_ACCOUNT_PREFIX,
-    TRANSFER_RESTRICTION_DATA_PREFIX, TRANSFER_RULE_PREFIX,
+    errors::TransferRestrictionsError, HolderGroup, TransferRestrictionData,
+    TransferRestrictionGroup, TransferRestrictionHolder, ANY_TRANSFER_GROUP_ID,
+    SECURITY_ASSOCIATED_ACCOUNT_PREFIX, TRANSFER_RESTRICTION_DATA_PREFIX,
+    TRANSFER_RESTRICTION_GROUP_PREFIX, TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX,
+    TRANSFER_RESTRICTION_HOLDER_PREFIX, TRANSFER_RULE_PREFIX,
 };
 
 use crate::{AccountInfo, Rent, SolanaSysvar};
@@ -29,7 +36,7 @@ pub fn get_extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
                 Seed::AccountKey { index: 1 },
             ],
             false, // is_signer
-            false, // is_writable
+            true,  // is_writable
         )?,
         // [index 6, 1] security associated account from
         ExtraAccountMeta::new_with_seeds(
@@ -40,7 +47,7 @@ pub fn get_extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
                 Seed::AccountKey { index: 0 },
             ],
             false,
-            false,
+            true,
         )?,
         // [index 7, 2] security associated account to
         ExtraAccountMeta::new_with_seeds(
@@ -51,7 +58,7 @@ pub fn get_extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
                 Seed::AccountKey { index: 2 },
             ],
             false,
-            false,
+            true,
         )?,
         // [index 8, 3] transfer rule account
         ExtraAccountMeta::new_with_seeds(
@@ -74,9 +81,173 @@ pub fn get_extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
             false,
             false,
         )?,
+        // [index 9, 4] transfer restriction holder from
+        // holder id is stored right after the `holder` option (8 + 8 + 33)
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: TRANSFER_RESTRICTION_HOLDER_PREFIX.as_bytes().to_vec(),
+                },
+                Seed::AccountKey { index: 5 },
+                Seed::AccountData {
+                    account_index: 6,
+                    data_index: 49,
+                    length: 8,
+                },
+            ],
+            false,
+            true,
+        )?,
+        // [index 10, 5] transfer restriction holder to
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: TRANSFER_RESTRICTION_HOLDER_PREFIX.as_bytes().to_vec(),
+                },
+                Seed::AccountKey { index: 5 },
+                Seed::AccountData {
+                    account_index: 7,
+                    data_index: 49,
+                    length: 8,
+                },
+            ],
+            false,
+            true,
+        )?,
+        // [index 11, 6] transfer restriction group to
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: TRANSFER_RESTRICTION_GROUP_PREFIX.as_bytes().to_vec(),
+                },
+                Seed::AccountKey { index: 5 },
+                Seed::AccountData {
+                    account_index: 7,
+                    data_index: 8,
+                    length: 8,
+                },
+            ],
+            false,
+            true,
+        )?,
+        // [index 12, 7] transfer restriction group from
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: TRANSFER_RESTRICTION_GROUP_PREFIX.as_bytes().to_vec(),
+                },
+                Seed::AccountKey { index: 5 },
+                Seed::AccountData {
+                    account_index: 6,
+                    data_index: 8,
+                    length: 8,
+                },
+            ],
+            false,
+            true,
+        )?,
+        // [index 13, 8] holder group from
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX.as_bytes().to_vec(),
+                },
+                Seed::AccountKey { index: 9 },
+                Seed::AccountData {
+                    account_index: 6,
+                    data_index: 8,
+                    length: 8,
+                },
+            ],
+            false,
+            true,
+        )?,
+        // [index 14, 9] holder group to
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX.as_bytes().to_vec(),
+                },
+                Seed::AccountKey { index: 10 },
+                Seed::AccountData {
+                    account_index: 7,
+                    data_index: 8,
+                    length: 8,
+                },
+            ],
+            false,
+            true,
+        )?,
+        // [index 15, 10] wildcard transfer rule from the sender group to any group
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: TRANSFER_RULE_PREFIX.as_bytes().to_vec(),
+                },
+                Seed::AccountKey { index: 5 },
+                Seed::AccountData {
+                    account_index: 6,
+                    data_index: 8,
+                    length: 8,
+                },
+                Seed::Literal {
+                    bytes: ANY_TRANSFER_GROUP_ID.to_le_bytes().to_vec(),
+                },
+            ],
+            false,
+            false,
+        )?,
+        // [index 16, 11] wildcard transfer rule from any group to the recipient group
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: TRANSFER_RULE_PREFIX.as_bytes().to_vec(),
+                },
+                Seed::AccountKey { index: 5 },
+                Seed::Literal {
+                    bytes: ANY_TRANSFER_GROUP_ID.to_le_bytes().to_vec(),
+                },
+                Seed::AccountData {
+                    account_index: 7,
+                    data_index: 8,
+                    length: 8,
+                },
+            ],
+            false,
+            false,
+        )?,
     ])
 }
 
+/// Applies a wallet balance change to the balances of its holder and holder group,
+/// keeping the global and group holders of record (holders with a non-zero balance) counts.
+/// Returns whether the holder became a holder of record globally and inside the group.
+pub fn record_holder_wallet_balance(
+    transfer_restriction_data: &mut TransferRestrictionData,
+    group: &mut TransferRestrictionGroup,
+    holder: &mut TransferRestrictionHolder,
+    holder_group: &mut HolderGroup,
+    previous_balance: u64,
+    new_balance: u64,
+) -> Result<(bool, bool)> {
+    let holder_previous_balance = holder.current_balance;
+    holder.update_wallet_balance(previous_balance, new_balance)?;
+    transfer_restriction_data
+        .update_holders_of_record_count(holder_previous_balance, holder.current_balance)?;
+
+    let holder_group_previous_balance = holder_group.current_balance;
+    holder_group.update_wallet_balance(previous_balance, new_balance)?;
+    group.update_holders_of_record_count(
+        holder_group_previous_balance,
+        holder_group.current_balance,
+    )?;
+
+    Ok((
+        holder_previous_balance == 0 && holder.current_balance != 0,
+        holder_group_previous_balance == 0 && holder_group.current_balance != 0,
+    ))
+}
+
 pub fn update_account_lamports_to_minimum_balance<'info>(
     account: AccountInfo<'info>,
     payer: AccountInfo<'info>,
@@ -92,6 +263,58 @@ pub fn update_account_lamports_to_minimum_balance<'info>(
     Ok(())
 }
 
+/// Creates the program owned PDA account of the given space, paid by the payer.
+/// Mirrors the `init` account constraint for accounts passed as remaining accounts.
+pub fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    seeds: &[&[u8]],
+    program_id: &Pubkey,
+) -> Result<()> {
+    let (pda, bump_seed) = Pubkey::find_program_address(seeds, program_id);
+    if pda != *account.key {
+        return Err(TransferRestrictionsError::InvalidPDA.into());
+    }
+    let bump = [bump_seed];
+    let signer_seeds = [seeds, &[&bump[..]]].concat();
+    let rent_lamports = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.get_lamports();
+    if current_lamports == 0 {
+        invoke_signed(
+            &create_account(
+                payer.key,
+                account.key,
+                rent_lamports,
+                space as u64,
+                program_id,
+            ),
+            &[payer.clone(), account.clone(), system_program.clone()],
+            &[&signer_seeds],
+        )?;
+    } else {
+        // account may be prefunded to prevent its creation
+        if rent_lamports > current_lamports {
+            invoke(
+                &transfer(payer.key, account.key, rent_lamports - current_lamports),
+                &[payer.clone(), account.clone(), system_program.clone()],
+            )?;
+        }
+        invoke_signed(
+            &allocate(account.key, space as u64),
+            &[account.clone(), system_program.clone()],
+            &[&signer_seeds],
+        )?;
+        invoke_signed(
+            &assign(account.key, program_id),
+            &[account.clone(), system_program.clone()],
+            &[&signer_seeds],
+        )?;
+    }
+    Ok(())
+}
+
 pub fn verify_pda(address: &Pubkey, seeds: &[&[u8]], program_id: &Pubkey) -> Result<()> {
     let (pda, _bump_seed) = Pubkey::find_program_address(seeds, program_id);
     if pda != *address {
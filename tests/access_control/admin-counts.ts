@@ -0,0 +1,135 @@
+import { assert } from "chai";
+import { Keypair } from "@solana/web3.js";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { Roles } from "../helpers/access-control_helper";
+
+describe("Access Control admin counts", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 10000,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const newContractAdmin = Keypair.generate();
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+  });
+
+  it("counts admins of each role", async () => {
+    const {
+      contractAdminCount,
+      reserveAdminCount,
+      walletsAdminCount,
+      transferAdminCount,
+    } = await testEnvironment.accessControlHelper.accessControlData();
+    assert.equal(contractAdminCount.toNumber(), 1);
+    assert.equal(reserveAdminCount.toNumber(), 1);
+    assert.equal(walletsAdminCount.toNumber(), 1);
+    assert.equal(transferAdminCount.toNumber(), 1);
+  });
+
+  it("fails to remove the last reserve admin role", async () => {
+    try {
+      await testEnvironment.accessControlHelper.updateWalletRole(
+        testEnvironment.reserveAdmin.publicKey,
+        Roles.WalletsAdmin,
+        testEnvironment.contractAdmin
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "CannotRemoveLastAdmin");
+      assert.equal(
+        error.errorMessage,
+        "Cannot remove the last Contract Admin or Reserve Admin"
+      );
+    }
+  });
+
+  it("fails to revoke the last reserve admin", async () => {
+    try {
+      await testEnvironment.accessControlHelper.revokeWalletRole(
+        testEnvironment.reserveAdmin.publicKey,
+        testEnvironment.contractAdmin
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "CannotRemoveLastAdmin");
+      assert.equal(
+        error.errorMessage,
+        "Cannot remove the last Contract Admin or Reserve Admin"
+      );
+    }
+  });
+
+  it("removes wallets admin role", async () => {
+    await testEnvironment.accessControlHelper.updateWalletRole(
+      testEnvironment.walletsAdmin.publicKey,
+      Roles.TransferAdmin,
+      testEnvironment.contractAdmin
+    );
+
+    const { walletsAdminCount, transferAdminCount } =
+      await testEnvironment.accessControlHelper.accessControlData();
+    assert.equal(walletsAdminCount.toNumber(), 0);
+    assert.equal(transferAdminCount.toNumber(), 2);
+  });
+
+  it("hands over contract admin role to another wallet", async () => {
+    await testEnvironment.accessControlHelper.initializeWalletRole(
+      newContractAdmin.publicKey,
+      Roles.ContractAdmin,
+      testEnvironment.contractAdmin
+    );
+    await testEnvironment.accessControlHelper.revokeWalletRole(
+      testEnvironment.contractAdmin.publicKey,
+      testEnvironment.contractAdmin
+    );
+
+    const { contractAdminCount } =
+      await testEnvironment.accessControlHelper.accessControlData();
+    assert.equal(contractAdminCount.toNumber(), 1);
+  });
+
+  it("fails to migrate access control with the current layout", async () => {
+    try {
+      await testEnvironment.accessControlHelper.migrateAccessControl(
+        [
+          newContractAdmin.publicKey,
+          testEnvironment.reserveAdmin.publicKey,
+          testEnvironment.walletsAdmin.publicKey,
+          testEnvironment.transferAdmin.publicKey,
+        ],
+        newContractAdmin
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "AccountAlreadyMigrated");
+      assert.equal(error.errorMessage, "Account is already migrated");
+    }
+  });
+
+  it("fails to migrate access control by non contract admin", async () => {
+    try {
+      await testEnvironment.accessControlHelper.migrateAccessControl(
+        [testEnvironment.reserveAdmin.publicKey],
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+    }
+  });
+});
@@ -0,0 +1,125 @@
+import { assert } from "chai";
+import { Keypair } from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  getMint,
+  getTokenMetadata,
+  getTransferHook,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+
+describe("Access Control authority handover", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 10000,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const newAuthority = Keypair.generate();
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+  });
+
+  it("fails to propose authority by non authority", async () => {
+    try {
+      await testEnvironment.accessControlHelper.proposeAuthority(
+        newAuthority.publicKey,
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("proposes new authority", async () => {
+    await testEnvironment.accessControlHelper.proposeAuthority(
+      newAuthority.publicKey,
+      testEnvironment.contractAdmin
+    );
+
+    const { authority, pendingAuthority } =
+      await testEnvironment.accessControlHelper.accessControlData();
+    assert.equal(
+      authority.toBase58(),
+      testEnvironment.contractAdmin.publicKey.toBase58()
+    );
+    assert.equal(pendingAuthority.toBase58(), newAuthority.publicKey.toBase58());
+  });
+
+  it("fails to propose the same authority", async () => {
+    try {
+      await testEnvironment.accessControlHelper.proposeAuthority(
+        newAuthority.publicKey,
+        testEnvironment.contractAdmin
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "ValueUnchanged");
+      assert.equal(
+        error.errorMessage,
+        "The provided value is already set. No changes were made"
+      );
+    }
+  });
+
+  it("fails to accept authority by not proposed wallet", async () => {
+    try {
+      await testEnvironment.accessControlHelper.acceptAuthority(
+        testEnvironment.contractAdmin,
+        Keypair.generate()
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidPendingAuthority");
+      assert.equal(error.errorMessage, "New authority was not proposed");
+    }
+  });
+
+  it("accepts authority and moves mint authorities to it", async () => {
+    await testEnvironment.accessControlHelper.acceptAuthority(
+      testEnvironment.contractAdmin,
+      newAuthority
+    );
+
+    const { authority, pendingAuthority } =
+      await testEnvironment.accessControlHelper.accessControlData();
+    assert.equal(authority.toBase58(), newAuthority.publicKey.toBase58());
+    assert.isNull(pendingAuthority);
+
+    const mintInfo = await getMint(
+      testEnvironment.connection,
+      testEnvironment.mintKeypair.publicKey,
+      testEnvironment.commitment,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const transferHook = getTransferHook(mintInfo);
+    assert.equal(
+      transferHook.authority.toBase58(),
+      newAuthority.publicKey.toBase58()
+    );
+    const metadata = await getTokenMetadata(
+      testEnvironment.connection,
+      testEnvironment.mintKeypair.publicKey,
+      testEnvironment.commitment
+    );
+    assert.equal(
+      metadata.updateAuthority.toBase58(),
+      newAuthority.publicKey.toBase58()
+    );
+  });
+});
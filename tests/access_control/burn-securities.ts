@@ -97,7 +97,7 @@ describe("Access Control burn securities", () => {
 
     try {
       await testEnvironment.accessControlHelper.program.methods
-        .burnSecurities(amount)
+        .burnSecurities(amount, "")
         .accountsStrict({
           authority: testEnvironment.reserveAdmin.publicKey,
           authorityWalletRole: reserveAdminWalletRole,
@@ -141,7 +141,7 @@ describe("Access Control burn securities", () => {
       );
     try {
       await testEnvironment.accessControlHelper.program.methods
-        .burnSecurities(amount)
+        .burnSecurities(amount, "")
         .accountsStrict({
           authority: attackerEnvironment.reserveAdmin.publicKey,
           authorityWalletRole: attackerReserveAdminWalletRole,
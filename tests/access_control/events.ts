@@ -0,0 +1,177 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { Roles } from "../helpers/access-control_helper";
+
+describe("Access Control events", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 10000,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const target = new Keypair();
+  let targetTokenAccount: PublicKey;
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+
+    targetTokenAccount =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        target.publicKey,
+        testEnvironment.contractAdmin
+      );
+  });
+
+  it("emits SecuritiesMintedEvent and SecuritiesBurnedEvent", async () => {
+    const amount = new anchor.BN(1_000);
+    const reason = "Subscription agreement 42";
+    let txSignature = await testEnvironment.accessControlHelper.mintSecurities(
+      amount,
+      target.publicKey,
+      targetTokenAccount,
+      testEnvironment.reserveAdmin,
+      reason
+    );
+
+    let [event] = await testEnvironment.accessControlHelper.parseEvents(
+      txSignature
+    );
+    assert.equal(event.name, "SecuritiesMintedEvent");
+    assert.equal(
+      event.data.destinationAccount.toBase58(),
+      targetTokenAccount.toBase58()
+    );
+    assert.equal(
+      event.data.destinationAuthority.toBase58(),
+      target.publicKey.toBase58()
+    );
+    assert.equal(event.data.amount.toNumber(), amount.toNumber());
+    assert.equal(
+      event.data.authority.toBase58(),
+      testEnvironment.reserveAdmin.publicKey.toBase58()
+    );
+    assert.equal(event.data.reason, reason);
+
+    txSignature = await testEnvironment.accessControlHelper.burnSecurities(
+      amount,
+      target.publicKey,
+      targetTokenAccount,
+      testEnvironment.reserveAdmin
+    );
+
+    [event] = await testEnvironment.accessControlHelper.parseEvents(
+      txSignature
+    );
+    assert.equal(event.name, "SecuritiesBurnedEvent");
+    assert.equal(
+      event.data.targetAccount.toBase58(),
+      targetTokenAccount.toBase58()
+    );
+    assert.equal(event.data.amount.toNumber(), amount.toNumber());
+    assert.equal(event.data.reason, "");
+  });
+
+  it("emits WalletFrozenEvent and WalletThawedEvent", async () => {
+    const reason = "Court order 2024-117";
+    let txSignature = await testEnvironment.accessControlHelper.freezeWallet(
+      target.publicKey,
+      targetTokenAccount,
+      testEnvironment.walletsAdmin,
+      reason
+    );
+
+    let [event] = await testEnvironment.accessControlHelper.parseEvents(
+      txSignature
+    );
+    assert.equal(event.name, "WalletFrozenEvent");
+    assert.equal(
+      event.data.targetAccount.toBase58(),
+      targetTokenAccount.toBase58()
+    );
+    assert.equal(
+      event.data.authority.toBase58(),
+      testEnvironment.walletsAdmin.publicKey.toBase58()
+    );
+    assert.equal(event.data.reason, reason);
+
+    txSignature = await testEnvironment.accessControlHelper.thawWallet(
+      target.publicKey,
+      targetTokenAccount,
+      testEnvironment.walletsAdmin
+    );
+
+    [event] = await testEnvironment.accessControlHelper.parseEvents(
+      txSignature
+    );
+    assert.equal(event.name, "WalletThawedEvent");
+    assert.equal(
+      event.data.targetAccount.toBase58(),
+      targetTokenAccount.toBase58()
+    );
+    assert.equal(event.data.reason, "");
+  });
+
+  it("fails to freeze a wallet with a too long reason", async () => {
+    try {
+      await testEnvironment.accessControlHelper.freezeWallet(
+        target.publicKey,
+        targetTokenAccount,
+        testEnvironment.walletsAdmin,
+        "x".repeat(65)
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "ReasonTooLong");
+      assert.equal(error.errorMessage, "Reason is too long");
+    }
+  });
+
+  it("emits WalletRoleUpdatedEvent", async () => {
+    const wallet = Keypair.generate();
+    let txSignature =
+      await testEnvironment.accessControlHelper.initializeWalletRole(
+        wallet.publicKey,
+        Roles.WalletsAdmin,
+        testEnvironment.contractAdmin
+      );
+
+    let [event] = await testEnvironment.accessControlHelper.parseEvents(
+      txSignature
+    );
+    assert.equal(event.name, "WalletRoleUpdatedEvent");
+    assert.equal(event.data.wallet.toBase58(), wallet.publicKey.toBase58());
+    assert.equal(event.data.roleBefore, Roles.None);
+    assert.equal(event.data.role, Roles.WalletsAdmin);
+
+    txSignature = await testEnvironment.accessControlHelper.updateWalletRole(
+      wallet.publicKey,
+      Roles.TransferAdmin,
+      testEnvironment.contractAdmin
+    );
+
+    [event] = await testEnvironment.accessControlHelper.parseEvents(
+      txSignature
+    );
+    assert.equal(event.name, "WalletRoleUpdatedEvent");
+    assert.equal(event.data.roleBefore, Roles.WalletsAdmin);
+    assert.equal(event.data.role, Roles.TransferAdmin);
+    assert.equal(
+      event.data.authority.toBase58(),
+      testEnvironment.contractAdmin.publicKey.toBase58()
+    );
+  });
+});
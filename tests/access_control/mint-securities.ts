@@ -95,7 +95,7 @@ describe("Access Control mint securities", () => {
 
     try {
       await testEnvironment.accessControlHelper.program.methods
-        .mintSecurities(amount)
+        .mintSecurities(amount, "")
         .accountsStrict({
           authority: testEnvironment.reserveAdmin.publicKey,
           authorityWalletRole: reserveAdminWalletRole,
@@ -139,7 +139,7 @@ describe("Access Control mint securities", () => {
       );
     try {
       await testEnvironment.accessControlHelper.program.methods
-        .mintSecurities(amount)
+        .mintSecurities(amount, "")
         .accountsStrict({
           authority: attackerEnvironment.reserveAdmin.publicKey,
           authorityWalletRole: attackerReserveAdminWalletRole,
@@ -0,0 +1,572 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { Roles } from "../helpers/access-control_helper";
+import { getNowTs } from "../helpers/clock_helper";
+
+describe("Access Control proposals", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 10000,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const secondReserveAdmin = Keypair.generate();
+  const thirdReserveAdmin = Keypair.generate();
+  const executor = Keypair.generate();
+  const recipient = Keypair.generate();
+  let recipientTokenAccount: PublicKey;
+  const otherRecipient = Keypair.generate();
+  let otherRecipientTokenAccount: PublicKey;
+  const mintAction = { mintSecurities: {} };
+  const amount = new anchor.BN(1_000_000);
+  const reason = "Board resolution 7";
+  let proposalPubkey: PublicKey;
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+
+    recipientTokenAccount =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        recipient.publicKey,
+        testEnvironment.contractAdmin
+      );
+    otherRecipientTokenAccount =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        otherRecipient.publicKey,
+        testEnvironment.contractAdmin
+      );
+  });
+
+  it("fails to set threshold above the number of reserve admins", async () => {
+    try {
+      await testEnvironment.accessControlHelper.setApprovalThreshold(
+        mintAction,
+        2,
+        testEnvironment.contractAdmin
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidApprovalThreshold");
+      assert.equal(error.errorMessage, "Invalid approval threshold");
+    }
+  });
+
+  it("fails to set threshold by non contract admin", async () => {
+    try {
+      await testEnvironment.accessControlHelper.setApprovalThreshold(
+        mintAction,
+        1,
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("sets mint threshold to 2 of 2 reserve admins", async () => {
+    await testEnvironment.accessControlHelper.initializeWalletRole(
+      secondReserveAdmin.publicKey,
+      Roles.ReserveAdmin,
+      testEnvironment.contractAdmin
+    );
+    await testEnvironment.accessControlHelper.setApprovalThreshold(
+      mintAction,
+      2,
+      testEnvironment.contractAdmin
+    );
+
+    const { approvalThresholds } =
+      await testEnvironment.accessControlHelper.accessControlData();
+    assert.deepEqual(approvalThresholds, [2, 0, 0, 0]);
+  });
+
+  it("fails to mint securities without proposal", async () => {
+    try {
+      await testEnvironment.accessControlHelper.mintSecurities(
+        amount,
+        recipient.publicKey,
+        recipientTokenAccount,
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "ProposalRequired");
+      assert.equal(error.errorMessage, "Action requires an approved proposal");
+    }
+  });
+
+  it("fails to create proposal by non reserve admin", async () => {
+    try {
+      await testEnvironment.accessControlHelper.createProposal(
+        {
+          mintSecurities: {
+            destinationAccount: recipientTokenAccount,
+            amount,
+          },
+        },
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("creates mint securities proposal", async () => {
+    let txSignature: string;
+    [proposalPubkey, txSignature] =
+      await testEnvironment.accessControlHelper.createProposal(
+        {
+          mintSecurities: {
+            destinationAccount: recipientTokenAccount,
+            amount,
+          },
+        },
+        testEnvironment.reserveAdmin,
+        reason
+      );
+
+    const { id, proposer, approvals } =
+      await testEnvironment.accessControlHelper.proposalData(proposalPubkey);
+    assert.equal(id.toNumber(), 0);
+    assert.equal(
+      proposer.toBase58(),
+      testEnvironment.reserveAdmin.publicKey.toBase58()
+    );
+    assert.equal(approvals.length, 1);
+
+    const [event] = await testEnvironment.accessControlHelper.parseEvents(
+      txSignature
+    );
+    assert.equal(event.name, "ProposalCreatedEvent");
+    assert.equal(event.data.proposalId.toNumber(), 0);
+    assert.equal(event.data.reason, reason);
+  });
+
+  it("fails to execute proposal without enough approvals", async () => {
+    try {
+      await testEnvironment.accessControlHelper.executeMintSecuritiesProposal(
+        proposalPubkey,
+        recipient.publicKey,
+        recipientTokenAccount,
+        executor
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "NotEnoughApprovals");
+      assert.equal(
+        error.errorMessage,
+        "Proposal does not have enough approvals"
+      );
+    }
+  });
+
+  it("fails to approve proposal twice", async () => {
+    try {
+      await testEnvironment.accessControlHelper.approveProposal(
+        proposalPubkey,
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "ProposalAlreadyApproved");
+      assert.equal(
+        error.errorMessage,
+        "Proposal is already approved by the wallet"
+      );
+    }
+  });
+
+  it("fails to approve proposal by non reserve admin", async () => {
+    try {
+      await testEnvironment.accessControlHelper.approveProposal(
+        proposalPubkey,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("approves proposal by second reserve admin", async () => {
+    const txSignature =
+      await testEnvironment.accessControlHelper.approveProposal(
+        proposalPubkey,
+        secondReserveAdmin
+      );
+
+    const { approvals } =
+      await testEnvironment.accessControlHelper.proposalData(proposalPubkey);
+    assert.equal(approvals.length, 2);
+    assert.equal(
+      approvals[1].toBase58(),
+      secondReserveAdmin.publicKey.toBase58()
+    );
+
+    const [event] = await testEnvironment.accessControlHelper.parseEvents(
+      txSignature
+    );
+    assert.equal(event.name, "ProposalApprovedEvent");
+    assert.equal(event.data.approvals, 2);
+  });
+
+  it("fails to execute proposal with other destination", async () => {
+    try {
+      await testEnvironment.accessControlHelper.executeMintSecuritiesProposal(
+        proposalPubkey,
+        otherRecipient.publicKey,
+        otherRecipientTokenAccount,
+        executor
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "ProposalActionMismatch");
+      assert.equal(
+        error.errorMessage,
+        "Accounts do not match the proposal action"
+      );
+    }
+  });
+
+  it("executes approved mint securities proposal", async () => {
+    const { supply: supplyBeforeMint } =
+      await testEnvironment.mintHelper.getMint();
+
+    const txSignature =
+      await testEnvironment.accessControlHelper.executeMintSecuritiesProposal(
+        proposalPubkey,
+        recipient.publicKey,
+        recipientTokenAccount,
+        executor
+      );
+
+    const { supply: supplyAfterMint } =
+      await testEnvironment.mintHelper.getMint();
+    assert.equal(
+      (supplyAfterMint - supplyBeforeMint).toString(),
+      amount.toString()
+    );
+    const recipientTokenAccountInfo =
+      await testEnvironment.mintHelper.getAccount(recipientTokenAccount);
+    assert.equal(
+      recipientTokenAccountInfo.amount.toString(),
+      amount.toString()
+    );
+    const proposalInfo = await testEnvironment.connection.getAccountInfo(
+      proposalPubkey
+    );
+    assert.isNull(proposalInfo);
+
+    const [mintedEvent, executedEvent] =
+      await testEnvironment.accessControlHelper.parseEvents(txSignature);
+    assert.equal(mintedEvent.name, "SecuritiesMintedEvent");
+    assert.equal(
+      mintedEvent.data.authority.toBase58(),
+      testEnvironment.reserveAdmin.publicKey.toBase58()
+    );
+    assert.equal(mintedEvent.data.reason, reason);
+    assert.equal(executedEvent.name, "ProposalExecutedEvent");
+    assert.equal(
+      executedEvent.data.executor.toBase58(),
+      executor.publicKey.toBase58()
+    );
+  });
+
+  it("fails to revoke reserve admin below the approval threshold", async () => {
+    try {
+      await testEnvironment.accessControlHelper.revokeWalletRole(
+        secondReserveAdmin.publicKey,
+        testEnvironment.contractAdmin
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(
+        error.errorCode.code,
+        "ReserveAdminsBelowApprovalThreshold"
+      );
+      assert.equal(
+        error.errorMessage,
+        "Fewer Reserve Admins would be left than an approval threshold requires"
+      );
+    }
+  });
+
+  it("fails to execute proposal approved by a revoked reserve admin", async () => {
+    await testEnvironment.accessControlHelper.initializeWalletRole(
+      thirdReserveAdmin.publicKey,
+      Roles.ReserveAdmin,
+      testEnvironment.contractAdmin
+    );
+    const [revokedProposalPubkey] =
+      await testEnvironment.accessControlHelper.createProposal(
+        {
+          mintSecurities: {
+            destinationAccount: recipientTokenAccount,
+            amount,
+          },
+        },
+        testEnvironment.reserveAdmin
+      );
+    await testEnvironment.accessControlHelper.approveProposal(
+      revokedProposalPubkey,
+      thirdReserveAdmin
+    );
+    await testEnvironment.accessControlHelper.revokeWalletRole(
+      thirdReserveAdmin.publicKey,
+      testEnvironment.contractAdmin
+    );
+
+    try {
+      await testEnvironment.accessControlHelper.executeMintSecuritiesProposal(
+        revokedProposalPubkey,
+        recipient.publicKey,
+        recipientTokenAccount,
+        executor
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "NotEnoughApprovals");
+      assert.equal(
+        error.errorMessage,
+        "Proposal does not have enough approvals"
+      );
+    }
+  });
+
+  it("executes burn securities proposal without threshold", async () => {
+    const [burnProposalPubkey] =
+      await testEnvironment.accessControlHelper.createProposal(
+        {
+          burnSecurities: {
+            targetAccount: recipientTokenAccount,
+            amount,
+          },
+        },
+        testEnvironment.reserveAdmin
+      );
+    await testEnvironment.accessControlHelper.executeBurnSecuritiesProposal(
+      burnProposalPubkey,
+      recipient.publicKey,
+      recipientTokenAccount,
+      executor
+    );
+
+    const recipientTokenAccountInfo =
+      await testEnvironment.mintHelper.getAccount(recipientTokenAccount);
+    assert.equal(recipientTokenAccountInfo.amount.toString(), "0");
+  });
+
+  it("executes set max total supply proposal", async () => {
+    const maxTotalSupply = new anchor.BN(
+      testEnvironmentParams.maxTotalSupply + 1
+    );
+    const [maxTotalSupplyProposalPubkey] =
+      await testEnvironment.accessControlHelper.createProposal(
+        { setMaxTotalSupply: { maxTotalSupply } },
+        testEnvironment.reserveAdmin
+      );
+    await testEnvironment.accessControlHelper.executeSetMaxTotalSupplyProposal(
+      maxTotalSupplyProposalPubkey,
+      executor
+    );
+
+    const accessControl =
+      await testEnvironment.accessControlHelper.accessControlData();
+    assert.equal(
+      accessControl.maxTotalSupply.toString(),
+      maxTotalSupply.toString()
+    );
+    assert.equal(accessControl.proposalsCount.toNumber(), 4);
+  });
+
+  it("fails to create proposal expiring in the past", async () => {
+    const nowTs = await getNowTs(testEnvironment.connection);
+    try {
+      await testEnvironment.accessControlHelper.createProposal(
+        {
+          mintSecurities: {
+            destinationAccount: recipientTokenAccount,
+            amount,
+          },
+        },
+        testEnvironment.reserveAdmin,
+        reason,
+        new anchor.BN(nowTs - 1)
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidProposalExpiration");
+      assert.equal(
+        error.errorMessage,
+        "Proposal expiration must be in the future"
+      );
+    }
+  });
+
+  it("fails to approve and execute expired proposal", async () => {
+    const expiresAt = new anchor.BN(
+      (await getNowTs(testEnvironment.connection)) + 2
+    );
+    const [expiredProposalPubkey] =
+      await testEnvironment.accessControlHelper.createProposal(
+        {
+          mintSecurities: {
+            destinationAccount: recipientTokenAccount,
+            amount,
+          },
+        },
+        testEnvironment.reserveAdmin,
+        reason,
+        expiresAt
+      );
+    const { expiresAt: proposalExpiresAt } =
+      await testEnvironment.accessControlHelper.proposalData(
+        expiredProposalPubkey
+      );
+    assert.equal(proposalExpiresAt.toString(), expiresAt.toString());
+    while (
+      (await getNowTs(testEnvironment.connection)) < expiresAt.toNumber()
+    ) {
+      await new Promise((resolve) => setTimeout(resolve, 500));
+    }
+
+    try {
+      await testEnvironment.accessControlHelper.approveProposal(
+        expiredProposalPubkey,
+        secondReserveAdmin
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "ProposalExpired");
+      assert.equal(error.errorMessage, "Proposal is expired");
+    }
+    try {
+      await testEnvironment.accessControlHelper.executeMintSecuritiesProposal(
+        expiredProposalPubkey,
+        recipient.publicKey,
+        recipientTokenAccount,
+        executor
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "ProposalExpired");
+      assert.equal(error.errorMessage, "Proposal is expired");
+    }
+  });
+
+  it("fails to cancel proposal by other reserve admin", async () => {
+    const [cancelledProposalPubkey] =
+      await testEnvironment.accessControlHelper.createProposal(
+        {
+          mintSecurities: {
+            destinationAccount: recipientTokenAccount,
+            amount,
+          },
+        },
+        testEnvironment.reserveAdmin
+      );
+    try {
+      await testEnvironment.accessControlHelper.cancelProposal(
+        cancelledProposalPubkey,
+        secondReserveAdmin
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("cancels proposal by the proposer", async () => {
+    const [cancelledProposalPubkey] =
+      await testEnvironment.accessControlHelper.createProposal(
+        {
+          mintSecurities: {
+            destinationAccount: recipientTokenAccount,
+            amount,
+          },
+        },
+        testEnvironment.reserveAdmin
+      );
+    const proposalRent = (
+      await testEnvironment.connection.getAccountInfo(cancelledProposalPubkey)
+    ).lamports;
+    const proposerBalanceBefore = await testEnvironment.connection.getBalance(
+      testEnvironment.reserveAdmin.publicKey
+    );
+
+    const txSignature =
+      await testEnvironment.accessControlHelper.cancelProposal(
+        cancelledProposalPubkey,
+        testEnvironment.reserveAdmin
+      );
+
+    const proposalInfo = await testEnvironment.connection.getAccountInfo(
+      cancelledProposalPubkey
+    );
+    assert.isNull(proposalInfo);
+    const proposerBalanceAfter = await testEnvironment.connection.getBalance(
+      testEnvironment.reserveAdmin.publicKey
+    );
+    assert.isAbove(proposerBalanceAfter, proposerBalanceBefore);
+    assert.isAtMost(proposerBalanceAfter - proposerBalanceBefore, proposalRent);
+
+    const [event] = await testEnvironment.accessControlHelper.parseEvents(
+      txSignature
+    );
+    assert.equal(event.name, "ProposalCancelledEvent");
+    assert.equal(
+      event.data.authority.toBase58(),
+      testEnvironment.reserveAdmin.publicKey.toBase58()
+    );
+  });
+
+  it("cancels proposal by contract admin", async () => {
+    const [cancelledProposalPubkey] =
+      await testEnvironment.accessControlHelper.createProposal(
+        {
+          mintSecurities: {
+            destinationAccount: recipientTokenAccount,
+            amount,
+          },
+        },
+        testEnvironment.reserveAdmin
+      );
+    const proposerBalanceBefore = await testEnvironment.connection.getBalance(
+      testEnvironment.reserveAdmin.publicKey
+    );
+
+    await testEnvironment.accessControlHelper.cancelProposal(
+      cancelledProposalPubkey,
+      testEnvironment.contractAdmin
+    );
+
+    const proposalInfo = await testEnvironment.connection.getAccountInfo(
+      cancelledProposalPubkey
+    );
+    assert.isNull(proposalInfo);
+    const proposerBalanceAfter = await testEnvironment.connection.getBalance(
+      testEnvironment.reserveAdmin.publicKey
+    );
+    assert.isAbove(proposerBalanceAfter, proposerBalanceBefore);
+  });
+});
@@ -0,0 +1,127 @@
+import { assert } from "chai";
+import { Keypair } from "@solana/web3.js";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { Roles } from "../helpers/access-control_helper";
+
+describe("Access Control revoke wallet role", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 10000,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const wallet = Keypair.generate();
+  const rentReceiver = Keypair.generate();
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+
+    await testEnvironment.accessControlHelper.initializeWalletRole(
+      wallet.publicKey,
+      Roles.WalletsAdmin,
+      testEnvironment.contractAdmin
+    );
+  });
+
+  it("fails to revoke wallet role by non contract admin", async () => {
+    try {
+      await testEnvironment.accessControlHelper.revokeWalletRole(
+        wallet.publicKey,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("fails to revoke the last contract admin", async () => {
+    try {
+      await testEnvironment.accessControlHelper.revokeWalletRole(
+        testEnvironment.contractAdmin.publicKey,
+        testEnvironment.contractAdmin
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "CannotRemoveLastAdmin");
+      assert.equal(
+        error.errorMessage,
+        "Cannot remove the last Contract Admin or Reserve Admin"
+      );
+    }
+  });
+
+  it("fails to remove the last contract admin role", async () => {
+    try {
+      await testEnvironment.accessControlHelper.updateWalletRole(
+        testEnvironment.contractAdmin.publicKey,
+        Roles.ReserveAdmin,
+        testEnvironment.contractAdmin
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "CannotRemoveLastAdmin");
+      assert.equal(
+        error.errorMessage,
+        "Cannot remove the last Contract Admin or Reserve Admin"
+      );
+    }
+  });
+
+  it("revokes wallet role and refunds rent", async () => {
+    const [walletRolePubkey] =
+      testEnvironment.accessControlHelper.walletRolePDA(wallet.publicKey);
+    const walletRoleLamports = await testEnvironment.connection.getBalance(
+      walletRolePubkey
+    );
+
+    const txSignature =
+      await testEnvironment.accessControlHelper.revokeWalletRole(
+        wallet.publicKey,
+        testEnvironment.contractAdmin,
+        rentReceiver.publicKey
+      );
+
+    const walletRoleInfo = await testEnvironment.connection.getAccountInfo(
+      walletRolePubkey
+    );
+    assert.isNull(walletRoleInfo);
+    const rentReceiverLamports = await testEnvironment.connection.getBalance(
+      rentReceiver.publicKey
+    );
+    assert.equal(rentReceiverLamports, walletRoleLamports);
+
+    const [event] = await testEnvironment.accessControlHelper.parseEvents(
+      txSignature
+    );
+    assert.equal(event.name, "WalletRoleRevokedEvent");
+    assert.equal(event.data.wallet.toBase58(), wallet.publicKey.toBase58());
+    assert.equal(event.data.role, Roles.WalletsAdmin);
+  });
+
+  it("initializes revoked wallet role again", async () => {
+    await testEnvironment.accessControlHelper.initializeWalletRole(
+      wallet.publicKey,
+      Roles.TransferAdmin,
+      testEnvironment.contractAdmin
+    );
+
+    const { role } = await testEnvironment.accessControlHelper.walletRoleData(
+      testEnvironment.accessControlHelper.walletRolePDA(wallet.publicKey)[0]
+    );
+    assert.equal(role, Roles.TransferAdmin);
+  });
+});
@@ -0,0 +1,106 @@
+import { assert } from "chai";
+import { getTokenMetadata } from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+
+describe("Access Control update token metadata", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 10000,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+  });
+
+  it("fails to update token metadata by non authority", async () => {
+    try {
+      await testEnvironment.accessControlHelper.updateTokenMetadata(
+        {
+          name: "ABC Token",
+          symbol: null,
+          uri: null,
+          additionalMetadata: [],
+        },
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expected an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("updates token metadata fields and additional metadata", async () => {
+    const txSignature =
+      await testEnvironment.accessControlHelper.updateTokenMetadata(
+        {
+          name: "ABC Token",
+          symbol: "ABC",
+          uri: null,
+          additionalMetadata: [
+            { key: "cusip", value: "037833100" },
+            { key: "isin", value: "US0378331005" },
+          ],
+        },
+        testEnvironment.contractAdmin
+      );
+
+    const metadata = await getTokenMetadata(
+      testEnvironment.connection,
+      testEnvironment.mintKeypair.publicKey,
+      testEnvironment.commitment
+    );
+    assert.equal(metadata.name, "ABC Token");
+    assert.equal(metadata.symbol, "ABC");
+    assert.equal(metadata.uri, testEnvironmentParams.mint.uri);
+    assert.deepEqual(metadata.additionalMetadata, [
+      ["cusip", "037833100"],
+      ["isin", "US0378331005"],
+    ]);
+
+    const [event] = await testEnvironment.accessControlHelper.parseEvents(
+      txSignature
+    );
+    assert.equal(event.name, "TokenMetadataUpdatedEvent");
+    assert.equal(event.data.name, "ABC Token");
+    assert.isNull(event.data.uri);
+    assert.equal(event.data.additionalMetadata.length, 2);
+  });
+
+  it("overwrites additional metadata value", async () => {
+    await testEnvironment.accessControlHelper.updateTokenMetadata(
+      {
+        name: null,
+        symbol: null,
+        uri: null,
+        additionalMetadata: [{ key: "cusip", value: "594918104" }],
+      },
+      testEnvironment.contractAdmin
+    );
+
+    const metadata = await getTokenMetadata(
+      testEnvironment.connection,
+      testEnvironment.mintKeypair.publicKey,
+      testEnvironment.commitment
+    );
+    assert.equal(metadata.name, "ABC Token");
+    assert.deepEqual(metadata.additionalMetadata, [
+      ["cusip", "594918104"],
+      ["isin", "US0378331005"],
+    ]);
+  });
+});
@@ -1,5 +1,6 @@
-import { Program, utils, BN } from "@coral-xyz/anchor";
+import { Program, utils, BN, Event, EventParser } from "@coral-xyz/anchor";
 import {
+  AccountMeta,
   Keypair,
   PublicKey,
   SystemProgram,
@@ -19,6 +20,74 @@ import {
 
 export const ACCESS_CONTROL_PREFIX = "ac";
 export const WALLET_ROLE_PREFIX = "wallet_role";
+export const PROPOSAL_PREFIX = "proposal";
+
+// Accounts the access control program needs to sync the holder balance of
+// the token account after minting or burning: the transfer hook program,
+// the security associated account and, for wallets with a holder,
+// the holder, holder group, group and transfer restriction data.
+export async function holderBalanceAccounts(
+  connection: Connection,
+  mintPubkey: PublicKey,
+  tokenAccountPubkey: PublicKey,
+  commitment: Commitment = "confirmed"
+): Promise<AccountMeta[]> {
+  const mintInfo = await getMint(
+    connection,
+    mintPubkey,
+    commitment,
+    TOKEN_2022_PROGRAM_ID
+  );
+  const { programId: hookProgramId } = getTransferHook(mintInfo);
+  const [securityAssociatedAccountPubkey] = PublicKey.findProgramAddressSync(
+    [Buffer.from("saa"), tokenAccountPubkey.toBuffer()],
+    hookProgramId
+  );
+  const accounts = [
+    { pubkey: hookProgramId, isSigner: false, isWritable: false },
+    {
+      pubkey: securityAssociatedAccountPubkey,
+      isSigner: false,
+      isWritable: true,
+    },
+  ];
+  const securityAssociatedAccount = await connection.getAccountInfo(
+    securityAssociatedAccountPubkey,
+    commitment
+  );
+  // data layout: discriminator, group id, holder option tag, holder
+  if (
+    securityAssociatedAccount === null ||
+    securityAssociatedAccount.data[16] === 0
+  ) {
+    return accounts;
+  }
+  const groupId = securityAssociatedAccount.data.subarray(8, 16);
+  const holderPubkey = new PublicKey(
+    securityAssociatedAccount.data.subarray(17, 49)
+  );
+  const [transferRestrictionDataPubkey] = PublicKey.findProgramAddressSync(
+    [Buffer.from("trd"), mintPubkey.toBuffer()],
+    hookProgramId
+  );
+  const [holderGroupPubkey] = PublicKey.findProgramAddressSync(
+    [Buffer.from("trhg"), holderPubkey.toBuffer(), groupId],
+    hookProgramId
+  );
+  const [groupPubkey] = PublicKey.findProgramAddressSync(
+    [Buffer.from("trg"), transferRestrictionDataPubkey.toBuffer(), groupId],
+    hookProgramId
+  );
+  return [
+    ...accounts,
+    ...[
+      holderPubkey,
+      holderGroupPubkey,
+      groupPubkey,
+      transferRestrictionDataPubkey,
+    ].map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })),
+  ];
+}
 
 export enum Roles {
   None = 0,
@@ -58,6 +127,25 @@ export class AccessControlHelper {
     this.commitment = commitment;
   }
 
+  async holderBalanceAccounts(
+    tokenAccountPubkey: PublicKey
+  ): Promise<AccountMeta[]> {
+    return holderBalanceAccounts(
+      this.program.provider.connection,
+      this.mintPubkey,
+      tokenAccountPubkey,
+      this.commitment
+    );
+  }
+
+  approverWalletRoleAccounts(approvals: PublicKey[]): AccountMeta[] {
+    return approvals.map((approver) => ({
+      pubkey: this.walletRolePDA(approver)[0],
+      isWritable: false,
+      isSigner: false,
+    }));
+  }
+
   walletRolePDA(walletPubkey: PublicKey): [PublicKey, number] {
     return PublicKey.findProgramAddressSync(
       [
@@ -116,6 +204,24 @@ export class AccessControlHelper {
     );
   }
 
+  proposalPDA(proposalId: BN): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(PROPOSAL_PREFIX),
+        this.accessControlPubkey.toBuffer(),
+        proposalId.toArrayLike(Buffer, "le", 8),
+      ],
+      this.program.programId
+    );
+  }
+
+  async proposalData(proposalPubkey: PublicKey): Promise<any> {
+    return this.program.account.proposal.fetch(
+      proposalPubkey,
+      this.commitment
+    );
+  }
+
   async walletRoleData(walletRolePubkey: PublicKey): Promise<any> {
     return this.program.account.walletRole.fetch(
       walletRolePubkey,
@@ -123,16 +229,29 @@ export class AccessControlHelper {
     );
   }
 
+  async parseEvents(txSignature: string): Promise<Event[]> {
+    const tx = await this.program.provider.connection.getTransaction(
+      txSignature,
+      { commitment: "confirmed", maxSupportedTransactionVersion: 0 }
+    );
+    const eventParser = new EventParser(
+      this.program.programId,
+      this.program.coder
+    );
+    return Array.from(eventParser.parseLogs(tx.meta.logMessages));
+  }
+
   async mintSecurities(
     amount: BN,
     userWalletPubkey: PublicKey,
     userWalletAssociatedAccountPubkey: PublicKey,
-    signer: Keypair
+    signer: Keypair,
+    reason = ""
   ): Promise<string> {
     const authorityWalletRolePubkey = this.walletRolePDA(signer.publicKey)[0];
 
     return this.program.methods
-      .mintSecurities(amount)
+      .mintSecurities(amount, reason)
       .accountsStrict({
         authority: signer.publicKey,
         authorityWalletRole: authorityWalletRolePubkey,
@@ -142,6 +261,9 @@ export class AccessControlHelper {
         destinationAuthority: userWalletPubkey,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
       })
+      .remainingAccounts(
+        await this.holderBalanceAccounts(userWalletAssociatedAccountPubkey)
+      )
       .signers([signer])
       .rpc({ commitment: this.commitment });
   }
@@ -150,12 +272,13 @@ export class AccessControlHelper {
     amount: BN,
     userWalletPubkey: PublicKey,
     userWalletAssociatedAccountPubkey: PublicKey,
-    signer: Keypair
+    signer: Keypair,
+    reason = ""
   ): Promise<string> {
     const authorityWalletRolePubkey = this.walletRolePDA(signer.publicKey)[0];
 
     return this.program.methods
-      .burnSecurities(amount)
+      .burnSecurities(amount, reason)
       .accountsStrict({
         authority: signer.publicKey,
         authorityWalletRole: authorityWalletRolePubkey,
@@ -165,6 +288,9 @@ export class AccessControlHelper {
         targetAuthority: userWalletPubkey,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
       })
+      .remainingAccounts(
+        await this.holderBalanceAccounts(userWalletAssociatedAccountPubkey)
+      )
       .signers([signer])
       .rpc({ commitment: this.commitment });
   }
@@ -214,15 +340,39 @@ export class AccessControlHelper {
       .rpc({ commitment: this.commitment });
   }
 
+  async revokeWalletRole(
+    walletPubkey: PublicKey,
+    signer: Keypair,
+    rentReceiverPubkey: PublicKey = signer.publicKey
+  ): Promise<string> {
+    const authorityWalletRolePubkey = this.walletRolePDA(signer.publicKey)[0];
+    const walletRolePubkey = this.walletRolePDA(walletPubkey)[0];
+    return this.program.methods
+      .revokeWalletRole()
+      .accountsStrict({
+        walletRole: walletRolePubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        accessControl: this.accessControlPubkey,
+        securityToken: this.mintPubkey,
+        userWallet: walletPubkey,
+        payer: signer.publicKey,
+        rentReceiver: rentReceiverPubkey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([signer])
+      .rpc({ commitment: this.commitment });
+  }
+
   async freezeWallet(
     walletPubkey: PublicKey,
     userWalletAssociatedAccountPubkey: PublicKey,
-    signer: Keypair
+    signer: Keypair,
+    reason = ""
   ): Promise<string> {
     const authorityWalletRolePubkey = this.walletRolePDA(signer.publicKey)[0];
 
     return this.program.methods
-      .freezeWallet()
+      .freezeWallet(reason)
       .accountsStrict({
         authority: signer.publicKey,
         authorityWalletRole: authorityWalletRolePubkey,
@@ -239,12 +389,13 @@ export class AccessControlHelper {
   async thawWallet(
     walletPubkey: PublicKey,
     userWalletAssociatedAccountPubkey: PublicKey,
-    signer: Keypair
+    signer: Keypair,
+    reason = ""
   ): Promise<string> {
     const authorityWalletRolePubkey = this.walletRolePDA(signer.publicKey)[0];
 
     return this.program.methods
-      .thawWallet()
+      .thawWallet(reason)
       .accountsStrict({
         authority: signer.publicKey,
         authorityWalletRole: authorityWalletRolePubkey,
@@ -265,24 +416,29 @@ export class AccessControlHelper {
     toOwnerPubkey: PublicKey,
     toAccountPubkey: PublicKey,
     signer: Keypair,
-    connection: Connection
+    connection: Connection,
+    reason = ""
   ): Promise<string> {
     const reserveAdminRolePubkey = this.walletRolePDA(signer.publicKey)[0];
 
     const forceTransferBetweenInstruction =
-      this.program.instruction.forceTransferBetween(new BN(amount.toString()), {
-        accounts: {
-          authority: signer.publicKey,
-          authorityWalletRole: reserveAdminRolePubkey,
-          accessControlAccount: this.accessControlPubkey,
-          securityMint: this.mintPubkey,
-          sourceAccount: fromAccountPubkey,
-          sourceAuthority: fromOwnerPubkey,
-          destinationAccount: toAccountPubkey,
-          destinationAuthority: toOwnerPubkey,
-          tokenProgram: TOKEN_2022_PROGRAM_ID,
-        },
-      });
+      this.program.instruction.forceTransferBetween(
+        new BN(amount.toString()),
+        reason,
+        {
+          accounts: {
+            authority: signer.publicKey,
+            authorityWalletRole: reserveAdminRolePubkey,
+            accessControlAccount: this.accessControlPubkey,
+            securityMint: this.mintPubkey,
+            sourceAccount: fromAccountPubkey,
+            sourceAuthority: fromOwnerPubkey,
+            destinationAccount: toAccountPubkey,
+            destinationAuthority: toOwnerPubkey,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          },
+        }
+      );
 
     const mintInfo = await getMint(
       connection,
@@ -341,4 +497,305 @@ export class AccessControlHelper {
       .signers([signer])
       .rpc({ commitment: this.commitment });
   }
+
+  async proposeAuthority(
+    newAuthorityPubkey: PublicKey,
+    authority: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .proposeAuthority(newAuthorityPubkey)
+      .accountsStrict({
+        accessControlAccount: this.accessControlPubkey,
+        mint: this.mintPubkey,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async acceptAuthority(
+    authority: Keypair,
+    newAuthority: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .acceptAuthority()
+      .accountsStrict({
+        accessControlAccount: this.accessControlPubkey,
+        mint: this.mintPubkey,
+        authority: authority.publicKey,
+        newAuthority: newAuthority.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([authority, newAuthority])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async updateTokenMetadata(
+    args: {
+      name: string | null;
+      symbol: string | null;
+      uri: string | null;
+      additionalMetadata: { key: string; value: string }[];
+    },
+    authority: Keypair,
+    payer: Keypair = authority
+  ): Promise<string> {
+    const authorityWalletRolePubkey = this.walletRolePDA(
+      authority.publicKey
+    )[0];
+    return this.program.methods
+      .updateTokenMetadata(args)
+      .accountsStrict({
+        accessControlAccount: this.accessControlPubkey,
+        mint: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: authority.publicKey,
+        payer: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers(payer === authority ? [authority] : [authority, payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async setApprovalThreshold(
+    actionType: any,
+    threshold: number,
+    signer: Keypair
+  ): Promise<string> {
+    const authorityWalletRolePubkey = this.walletRolePDA(signer.publicKey)[0];
+    return this.program.methods
+      .setApprovalThreshold(actionType, threshold)
+      .accountsStrict({
+        accessControlAccount: this.accessControlPubkey,
+        mint: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        payer: signer.publicKey,
+      })
+      .signers([signer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async createProposal(
+    action: any,
+    signer: Keypair,
+    reason = "",
+    expiresAt = new BN(Math.floor(Date.now() / 1000) + 86400)
+  ): Promise<[PublicKey, string]> {
+    const { proposalsCount } = await this.accessControlData();
+    const [proposalPubkey] = this.proposalPDA(proposalsCount);
+    const authorityWalletRolePubkey = this.walletRolePDA(signer.publicKey)[0];
+    const txSignature = await this.program.methods
+      .createProposal(action, reason, expiresAt)
+      .accountsStrict({
+        proposal: proposalPubkey,
+        accessControl: this.accessControlPubkey,
+        securityMint: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        payer: signer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([signer])
+      .rpc({ commitment: this.commitment });
+    return [proposalPubkey, txSignature];
+  }
+
+  async approveProposal(
+    proposalPubkey: PublicKey,
+    signer: Keypair
+  ): Promise<string> {
+    const authorityWalletRolePubkey = this.walletRolePDA(signer.publicKey)[0];
+    return this.program.methods
+      .approveProposal()
+      .accountsStrict({
+        proposal: proposalPubkey,
+        accessControl: this.accessControlPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: signer.publicKey,
+      })
+      .signers([signer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async cancelProposal(
+    proposalPubkey: PublicKey,
+    signer: Keypair
+  ): Promise<string> {
+    const { proposer } = await this.proposalData(proposalPubkey);
+    const authorityWalletRolePubkey = this.walletRolePDA(signer.publicKey)[0];
+    return this.program.methods
+      .cancelProposal()
+      .accountsStrict({
+        proposal: proposalPubkey,
+        proposer,
+        accessControl: this.accessControlPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        authority: signer.publicKey,
+      })
+      .signers([signer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async executeMintSecuritiesProposal(
+    proposalPubkey: PublicKey,
+    userWalletPubkey: PublicKey,
+    userWalletAssociatedAccountPubkey: PublicKey,
+    executor: Keypair
+  ): Promise<string> {
+    const { proposer, approvals } = await this.proposalData(proposalPubkey);
+    return this.program.methods
+      .executeMintSecuritiesProposal()
+      .accountsStrict({
+        proposal: proposalPubkey,
+        proposer,
+        accessControl: this.accessControlPubkey,
+        securityMint: this.mintPubkey,
+        destinationAccount: userWalletAssociatedAccountPubkey,
+        destinationAuthority: userWalletPubkey,
+        executor: executor.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts([
+        ...this.approverWalletRoleAccounts(approvals),
+        ...(await this.holderBalanceAccounts(
+          userWalletAssociatedAccountPubkey
+        )),
+      ])
+      .signers([executor])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async executeBurnSecuritiesProposal(
+    proposalPubkey: PublicKey,
+    userWalletPubkey: PublicKey,
+    userWalletAssociatedAccountPubkey: PublicKey,
+    executor: Keypair
+  ): Promise<string> {
+    const { proposer, approvals } = await this.proposalData(proposalPubkey);
+    return this.program.methods
+      .executeBurnSecuritiesProposal()
+      .accountsStrict({
+        proposal: proposalPubkey,
+        proposer,
+        accessControl: this.accessControlPubkey,
+        securityMint: this.mintPubkey,
+        targetAccount: userWalletAssociatedAccountPubkey,
+        targetAuthority: userWalletPubkey,
+        executor: executor.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts([
+        ...this.approverWalletRoleAccounts(approvals),
+        ...(await this.holderBalanceAccounts(
+          userWalletAssociatedAccountPubkey
+        )),
+      ])
+      .signers([executor])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async executeForceTransferBetweenProposal(
+    proposalPubkey: PublicKey,
+    amount: number | bigint,
+    fromOwnerPubkey: PublicKey,
+    fromAccountPubkey: PublicKey,
+    toOwnerPubkey: PublicKey,
+    toAccountPubkey: PublicKey,
+    executor: Keypair,
+    connection: Connection
+  ): Promise<string> {
+    const { proposer, approvals } = await this.proposalData(proposalPubkey);
+    const executeInstruction = await this.program.methods
+      .executeForceTransferBetweenProposal()
+      .accountsStrict({
+        proposal: proposalPubkey,
+        proposer,
+        sourceAccount: fromAccountPubkey,
+        securityMint: this.mintPubkey,
+        destinationAccount: toAccountPubkey,
+        accessControl: this.accessControlPubkey,
+        sourceAuthority: fromOwnerPubkey,
+        destinationAuthority: toOwnerPubkey,
+        executor: executor.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(this.approverWalletRoleAccounts(approvals))
+      .instruction();
+
+    const mintInfo = await getMint(
+      connection,
+      this.mintPubkey,
+      this.commitment,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const transferHook = getTransferHook(mintInfo);
+
+    await addExtraAccountMetasForExecute(
+      connection,
+      executeInstruction,
+      transferHook.programId,
+      fromAccountPubkey,
+      this.mintPubkey,
+      toAccountPubkey,
+      fromOwnerPubkey,
+      amount,
+      this.commitment
+    );
+
+    const modifyComputeUnitsInstruction =
+      ComputeBudgetProgram.setComputeUnitLimit({
+        units: 400000,
+      });
+
+    return sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(modifyComputeUnitsInstruction, executeInstruction),
+      [executor],
+      { commitment: this.commitment }
+    );
+  }
+
+  async executeSetMaxTotalSupplyProposal(
+    proposalPubkey: PublicKey,
+    executor: Keypair
+  ): Promise<string> {
+    const { proposer, approvals } = await this.proposalData(proposalPubkey);
+    return this.program.methods
+      .executeSetMaxTotalSupplyProposal()
+      .accountsStrict({
+        proposal: proposalPubkey,
+        proposer,
+        accessControl: this.accessControlPubkey,
+        mint: this.mintPubkey,
+        executor: executor.publicKey,
+      })
+      .remainingAccounts(this.approverWalletRoleAccounts(approvals))
+      .signers([executor])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async migrateAccessControl(
+    walletPubkeys: PublicKey[],
+    signer: Keypair
+  ): Promise<string> {
+    const authorityWalletRolePubkey = this.walletRolePDA(signer.publicKey)[0];
+    return this.program.methods
+      .migrateAccessControl()
+      .accountsStrict({
+        accessControl: this.accessControlPubkey,
+        securityToken: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        payer: signer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(
+        walletPubkeys.map((walletPubkey) => ({
+          pubkey: this.walletRolePDA(walletPubkey)[0],
+          isWritable: false,
+          isSigner: false,
+        }))
+      )
+      .signers([signer])
+      .rpc({ commitment: this.commitment });
+  }
 }
@@ -20,8 +20,12 @@ import {
   getTransferHook,
   TOKEN_2022_PROGRAM_ID,
 } from "@solana/spl-token";
-import { TransferRestrictionsHelper } from "./transfer-restrictions_helper";
+import {
+  ANY_TRANSFER_GROUP_ID,
+  TransferRestrictionsHelper,
+} from "./transfer-restrictions_helper";
 import { AccessControl } from "../../target/types/access_control";
+import { holderBalanceAccounts } from "./access-control_helper";
 
 export function uuidBytes(): number[] {
   const uuid = uuidv4().replace(/-/g, "");
@@ -598,6 +602,11 @@ export async function mintReleaseSchedule(
             tokenProgram: TOKEN_2022_PROGRAM_ID,
             accessControlProgram: accessControlProgramId,
           },
+          remainingAccounts: await holderBalanceAccounts(
+            connection,
+            mintPubkey,
+            escrowAccount
+          ),
           signers: [signer],
         }
       );
@@ -677,6 +686,12 @@ export async function withdraw(
     secAssocAccountFromData.group,
     secAssocAccountToData.group
   );
+  const [transferRestrictionGroupFrom] = transferRestrictionsHelper.groupPDA(
+    secAssocAccountFromData.group
+  );
+  const [transferRestrictionGroupTo] = transferRestrictionsHelper.groupPDA(
+    secAssocAccountToData.group
+  );
   const transferInstruction = program.instruction.transfer(amount, {
     accounts: {
       tokenlockAccount,
@@ -692,6 +707,18 @@ export async function withdraw(
       securityAssociatedAccountFrom,
       securityAssociatedAccountTo,
       transferRule: transferRulePubkey,
+      transferRestrictionGroupFrom,
+      transferRestrictionGroupTo,
+      transferRestrictionHolderFrom: secAssocAccountFromData.holder,
+      transferRestrictionHolderTo: secAssocAccountToData.holder,
+      transferRuleFromGroupToAny: transferRestrictionsHelper.transferRulePDA(
+        secAssocAccountFromData.group,
+        ANY_TRANSFER_GROUP_ID
+      )[0],
+      transferRuleFromAnyToGroup: transferRestrictionsHelper.transferRulePDA(
+        ANY_TRANSFER_GROUP_ID,
+        secAssocAccountToData.group
+      )[0],
     },
     signers: [signer],
   });
@@ -825,6 +852,12 @@ export async function batchMintReleaseSchedule(
     TOKEN_2022_PROGRAM_ID
   );
 
+  const escrowHolderBalanceAccounts = await holderBalanceAccounts(
+    program.provider.connection,
+    mintPubkey,
+    escrowAccount
+  );
+
   // making instructions
   const instructions = [];
   const count = amount.length;
@@ -850,6 +883,7 @@ export async function batchMintReleaseSchedule(
           tokenProgram: TOKEN_2022_PROGRAM_ID,
           accessControlProgram: accessControlProgram.programId,
         },
+        remainingAccounts: escrowHolderBalanceAccounts,
         signers: [signer],
       }
     );
@@ -921,6 +955,12 @@ export async function cancelTimelock(
     secAssocAccountFromData.group,
     secAssocAccountToData.group
   );
+  const [transferRestrictionGroupFrom] = transferRestrictionsHelper.groupPDA(
+    secAssocAccountFromData.group
+  );
+  const [transferRestrictionGroupTo] = transferRestrictionsHelper.groupPDA(
+    secAssocAccountToData.group
+  );
 
   if (accInfo === null) {
     return null;
@@ -946,6 +986,18 @@ export async function cancelTimelock(
           securityAssociatedAccountFrom,
           securityAssociatedAccountTo,
           transferRule: transferRulePubkey,
+          transferRestrictionGroupFrom,
+          transferRestrictionGroupTo,
+          transferRestrictionHolderFrom: secAssocAccountFromData.holder,
+          transferRestrictionHolderTo: secAssocAccountToData.holder,
+          transferRuleFromGroupToAny: transferRestrictionsHelper.transferRulePDA(
+            secAssocAccountFromData.group,
+            ANY_TRANSFER_GROUP_ID
+          )[0],
+          transferRuleFromAnyToGroup: transferRestrictionsHelper.transferRulePDA(
+            ANY_TRANSFER_GROUP_ID,
+            secAssocAccountToData.group
+          )[0],
         },
         signers: [signer],
       }
@@ -1,16 +1,26 @@
-import { BN, Program } from "@coral-xyz/anchor";
+import { BN, Event, EventParser, Program } from "@coral-xyz/anchor";
 import { Keypair, PublicKey, SystemProgram, Commitment } from "@solana/web3.js";
 import { TransferRestrictions } from "../../target/types/transfer_restrictions";
 import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { sha256 } from "js-sha256";
 
 export const EXTRA_METAS_ACCOUNT_PREFIX = "extra-account-metas";
 export const TRANSFER_RESTRICTION_GROUP_PREFIX = "trg";
 export const TRANSFER_RESTRICTION_DATA_PREFIX = "trd";
 export const TRANSFER_RULE_PREFIX = "tr";
+export const ANY_TRANSFER_GROUP_ID = new BN("18446744073709551615"); // u64::MAX
 export const SECURITY_ASSOCIATED_ACCOUNT_PREFIX = "saa"; // security associated account
 export const TRANSFER_RESTRICTION_HOLDER_PREFIX = "trh"; // transfer_restriction_holder
+export const DERIVED_HOLDER_ID_FLAG = new BN(1).shln(63);
 export const TRANSFER_RESTRICTION_HOLDER_GROUP_PREFIX = "trhg"; // transfer_restriction_holder_group
 
+export enum GroupFlags {
+  None = 0,
+  AccreditedOnly = 1,
+  Lockup = 2,
+  All = 3,
+}
+
 export class TransferRestrictionsHelper {
   program: Program<TransferRestrictions>;
   mintPubkey: PublicKey;
@@ -59,6 +69,18 @@ export class TransferRestrictionsHelper {
     );
   }
 
+  async parseEvents(txSignature: string): Promise<Event[]> {
+    const tx = await this.program.provider.connection.getTransaction(
+      txSignature,
+      { commitment: "confirmed", maxSupportedTransactionVersion: 0 }
+    );
+    const eventParser = new EventParser(
+      this.program.programId,
+      this.program.coder
+    );
+    return Array.from(eventParser.parseLogs(tx.meta.logMessages));
+  }
+
   async groupData(groupPubkey: PublicKey): Promise<any> {
     return this.program.account.transferRestrictionGroup.fetch(
       groupPubkey,
@@ -77,6 +99,15 @@ export class TransferRestrictionsHelper {
     );
   }
 
+  holderIdFromReference(reference: Buffer): BN {
+    const hash = sha256
+      .create()
+      .update(Buffer.from(TRANSFER_RESTRICTION_HOLDER_PREFIX))
+      .update(reference)
+      .array();
+    return new BN(hash.slice(0, 8), "le").or(DERIVED_HOLDER_ID_FLAG);
+  }
+
   async holderData(holderPubkey: PublicKey): Promise<any> {
     return this.program.account.transferRestrictionHolder.fetch(
       holderPubkey,
@@ -114,6 +145,11 @@ export class TransferRestrictionsHelper {
     );
   }
 
+  // Wildcard transfer rules are initialized without the group account
+  transferRuleGroupPubkey(groupId: BN): PublicKey | null {
+    return groupId.eq(ANY_TRANSFER_GROUP_ID) ? null : this.groupPDA(groupId)[0];
+  }
+
   async transferRuleData(transferRulePubkey: PublicKey): Promise<any> {
     return this.program.account.transferRule.fetch(
       transferRulePubkey,
@@ -219,8 +255,10 @@ export class TransferRestrictionsHelper {
       transferGroupFromId,
       transferGroupToId
     );
-    const [transferGroupFromPubkey] = this.groupPDA(transferGroupFromId);
-    const [transferGroupToPubkey] = this.groupPDA(transferGroupToId);
+    const transferGroupFromPubkey =
+      this.transferRuleGroupPubkey(transferGroupFromId);
+    const transferGroupToPubkey =
+      this.transferRuleGroupPubkey(transferGroupToId);
 
     return this.program.methods
       .initializeTransferRule(lockedUntil)
@@ -259,6 +297,29 @@ export class TransferRestrictionsHelper {
       .rpc({ commitment: this.commitment });
   }
 
+  async initializeTransferRestrictionHolderFromReference(
+    reference: Buffer,
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    const [holderPubkey] = this.holderPDA(
+      this.holderIdFromReference(reference)
+    );
+
+    return this.program.methods
+      .initializeTransferRestrictionHolderFromReference([...reference])
+      .accountsStrict({
+        transferRestrictionHolder: holderPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        payer: payer.publicKey,
+        accessControlAccount: this.accessControlPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
   async initializeSecurityAssociatedAccount(
     groupPubkey: PublicKey,
     holderPubkey: PublicKey,
@@ -291,6 +352,78 @@ export class TransferRestrictionsHelper {
       .rpc({ commitment: this.commitment });
   }
 
+  async provisionInvestor(
+    holderId: BN,
+    groupId: BN,
+    userWalletPubkey: PublicKey,
+    userWalletAssociatedAccountPubkey: PublicKey,
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    const [holderPubkey] = this.holderPDA(holderId);
+
+    return this.program.methods
+      .provisionInvestor(holderId)
+      .accountsStrict({
+        holder: holderPubkey,
+        holderGroup: this.holderGroupPDA(holderPubkey, groupId)[0],
+        securityAssociatedAccount: this.securityAssociatedAccountPDA(
+          userWalletAssociatedAccountPubkey
+        )[0],
+        group: this.groupPDA(groupId)[0],
+        securityToken: this.mintPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        userWallet: userWalletPubkey,
+        associatedTokenAccount: userWalletAssociatedAccountPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        payer: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async provisionInvestors(
+    holderIds: BN[],
+    groupId: BN,
+    userWalletAssociatedAccountPubkeys: PublicKey[],
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    const remainingAccounts = holderIds.flatMap((holderId, index) => {
+      const [holderPubkey] = this.holderPDA(holderId);
+      const tokenAccount = userWalletAssociatedAccountPubkeys[index];
+      return [
+        { pubkey: holderPubkey, isSigner: false, isWritable: true },
+        {
+          pubkey: this.holderGroupPDA(holderPubkey, groupId)[0],
+          isSigner: false,
+          isWritable: true,
+        },
+        { pubkey: tokenAccount, isSigner: false, isWritable: false },
+        {
+          pubkey: this.securityAssociatedAccountPDA(tokenAccount)[0],
+          isSigner: false,
+          isWritable: true,
+        },
+      ];
+    });
+
+    return this.program.methods
+      .provisionInvestors(holderIds)
+      .accountsStrict({
+        group: this.groupPDA(groupId)[0],
+        securityToken: this.mintPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        payer: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
   async initializeHolderGroup(
     holderGroupPubkey: PublicKey,
     holderPubkey: PublicKey,
@@ -324,6 +457,9 @@ export class TransferRestrictionsHelper {
     userTokenAccountPubkey: PublicKey,
     payer: Keypair
   ): Promise<string> {
+    const { holder } = await this.securityAssociatedAccountData(
+      userWalletSecAssociatedAccountPubkey
+    );
     return this.program.methods
       .updateWalletGroup()
       .accountsStrict({
@@ -334,6 +470,7 @@ export class TransferRestrictionsHelper {
         transferRestrictionGroupNew: groupNewPubkey,
         holderGroupCurrent: holderGroupCurrentPubkey,
         holderGroupNew: holderGroupNewPubkey,
+        holder,
         authorityWalletRole,
         userWallet: userWalletPubkey,
         userAssociatedTokenAccount: userTokenAccountPubkey,
@@ -344,6 +481,101 @@ export class TransferRestrictionsHelper {
       .rpc({ commitment: this.commitment });
   }
 
+  async updateWalletHolder(
+    groupId: BN,
+    holderCurrentPubkey: PublicKey,
+    holderNewPubkey: PublicKey,
+    authorityWalletRole: PublicKey,
+    userWalletPubkey: PublicKey,
+    userTokenAccountPubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    const [groupPubkey] = this.groupPDA(groupId);
+    const [holderGroupCurrentPubkey] = this.holderGroupPDA(
+      holderCurrentPubkey,
+      groupId
+    );
+    const [holderGroupNewPubkey] = this.holderGroupPDA(
+      holderNewPubkey,
+      groupId
+    );
+
+    return this.program.methods
+      .updateWalletHolder()
+      .accountsStrict({
+        securityAssociatedAccount: this.securityAssociatedAccountPDA(
+          userTokenAccountPubkey
+        )[0],
+        securityToken: this.mintPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        group: groupPubkey,
+        holderCurrent: holderCurrentPubkey,
+        holderNew: holderNewPubkey,
+        holderGroupCurrent: holderGroupCurrentPubkey,
+        holderGroupNew: holderGroupNewPubkey,
+        authorityWalletRole,
+        userWallet: userWalletPubkey,
+        userAssociatedTokenAccount: userTokenAccountPubkey,
+        payer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async renewWalletsValidity(
+    validUntil: BN,
+    tokenAccountPubkeys: PublicKey[],
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    const remainingAccounts = tokenAccountPubkeys.flatMap((tokenAccount) => [
+      { pubkey: tokenAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: this.securityAssociatedAccountPDA(tokenAccount)[0],
+        isSigner: false,
+        isWritable: true,
+      },
+    ]);
+
+    return this.program.methods
+      .renewWalletsValidity(validUntil)
+      .accountsStrict({
+        securityToken: this.mintPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        payer: payer.publicKey,
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async updateWalletTransferBlocks(
+    sendBlocked: boolean,
+    receiveBlocked: boolean,
+    userWalletPubkey: PublicKey,
+    userTokenAccountPubkey: PublicKey,
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    const [securityAssociatedAccountPubkey] =
+      this.securityAssociatedAccountPDA(userTokenAccountPubkey);
+
+    return this.program.methods
+      .updateWalletTransferBlocks(sendBlocked, receiveBlocked)
+      .accountsStrict({
+        securityAssociatedAccount: securityAssociatedAccountPubkey,
+        securityToken: this.mintPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        userWallet: userWalletPubkey,
+        userAssociatedTokenAccount: userTokenAccountPubkey,
+        payer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
   async setHolderMax(
     maxHolders: BN,
     authorityWalletRolePubkey: PublicKey,
@@ -362,6 +594,52 @@ export class TransferRestrictionsHelper {
       .rpc({ commitment: this.commitment });
   }
 
+  async setHoldersOfRecordEnforcement(
+    enforced: boolean,
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .setHoldersOfRecordEnforcement(enforced)
+      .accountsStrict({
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        accessControlAccount: this.accessControlPubkey,
+        mint: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        payer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async setDefaultTransferRule(
+    lockedUntil: BN,
+    lockedAfter: BN,
+    minAmount: BN,
+    maxAmount: BN,
+    lotSize: BN,
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .setDefaultTransferRule(
+        lockedUntil,
+        lockedAfter,
+        minAmount,
+        maxAmount,
+        lotSize
+      )
+      .accountsStrict({
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        accessControlAccount: this.accessControlPubkey,
+        mint: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        payer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
   async setHolderGroupMax(
     maxHolders: BN,
     groupPubkey: PublicKey,
@@ -382,16 +660,368 @@ export class TransferRestrictionsHelper {
       .rpc({ commitment: this.commitment });
   }
 
+  async setGroupMaxBalance(
+    maxBalance: BN,
+    groupPubkey: PublicKey,
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .setGroupMaxBalance(maxBalance)
+      .accountsStrict({
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        accessControlAccount: this.accessControlPubkey,
+        mint: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        group: groupPubkey,
+        payer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async setGroupMetadata(
+    name: string,
+    jurisdiction: string,
+    flags: number,
+    groupPubkey: PublicKey,
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .setGroupMetadata(name, jurisdiction, flags)
+      .accountsStrict({
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        accessControlAccount: this.accessControlPubkey,
+        mint: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        group: groupPubkey,
+        payer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async pauseGroup(
+    paused: boolean,
+    groupPubkey: PublicKey,
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .pauseGroup(paused)
+      .accountsStrict({
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        accessControlAccount: this.accessControlPubkey,
+        mint: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        group: groupPubkey,
+        payer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async schedulePause(
+    pausedFrom: BN,
+    pausedUntil: BN,
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .schedulePause(pausedFrom, pausedUntil)
+      .accountsStrict({
+        securityMint: this.mintPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        accessControlAccount: this.accessControlPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        payer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async setGroupLockup(
+    lockedUntil: BN,
+    frozen: boolean,
+    groupPubkey: PublicKey,
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .setGroupLockup(lockedUntil, frozen)
+      .accountsStrict({
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        accessControlAccount: this.accessControlPubkey,
+        mint: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        group: groupPubkey,
+        payer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async setGroupVelocityLimit(
+    velocityLimit: BN,
+    velocityPeriod: BN,
+    groupPubkey: PublicKey,
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .setGroupVelocityLimit(velocityLimit, velocityPeriod)
+      .accountsStrict({
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        accessControlAccount: this.accessControlPubkey,
+        mint: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        group: groupPubkey,
+        payer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async setHolderMaxBalance(
+    maxBalance: BN,
+    holderPubkey: PublicKey,
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .setHolderMaxBalance(maxBalance)
+      .accountsStrict({
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        accessControlAccount: this.accessControlPubkey,
+        mint: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        holder: holderPubkey,
+        payer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async setHolderActive(
+    active: boolean,
+    holderPubkey: PublicKey,
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .setHolderActive(active)
+      .accountsStrict({
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        accessControlAccount: this.accessControlPubkey,
+        mint: this.mintPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        holder: holderPubkey,
+        payer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async enforceTransferRestrictions(
+    sourceTokenAccountPubkey: PublicKey,
+    destinationTokenAccountPubkey: PublicKey,
+    amount: BN,
+    authority: Keypair | null = null
+  ): Promise<string> {
+    const [securityAssociatedAccountFromPubkey] =
+      this.securityAssociatedAccountPDA(sourceTokenAccountPubkey);
+    const [securityAssociatedAccountToPubkey] =
+      this.securityAssociatedAccountPDA(destinationTokenAccountPubkey);
+    const securityAssociatedAccountFrom =
+      await this.securityAssociatedAccountData(
+        securityAssociatedAccountFromPubkey
+      );
+    const securityAssociatedAccountTo =
+      await this.securityAssociatedAccountData(
+        securityAssociatedAccountToPubkey
+      );
+
+    return this.program.methods
+      .enforceTransferRestrictions(amount)
+      .accountsStrict({
+        sourceAccount: sourceTokenAccountPubkey,
+        mint: this.mintPubkey,
+        destinationAccount: destinationTokenAccountPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        securityAssociatedAccountFrom: securityAssociatedAccountFromPubkey,
+        securityAssociatedAccountTo: securityAssociatedAccountToPubkey,
+        transferRule: this.transferRulePDA(
+          securityAssociatedAccountFrom.group,
+          securityAssociatedAccountTo.group
+        )[0],
+        transferRestrictionGroupFrom: this.groupPDA(
+          securityAssociatedAccountFrom.group
+        )[0],
+        transferRestrictionGroupTo: this.groupPDA(
+          securityAssociatedAccountTo.group
+        )[0],
+        transferRestrictionHolderFrom: securityAssociatedAccountFrom.holder,
+        transferRestrictionHolderTo: securityAssociatedAccountTo.holder,
+        transferRuleFromGroupToAny: this.transferRulePDA(
+          securityAssociatedAccountFrom.group,
+          ANY_TRANSFER_GROUP_ID
+        )[0],
+        transferRuleFromAnyToGroup: this.transferRulePDA(
+          ANY_TRANSFER_GROUP_ID,
+          securityAssociatedAccountTo.group
+        )[0],
+        authority: authority?.publicKey ?? null,
+      })
+      .signers(authority ? [authority] : [])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async detectTransferRestriction(
+    sourceTokenAccountPubkey: PublicKey,
+    destinationTokenAccountPubkey: PublicKey,
+    amount: BN
+  ): Promise<{ code: number; message: string }> {
+    const [securityAssociatedAccountFromPubkey] =
+      this.securityAssociatedAccountPDA(sourceTokenAccountPubkey);
+    const [securityAssociatedAccountToPubkey] =
+      this.securityAssociatedAccountPDA(destinationTokenAccountPubkey);
+    const securityAssociatedAccountFrom =
+      await this.securityAssociatedAccountData(
+        securityAssociatedAccountFromPubkey
+      );
+    const securityAssociatedAccountTo =
+      await this.securityAssociatedAccountData(
+        securityAssociatedAccountToPubkey
+      );
+    const [holderFromPubkey] = this.holderPDA(
+      securityAssociatedAccountFrom.holderId
+    );
+    const [holderToPubkey] = this.holderPDA(
+      securityAssociatedAccountTo.holderId
+    );
+
+    return this.program.methods
+      .detectTransferRestriction(amount)
+      .accountsStrict({
+        sourceAccount: sourceTokenAccountPubkey,
+        mint: this.mintPubkey,
+        destinationAccount: destinationTokenAccountPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        securityAssociatedAccountFrom: securityAssociatedAccountFromPubkey,
+        securityAssociatedAccountTo: securityAssociatedAccountToPubkey,
+        transferRule: this.transferRulePDA(
+          securityAssociatedAccountFrom.group,
+          securityAssociatedAccountTo.group
+        )[0],
+        transferRestrictionHolderFrom: holderFromPubkey,
+        transferRestrictionHolderTo: holderToPubkey,
+        transferRestrictionGroupTo: this.groupPDA(
+          securityAssociatedAccountTo.group
+        )[0],
+        transferRestrictionGroupFrom: this.groupPDA(
+          securityAssociatedAccountFrom.group
+        )[0],
+        holderGroupFrom: this.holderGroupPDA(
+          holderFromPubkey,
+          securityAssociatedAccountFrom.group
+        )[0],
+        holderGroupTo: this.holderGroupPDA(
+          holderToPubkey,
+          securityAssociatedAccountTo.group
+        )[0],
+        transferRuleFromGroupToAny: this.transferRulePDA(
+          securityAssociatedAccountFrom.group,
+          ANY_TRANSFER_GROUP_ID
+        )[0],
+        transferRuleFromAnyToGroup: this.transferRulePDA(
+          ANY_TRANSFER_GROUP_ID,
+          securityAssociatedAccountTo.group
+        )[0],
+      })
+      .view({ commitment: this.commitment });
+  }
+
+  async syncHolderBalance(
+    tokenAccountPubkey: PublicKey,
+    holderPubkey: PublicKey
+  ): Promise<string> {
+    const [securityAssociatedAccountPubkey] =
+      this.securityAssociatedAccountPDA(tokenAccountPubkey);
+    const { group } = await this.securityAssociatedAccountData(
+      securityAssociatedAccountPubkey
+    );
+    const [groupPubkey] = this.groupPDA(group);
+    const [holderGroupPubkey] = this.holderGroupPDA(holderPubkey, group);
+
+    return this.program.methods
+      .syncHolderBalance()
+      .accountsStrict({
+        securityAssociatedAccount: securityAssociatedAccountPubkey,
+        holder: holderPubkey,
+        holderGroup: holderGroupPubkey,
+        group: groupPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        tokenAccount: tokenAccountPubkey,
+      })
+      .rpc({ commitment: this.commitment });
+  }
+
+  async migrateAccount(
+    accountPubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    return this.program.methods
+      .migrateAccount()
+      .accountsStrict({
+        account: accountPubkey,
+        payer: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async migrateSecurityAssociatedAccount(
+    tokenAccountPubkey: PublicKey,
+    holderPubkey: PublicKey | null,
+    group: BN | null,
+    payer: Keypair
+  ): Promise<string> {
+    const [securityAssociatedAccountPubkey] =
+      this.securityAssociatedAccountPDA(tokenAccountPubkey);
+    // lockup escrow accounts are migrated without holder accounts
+    const holderGroupPubkey =
+      holderPubkey && this.holderGroupPDA(holderPubkey, group)[0];
+    const groupPubkey = holderPubkey && this.groupPDA(group)[0];
+
+    return this.program.methods
+      .migrateSecurityAssociatedAccount()
+      .accountsStrict({
+        securityAssociatedAccount: securityAssociatedAccountPubkey,
+        holder: holderPubkey,
+        holderGroup: holderGroupPubkey,
+        group: groupPubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        tokenAccount: tokenAccountPubkey,
+        payer: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
   async setAllowTransferRule(
     lockedUntil: BN,
+    lockedAfter: BN,
     transferRulePubkey: PublicKey,
-    transferRestrictionGroupFromPubkey: PublicKey,
-    transferRestrictionGroupToPubkey: PublicKey,
+    transferRestrictionGroupFromPubkey: PublicKey | null,
+    transferRestrictionGroupToPubkey: PublicKey | null,
     authorityWalletRolePubkey: PublicKey,
     payer: Keypair
   ): Promise<string> {
     return this.program.methods
-      .setAllowTransferRule(lockedUntil)
+      .setAllowTransferRule(lockedUntil, lockedAfter)
       .accountsStrict({
         transferRestrictionData: this.transferRestrictionDataPubkey,
         transferRule: transferRulePubkey,
@@ -400,6 +1030,41 @@ export class TransferRestrictionsHelper {
         accessControlAccount: this.accessControlPubkey,
         authorityWalletRole: authorityWalletRolePubkey,
         payer: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer])
+      .rpc({ commitment: this.commitment });
+  }
+
+  async setTransferRuleAmountLimits(
+    minAmount: BN,
+    maxAmount: BN,
+    lotSize: BN,
+    transferGroupFromId: BN,
+    transferGroupToId: BN,
+    authorityWalletRolePubkey: PublicKey,
+    payer: Keypair
+  ): Promise<string> {
+    const [transferRulePubkey] = this.transferRulePDA(
+      transferGroupFromId,
+      transferGroupToId
+    );
+    const transferGroupFromPubkey =
+      this.transferRuleGroupPubkey(transferGroupFromId);
+    const transferGroupToPubkey =
+      this.transferRuleGroupPubkey(transferGroupToId);
+
+    return this.program.methods
+      .setTransferRuleAmountLimits(minAmount, maxAmount, lotSize)
+      .accountsStrict({
+        transferRule: transferRulePubkey,
+        transferRestrictionData: this.transferRestrictionDataPubkey,
+        transferRestrictionGroupFrom: transferGroupFromPubkey,
+        transferRestrictionGroupTo: transferGroupToPubkey,
+        accessControlAccount: this.accessControlPubkey,
+        authorityWalletRole: authorityWalletRolePubkey,
+        payer: payer.publicKey,
+        systemProgram: SystemProgram.programId,
       })
       .signers([payer])
       .rpc({ commitment: this.commitment });
@@ -410,7 +1075,8 @@ export class TransferRestrictionsHelper {
     userWalletPubkey: PublicKey,
     userWalletAssociatedAccountPubkey: PublicKey,
     authorityWalletRolePubkey: PublicKey,
-    payer: Keypair
+    payer: Keypair,
+    rentReceiverPubkey: PublicKey = payer.publicKey
   ): Promise<string> {
     const userWalletSecAssocAccountData =
       await this.securityAssociatedAccountData(
@@ -434,6 +1100,7 @@ export class TransferRestrictionsHelper {
         associatedTokenAccount: userWalletAssociatedAccountPubkey,
         authorityWalletRole: authorityWalletRolePubkey,
         payer: payer.publicKey,
+        rentReceiver: rentReceiverPubkey,
         systemProgram: SystemProgram.programId,
       })
       .signers([payer])
@@ -443,7 +1110,8 @@ export class TransferRestrictionsHelper {
   async revokeHolder(
     holderPubkey: PublicKey,
     authorityWalletRolePubkey: PublicKey,
-    payer: Keypair
+    payer: Keypair,
+    rentReceiverPubkey: PublicKey = payer.publicKey
   ): Promise<string> {
     return this.program.methods
       .revokeHolder()
@@ -452,6 +1120,7 @@ export class TransferRestrictionsHelper {
         transferRestrictionData: this.transferRestrictionDataPubkey,
         authorityWalletRole: authorityWalletRolePubkey,
         payer: payer.publicKey,
+        rentReceiver: rentReceiverPubkey,
         systemProgram: SystemProgram.programId,
       })
       .signers([payer])
@@ -462,7 +1131,8 @@ export class TransferRestrictionsHelper {
     holderPubkey: PublicKey,
     groupId: BN,
     authorityWalletRolePubkey: PublicKey,
-    payer: Keypair
+    payer: Keypair,
+    rentReceiverPubkey: PublicKey = payer.publicKey
   ): Promise<string> {
     const [holderGroupPubkey] = this.holderGroupPDA(holderPubkey, groupId);
     const [groupPubkey] = this.groupPDA(groupId);
@@ -476,6 +1146,7 @@ export class TransferRestrictionsHelper {
         group: groupPubkey,
         authorityWalletRole: authorityWalletRolePubkey,
         payer: payer.publicKey,
+        rentReceiver: rentReceiverPubkey,
         systemProgram: SystemProgram.programId,
       })
       .signers([payer])
@@ -35,6 +35,7 @@ import {
   uuidBytes,
 } from "./helpers/tokenlock_helper";
 import { getNowTs } from "./helpers/clock_helper";
+import { ANY_TRANSFER_GROUP_ID } from "./helpers/transfer-restrictions_helper";
 
 describe("token lockup", () => {
   const testEnvironmentParams: TestEnvironmentParams = {
@@ -366,6 +367,10 @@ describe("token lockup", () => {
             accessControlProgram:
               testEnvironment.accessControlHelper.program.programId,
           },
+          remainingAccounts:
+            await testEnvironment.accessControlHelper.holderBalanceAccounts(
+              escrowAccount
+            ),
           signers: [testEnvironment.reserveAdmin],
         }
       );
@@ -631,6 +636,20 @@ describe("token lockup", () => {
               investorTokenAccountPubkey
             )[0],
           transferRule: transferRulePubkey,
+          transferRestrictionGroupFrom: investorGroupPubkey,
+          transferRestrictionGroupTo: investorGroupPubkey,
+          transferRestrictionHolderFrom: investorHolderPubkey,
+          transferRestrictionHolderTo: investorHolderPubkey,
+          transferRuleFromGroupToAny:
+            testEnvironment.transferRestrictionsHelper.transferRulePDA(
+              investorGroupId,
+              ANY_TRANSFER_GROUP_ID
+            )[0],
+          transferRuleFromAnyToGroup:
+            testEnvironment.transferRestrictionsHelper.transferRulePDA(
+              ANY_TRANSFER_GROUP_ID,
+              investorGroupId
+            )[0],
         },
         signers: [investor],
       }
@@ -755,6 +774,20 @@ describe("token lockup", () => {
                 investorTokenAccountPubkey
               )[0],
             transferRule: transferRulePubkey,
+            transferRestrictionGroupFrom: investorGroupPubkey,
+            transferRestrictionGroupTo: investorGroupPubkey,
+            transferRestrictionHolderFrom: investorHolderPubkey,
+            transferRestrictionHolderTo: investorHolderPubkey,
+            transferRuleFromGroupToAny:
+              testEnvironment.transferRestrictionsHelper.transferRulePDA(
+                investorGroupId,
+                ANY_TRANSFER_GROUP_ID
+              )[0],
+            transferRuleFromAnyToGroup:
+              testEnvironment.transferRestrictionsHelper.transferRulePDA(
+                ANY_TRANSFER_GROUP_ID,
+                investorGroupId
+              )[0],
           },
           signers: [investor],
         }
@@ -872,6 +905,20 @@ describe("token lockup", () => {
                 newinvestorTokenAccountPubkey
               )[0],
             transferRule: transferRulePubkey,
+            transferRestrictionGroupFrom: investorGroupPubkey,
+            transferRestrictionGroupTo: investorGroupPubkey,
+            transferRestrictionHolderFrom: investorHolderPubkey,
+            transferRestrictionHolderTo: investorHolderPubkey,
+            transferRuleFromGroupToAny:
+              testEnvironment.transferRestrictionsHelper.transferRulePDA(
+                investorGroupId,
+                ANY_TRANSFER_GROUP_ID
+              )[0],
+            transferRuleFromAnyToGroup:
+              testEnvironment.transferRestrictionsHelper.transferRulePDA(
+                ANY_TRANSFER_GROUP_ID,
+                investorGroupId
+              )[0],
           },
           signers: [investor],
         }
@@ -902,6 +949,158 @@ describe("token lockup", () => {
     console.log("Transfer Timelock Transaction Signature", transferTxSignature);
   });
 
+  it("fails to transfer from timelock to a new holder beyond max holders", async () => {
+    const newHolderId = new anchor.BN(3);
+    const [newHolderPubkey] =
+      testEnvironment.transferRestrictionsHelper.holderPDA(newHolderId);
+    const [newHolderGroupPubkey] =
+      testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+        newHolderPubkey,
+        investorGroupId
+      );
+    const newHolderWallet = anchor.web3.Keypair.generate();
+    const newHolderTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        newHolderWallet.publicKey,
+        investor
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionHolder(
+      newHolderId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    await testEnvironment.transferRestrictionsHelper.initializeHolderGroup(
+      newHolderGroupPubkey,
+      newHolderPubkey,
+      investorGroupPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccount(
+      investorGroupPubkey,
+      newHolderPubkey,
+      newHolderGroupPubkey,
+      newHolderWallet.publicKey,
+      newHolderTokenAccountPubkey,
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      )[0],
+      testEnvironment.walletsAdmin
+    );
+
+    await testEnvironment.transferRestrictionsHelper.setHoldersOfRecordEnforcement(
+      true,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const { currentHoldersOfRecordCount } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    await testEnvironment.transferRestrictionsHelper.setHolderMax(
+      currentHoldersOfRecordCount,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    const timelockAccount = getTimelockAccount(
+      tokenlockProgram.programId,
+      tokenlockDataPubkey,
+      investor.publicKey
+    );
+    const transferTimelockAmount = new anchor.BN(1);
+    const timelockId = 0;
+    const transferTimelockInstruction =
+      tokenlockProgram.instruction.transferTimelock(
+        transferTimelockAmount,
+        timelockId,
+        {
+          accounts: {
+            tokenlockAccount: tokenlockDataPubkey,
+            timelockAccount,
+            escrowAccount: escrowAccount,
+            pdaAccount: escrowOwnerPubkey,
+            authority: investor.publicKey,
+            to: newHolderTokenAccountPubkey,
+            mintAddress: testEnvironment.mintKeypair.publicKey,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            transferRestrictionsProgram:
+              testEnvironment.transferRestrictionsHelper.program.programId,
+            authorityAccount:
+              testEnvironment.mintHelper.getAssocciatedTokenAddress(
+                investor.publicKey
+              ),
+            securityAssociatedAccountFrom:
+              testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+                investorTokenAccountPubkey
+              )[0],
+            securityAssociatedAccountTo:
+              testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+                newHolderTokenAccountPubkey
+              )[0],
+            transferRule: transferRulePubkey,
+            transferRestrictionGroupFrom: investorGroupPubkey,
+            transferRestrictionGroupTo: investorGroupPubkey,
+            transferRestrictionHolderFrom: investorHolderPubkey,
+            transferRestrictionHolderTo: newHolderPubkey,
+            transferRuleFromGroupToAny:
+              testEnvironment.transferRestrictionsHelper.transferRulePDA(
+                investorGroupId,
+                ANY_TRANSFER_GROUP_ID
+              )[0],
+            transferRuleFromAnyToGroup:
+              testEnvironment.transferRestrictionsHelper.transferRulePDA(
+                ANY_TRANSFER_GROUP_ID,
+                investorGroupId
+              )[0],
+          },
+          signers: [investor],
+        }
+      );
+    const mintInfo = await testEnvironment.mintHelper.getMint();
+    const transferHook = getTransferHook(mintInfo);
+    assert.ok(transferHook);
+
+    await addExtraAccountMetasForExecute(
+      testEnvironment.connection,
+      transferTimelockInstruction,
+      transferHook.programId,
+      escrowAccount,
+      testEnvironment.mintKeypair.publicKey,
+      newHolderTokenAccountPubkey,
+      escrowOwnerPubkey,
+      transferTimelockAmount.toNumber(),
+      testEnvironment.commitment
+    );
+
+    try {
+      await sendAndConfirmTransaction(
+        testEnvironment.connection,
+        new Transaction().add(transferTimelockInstruction),
+        [investor],
+        { commitment: testEnvironment.commitment }
+      );
+      assert.fail("Expect an error");
+    } catch (error) {
+      assert.isTrue(
+        error.logs.some(
+          (log: string) =>
+            log ===
+            "Program log: AnchorError occurred. Error Code: MaxHoldersReached. Error Number: 6001. Error Message: Max holders reached."
+        )
+      );
+    }
+
+    await testEnvironment.transferRestrictionsHelper.setHolderMax(
+      new anchor.BN(testEnvironmentParams.maxHolders),
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    await testEnvironment.transferRestrictionsHelper.setHoldersOfRecordEnforcement(
+      false,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+  });
+
   it("cancel timelock from timelock", async () => {
     const timelockAccount = getTimelockAccount(
       tokenlockProgram.programId,
@@ -20,7 +20,10 @@ import { assert, expect } from "chai";
 import { solToLamports, topUpWallet } from "./utils";
 import { AccessControlHelper, Roles } from "./helpers/access-control_helper";
 
-import { TransferRestrictionsHelper } from "./helpers/transfer-restrictions_helper";
+import {
+  ANY_TRANSFER_GROUP_ID,
+  TransferRestrictionsHelper,
+} from "./helpers/transfer-restrictions_helper";
 import { MintHelper } from "./helpers/mint_helper";
 import { getNowTs } from "./helpers/clock_helper";
 
@@ -271,7 +274,7 @@ describe("solana-security-token", () => {
   it("fails to mint more than max total supply", async () => {
     const mintAmount = setupAccessControlArgs.maxTotalSupply.addn(1);
     try {
-      await accessControlProgram.rpc.mintSecurities(mintAmount, {
+      await accessControlProgram.rpc.mintSecurities(mintAmount, "", {
         accounts: {
           authority: superAdmin.publicKey,
           authorityWalletRole: authorityWalletRolePubkey,
@@ -865,7 +868,7 @@ describe("solana-security-token", () => {
     assert.equal(assAccountInfo.isFrozen, true);
 
     const freezeTx = await accessControlProgram.methods
-      .thawWallet()
+      .thawWallet("")
       .accountsStrict({
         authority: superAdmin.publicKey,
         authorityWalletRole: authorityWalletRolePubkey,
@@ -926,7 +929,7 @@ describe("solana-security-token", () => {
     );
     try {
       await transferRestrictionsProgram.methods
-        .enforceTransferRestrictions()
+        .enforceTransferRestrictions(new anchor.BN(1_000))
         .accountsStrict({
           sourceAccount: userWalletAssociatedAccountPubkey,
           destinationAccount: userWalletRecipientAssociatedTokenAccountPubkey,
@@ -937,6 +940,19 @@ describe("solana-security-token", () => {
           securityAssociatedAccountTo:
             userWalletRecipientSecurityAssociatedTokenAccountPubkey,
           transferRule: transferRulePubkey,
+          transferRestrictionGroupFrom: transferRestrictionGroup1Pubkey,
+          transferRestrictionGroupTo: transferRestrictionGroup1Pubkey,
+          transferRestrictionHolderFrom: holderSenderPubkey,
+          transferRestrictionHolderTo: holderRecipientPubkey,
+          transferRuleFromGroupToAny: transferRestrictionsHelper.transferRulePDA(
+            transferGroup1,
+            ANY_TRANSFER_GROUP_ID
+          )[0],
+          transferRuleFromAnyToGroup: transferRestrictionsHelper.transferRulePDA(
+            ANY_TRANSFER_GROUP_ID,
+            transferGroup1
+          )[0],
+          authority: null,
         })
         .signers([])
         .rpc({ commitment: commitment });
@@ -1280,6 +1296,7 @@ describe("solana-security-token", () => {
     const setLockedUntilTx =
       await transferRestrictionsHelper.setAllowTransferRule(
         lockedUntil,
+        new anchor.BN(0),
         transferRulePubkey,
         transferRestrictionGroup2Pubkey,
         transferRestrictionGroup1Pubkey,
@@ -22,6 +22,7 @@ import {
 import { fromDaysToSeconds } from "../helpers/datetime";
 import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
 import { getNowTs } from "../helpers/clock_helper";
+import { ANY_TRANSFER_GROUP_ID } from "../helpers/transfer-restrictions_helper";
 
 describe("TokenLockup tokenlock discriminator tests", () => {
   const testEnvironmentParams: TestEnvironmentParams = {
@@ -462,6 +463,20 @@ describe("TokenLockup tokenlock discriminator tests", () => {
           securityAssociatedAccountFrom,
           securityAssociatedAccountTo,
           transferRule: transferRulePubkey,
+          transferRestrictionGroupFrom: groupPubkey,
+          transferRestrictionGroupTo: groupPubkey,
+          transferRestrictionHolderFrom: holderPubkey,
+          transferRestrictionHolderTo: holderPubkey,
+          transferRuleFromGroupToAny:
+            testEnvironment.transferRestrictionsHelper.transferRulePDA(
+              groupId,
+              ANY_TRANSFER_GROUP_ID
+            )[0],
+          transferRuleFromAnyToGroup:
+            testEnvironment.transferRestrictionsHelper.transferRulePDA(
+              ANY_TRANSFER_GROUP_ID,
+              groupId
+            )[0],
         },
         signers: [walletA],
       });
@@ -628,6 +643,20 @@ describe("TokenLockup tokenlock discriminator tests", () => {
             securityAssociatedAccountFrom,
             securityAssociatedAccountTo,
             transferRule: transferRulePubkey,
+            transferRestrictionGroupFrom: groupPubkey,
+            transferRestrictionGroupTo: groupPubkey,
+            transferRestrictionHolderFrom: holderPubkey,
+            transferRestrictionHolderTo: holderPubkey,
+            transferRuleFromGroupToAny:
+              testEnvironment.transferRestrictionsHelper.transferRulePDA(
+                groupId,
+                ANY_TRANSFER_GROUP_ID
+              )[0],
+            transferRuleFromAnyToGroup:
+              testEnvironment.transferRestrictionsHelper.transferRulePDA(
+                ANY_TRANSFER_GROUP_ID,
+                groupId
+              )[0],
           },
           signers: [walletA],
         }
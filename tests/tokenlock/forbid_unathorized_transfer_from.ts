@@ -35,6 +35,9 @@ import {
   uuidBytes,
 } from "./../helpers/tokenlock_helper";
 import { getNowTs } from "./../helpers/clock_helper";
+import {
+  ANY_TRANSFER_GROUP_ID,
+} from "./../helpers/transfer-restrictions_helper";
 
 describe("token lockup", () => {
   const testEnvironmentParams: TestEnvironmentParams = {
@@ -371,6 +374,10 @@ describe("token lockup", () => {
             accessControlProgram:
               testEnvironment.accessControlHelper.program.programId,
           },
+          remainingAccounts:
+            await testEnvironment.accessControlHelper.holderBalanceAccounts(
+              escrowAccount
+            ),
           signers: [testEnvironment.reserveAdmin],
         }
       );
@@ -686,6 +693,14 @@ describe("token lockup", () => {
         secAssocAccountFromData.group,
         secAssocAccountToData.group
       );
+    const [transferRestrictionGroupFrom] =
+      testEnvironment.transferRestrictionsHelper.groupPDA(
+        secAssocAccountFromData.group
+      );
+    const [transferRestrictionGroupTo] =
+      testEnvironment.transferRestrictionsHelper.groupPDA(
+        secAssocAccountToData.group
+      );
     const amount = new anchor.BN(1000);
     const transferInstruction = tokenlockProgram.instruction.transfer(amount, {
       accounts: {
@@ -703,6 +718,20 @@ describe("token lockup", () => {
         securityAssociatedAccountFrom: securityAssociatedAccountFromFake,
         securityAssociatedAccountTo,
         transferRule: transferRulePubkey,
+        transferRestrictionGroupFrom,
+        transferRestrictionGroupTo,
+        transferRestrictionHolderFrom: secAssocAccountFromData.holder,
+        transferRestrictionHolderTo: secAssocAccountToData.holder,
+        transferRuleFromGroupToAny:
+          testEnvironment.transferRestrictionsHelper.transferRulePDA(
+            secAssocAccountFromData.group,
+            ANY_TRANSFER_GROUP_ID
+          )[0],
+        transferRuleFromAnyToGroup:
+          testEnvironment.transferRestrictionsHelper.transferRulePDA(
+            ANY_TRANSFER_GROUP_ID,
+            secAssocAccountToData.group
+          )[0],
       },
       signers: [investor],
     });
@@ -21,6 +21,9 @@ import {
   balanceOfTimelock,
 } from "./../helpers/tokenlock_helper";
 import { getNowTs } from "./../helpers/clock_helper";
+import {
+  ANY_TRANSFER_GROUP_ID,
+} from "./../helpers/transfer-restrictions_helper";
 import { fromDaysToSeconds } from "../helpers/datetime";
 import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
 
@@ -325,6 +328,14 @@ describe("Transfer Negative cases", async () => {
         secAssocAccountFromData.group,
         secAssocAccountToData.group
       );
+    const [transferRestrictionGroupFrom] =
+      testEnvironment.transferRestrictionsHelper.groupPDA(
+        secAssocAccountFromData.group
+      );
+    const [transferRestrictionGroupTo] =
+      testEnvironment.transferRestrictionsHelper.groupPDA(
+        secAssocAccountToData.group
+      );
     try {
       await tokenlockProgram.rpc.transfer(new anchor.BN(amount), {
         accounts: {
@@ -342,6 +353,20 @@ describe("Transfer Negative cases", async () => {
           securityAssociatedAccountFrom,
           securityAssociatedAccountTo,
           transferRule: transferRulePubkey,
+          transferRestrictionGroupFrom,
+          transferRestrictionGroupTo,
+          transferRestrictionHolderFrom: secAssocAccountFromData.holder,
+          transferRestrictionHolderTo: secAssocAccountToData.holder,
+          transferRuleFromGroupToAny:
+            testEnvironment.transferRestrictionsHelper.transferRulePDA(
+              secAssocAccountFromData.group,
+              ANY_TRANSFER_GROUP_ID
+            )[0],
+          transferRuleFromAnyToGroup:
+            testEnvironment.transferRestrictionsHelper.transferRulePDA(
+              ANY_TRANSFER_GROUP_ID,
+              secAssocAccountToData.group
+            )[0],
         },
         signers: [walletPretender],
       });
@@ -421,6 +446,14 @@ describe("Transfer Negative cases", async () => {
         secAssocAccountFromData.group,
         secAssocAccountToData.group
       );
+    const [transferRestrictionGroupFrom] =
+      testEnvironment.transferRestrictionsHelper.groupPDA(
+        secAssocAccountFromData.group
+      );
+    const [transferRestrictionGroupTo] =
+      testEnvironment.transferRestrictionsHelper.groupPDA(
+        secAssocAccountToData.group
+      );
     try {
       await tokenlockProgram.rpc.transfer(new anchor.BN(amount), {
         accounts: {
@@ -438,6 +471,20 @@ describe("Transfer Negative cases", async () => {
           securityAssociatedAccountFrom,
           securityAssociatedAccountTo,
           transferRule: transferRulePubkey,
+          transferRestrictionGroupFrom,
+          transferRestrictionGroupTo,
+          transferRestrictionHolderFrom: secAssocAccountFromData.holder,
+          transferRestrictionHolderTo: secAssocAccountToData.holder,
+          transferRuleFromGroupToAny:
+            testEnvironment.transferRestrictionsHelper.transferRulePDA(
+              secAssocAccountFromData.group,
+              ANY_TRANSFER_GROUP_ID
+            )[0],
+          transferRuleFromAnyToGroup:
+            testEnvironment.transferRestrictionsHelper.transferRulePDA(
+              ANY_TRANSFER_GROUP_ID,
+              secAssocAccountToData.group
+            )[0],
         },
         signers: [walletPretender],
       });
@@ -0,0 +1,246 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+
+describe("Detect transfer restriction", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 3,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const holderReserveAdminId = new anchor.BN(0);
+  const holderInvestorId = new anchor.BN(1);
+  const investor = Keypair.generate();
+  const mintedAmount = 1_000_000 * 10 ** testEnvironmentParams.mint.decimals;
+  const transferAmount = new anchor.BN(
+    1_000 * 10 ** testEnvironmentParams.mint.decimals
+  );
+  let groupPubkey: PublicKey;
+  let investorHolderPubkey: PublicKey;
+  let reserveAdminTokenAccountPubkey: PublicKey;
+  let investorTokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+
+    reserveAdminTokenAccountPubkey =
+      testEnvironment.mintHelper.getAssocciatedTokenAddress(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN(mintedAmount),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      testEnvironment.reserveAdmin
+    );
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    [groupPubkey] = testEnvironment.transferRestrictionsHelper.groupPDA(groupId);
+
+    const wallets = [
+      {
+        holderId: holderReserveAdminId,
+        owner: testEnvironment.reserveAdmin.publicKey,
+        tokenAccount: reserveAdminTokenAccountPubkey,
+      },
+      {
+        holderId: holderInvestorId,
+        owner: investor.publicKey,
+        tokenAccount: investorTokenAccountPubkey,
+      },
+    ];
+    for (const { holderId, owner, tokenAccount } of wallets) {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionHolder(
+        holderId,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      const [holderPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderPDA(holderId);
+      const [holderGroupPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+          holderPubkey,
+          groupId
+        );
+      await testEnvironment.transferRestrictionsHelper.initializeHolderGroup(
+        holderGroupPubkey,
+        holderPubkey,
+        groupPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccount(
+        groupPubkey,
+        holderPubkey,
+        holderGroupPubkey,
+        owner,
+        tokenAccount,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+    }
+    [investorHolderPubkey] =
+      testEnvironment.transferRestrictionsHelper.holderPDA(holderInvestorId);
+
+    const lockedUntil = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(lockedUntil),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+  });
+
+  it("returns success code for allowed transfer without changing state", async () => {
+    const [securityAssociatedAccountPubkey] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        reserveAdminTokenAccountPubkey
+      );
+    const { balance: balanceBefore } =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        securityAssociatedAccountPubkey
+      );
+
+    const { code, message } =
+      await testEnvironment.transferRestrictionsHelper.detectTransferRestriction(
+        reserveAdminTokenAccountPubkey,
+        investorTokenAccountPubkey,
+        transferAmount
+      );
+    assert.equal(code, 0);
+    assert.equal(message, "SUCCESS");
+
+    const { balance: balanceAfter } =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        securityAssociatedAccountPubkey
+      );
+    assert.equal(balanceAfter.toString(), balanceBefore.toString());
+    const { currentBalance } =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        investorHolderPubkey
+      );
+    assert.equal(currentBalance.toNumber(), 0);
+  });
+
+  it("detects transfer above holder max balance", async () => {
+    await testEnvironment.transferRestrictionsHelper.setHolderMaxBalance(
+      transferAmount.subn(1),
+      investorHolderPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    const { code, message } =
+      await testEnvironment.transferRestrictionsHelper.detectTransferRestriction(
+        reserveAdminTokenAccountPubkey,
+        investorTokenAccountPubkey,
+        transferAmount
+      );
+    assert.equal(code, 6024);
+    assert.equal(message, "Holder max balance exceeded");
+
+    await testEnvironment.transferRestrictionsHelper.setHolderMaxBalance(
+      new anchor.BN(0),
+      investorHolderPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+  });
+
+  it("detects transfer amount above transfer rule maximum", async () => {
+    await testEnvironment.transferRestrictionsHelper.setTransferRuleAmountLimits(
+      new anchor.BN(0),
+      transferAmount.subn(1),
+      new anchor.BN(0),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    const { code, message } =
+      await testEnvironment.transferRestrictionsHelper.detectTransferRestriction(
+        reserveAdminTokenAccountPubkey,
+        investorTokenAccountPubkey,
+        transferAmount
+      );
+    assert.equal(code, 6027);
+    assert.equal(
+      message,
+      "Transfer amount is above the maximum allowed by transfer rule"
+    );
+  });
+
+  it("detects source balance below the transfer amount", async () => {
+    const { amount: sourceBalance } =
+      await testEnvironment.mintHelper.getAccount(
+        reserveAdminTokenAccountPubkey
+      );
+
+    const { code, message } =
+      await testEnvironment.transferRestrictionsHelper.detectTransferRestriction(
+        reserveAdminTokenAccountPubkey,
+        investorTokenAccountPubkey,
+        new anchor.BN((sourceBalance + BigInt(1)).toString())
+      );
+    assert.equal(code, 6054);
+    assert.equal(message, "Source balance is lower than the transfer amount");
+  });
+
+  it("detects blocked sender before other restrictions", async () => {
+    await testEnvironment.transferRestrictionsHelper.updateWalletTransferBlocks(
+      true,
+      false,
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+
+    const { code, message } =
+      await testEnvironment.transferRestrictionsHelper.detectTransferRestriction(
+        reserveAdminTokenAccountPubkey,
+        investorTokenAccountPubkey,
+        transferAmount
+      );
+    assert.equal(code, 6034);
+    assert.equal(message, "Sending tokens is blocked for the wallet");
+  });
+});
@@ -7,6 +7,7 @@ import {
   TestEnvironmentParams,
 } from "../helpers/test_environment";
 import { getNowTs } from "../helpers/clock_helper";
+import { ANY_TRANSFER_GROUP_ID } from "../helpers/transfer-restrictions_helper";
 
 describe("Enforce transfer restrictions", () => {
   const testEnvironmentParams: TestEnvironmentParams = {
@@ -35,6 +36,11 @@ describe("Enforce transfer restrictions", () => {
   let securityAssociatedAccountToPubkey: PublicKey;
   let transferRulePubkey: PublicKey;
   let transferGroupPubkey: PublicKey;
+  let holderFromPubkey: PublicKey;
+  let holderToPubkey: PublicKey;
+  let transferRuleFromGroupToAnyPubkey: PublicKey;
+  let transferRuleFromAnyToGroupPubkey: PublicKey;
+  const transferAmount = new anchor.BN(1_000);
 
   before(async () => {
     testEnvironment = new TestEnvironment(testEnvironmentParams);
@@ -195,12 +201,28 @@ describe("Enforce transfer restrictions", () => {
       );
     [transferGroupPubkey] =
       testEnvironment.transferRestrictionsHelper.groupPDA(firstGroupIdx);
+    [holderFromPubkey] = testEnvironment.transferRestrictionsHelper.holderPDA(
+      new anchor.BN(0)
+    );
+    [holderToPubkey] = testEnvironment.transferRestrictionsHelper.holderPDA(
+      new anchor.BN(1)
+    );
+    [transferRuleFromGroupToAnyPubkey] =
+      testEnvironment.transferRestrictionsHelper.transferRulePDA(
+        firstGroupIdx,
+        ANY_TRANSFER_GROUP_ID
+      );
+    [transferRuleFromAnyToGroupPubkey] =
+      testEnvironment.transferRestrictionsHelper.transferRulePDA(
+        ANY_TRANSFER_GROUP_ID,
+        firstGroupIdx
+      );
   });
 
   describe("when transfer rule is unlocked and transfers unpaused", () => {
     it("execute transaction successfully without error", async () => {
       await testEnvironment.transferRestrictionsHelper.program.methods
-        .enforceTransferRestrictions()
+        .enforceTransferRestrictions(transferAmount)
         .accountsStrict({
           sourceAccount: investorWallet0AssociatedAccount,
           mint: testEnvironment.mintKeypair.publicKey,
@@ -211,6 +233,13 @@ describe("Enforce transfer restrictions", () => {
           securityAssociatedAccountFrom: securityAssociatedAccountFromPubkey,
           securityAssociatedAccountTo: securityAssociatedAccountToPubkey,
           transferRule: transferRulePubkey,
+          transferRestrictionGroupFrom: transferGroupPubkey,
+          transferRestrictionGroupTo: transferGroupPubkey,
+          transferRestrictionHolderFrom: holderFromPubkey,
+          transferRestrictionHolderTo: holderToPubkey,
+          transferRuleFromGroupToAny: transferRuleFromGroupToAnyPubkey,
+          transferRuleFromAnyToGroup: transferRuleFromAnyToGroupPubkey,
+          authority: null,
         })
         .signers([])
         .rpc({ commitment: testEnvironment.commitment });
@@ -218,9 +247,194 @@ describe("Enforce transfer restrictions", () => {
     });
   });
 
+  describe("when transfer amount violates transfer rule amount limits", () => {
+    const limitCases = [
+      {
+        limits: [2_000, 0, 0],
+        code: "TransferAmountBelowMinimum",
+        message: "Transfer amount is below the minimum allowed by transfer rule",
+      },
+      {
+        limits: [0, 500, 0],
+        code: "TransferAmountAboveMaximum",
+        message: "Transfer amount is above the maximum allowed by transfer rule",
+      },
+      {
+        limits: [0, 0, 300],
+        code: "TransferAmountNotMultipleOfLotSize",
+        message: "Transfer amount is not a multiple of transfer rule lot size",
+      },
+    ];
+
+    after(async () => {
+      await testEnvironment.transferRestrictionsHelper.setTransferRuleAmountLimits(
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(0),
+        firstGroupIdx,
+        firstGroupIdx,
+        transferAdminRole,
+        testEnvironment.transferAdmin
+      );
+    });
+
+    for (const { limits, code, message } of limitCases) {
+      it(`fails transaction with ${code} error`, async () => {
+        const [minAmount, maxAmount, lotSize] = limits;
+        await testEnvironment.transferRestrictionsHelper.setTransferRuleAmountLimits(
+          new anchor.BN(minAmount),
+          new anchor.BN(maxAmount),
+          new anchor.BN(lotSize),
+          firstGroupIdx,
+          firstGroupIdx,
+          transferAdminRole,
+          testEnvironment.transferAdmin
+        );
+        try {
+          await testEnvironment.transferRestrictionsHelper.program.methods
+            .enforceTransferRestrictions(transferAmount)
+            .accountsStrict({
+              sourceAccount: investorWallet0AssociatedAccount,
+              mint: testEnvironment.mintKeypair.publicKey,
+              destinationAccount: investorWallet1AssociatedAccount,
+              transferRestrictionData:
+                testEnvironment.transferRestrictionsHelper
+                  .transferRestrictionDataPubkey,
+              securityAssociatedAccountFrom: securityAssociatedAccountFromPubkey,
+              securityAssociatedAccountTo: securityAssociatedAccountToPubkey,
+              transferRule: transferRulePubkey,
+              transferRestrictionGroupFrom: transferGroupPubkey,
+              transferRestrictionGroupTo: transferGroupPubkey,
+              transferRestrictionHolderFrom: holderFromPubkey,
+              transferRestrictionHolderTo: holderToPubkey,
+              transferRuleFromGroupToAny: transferRuleFromGroupToAnyPubkey,
+              transferRuleFromAnyToGroup: transferRuleFromAnyToGroupPubkey,
+              authority: null,
+            })
+            .signers([])
+            .rpc({ commitment: testEnvironment.commitment });
+          assert.fail("Expect an error");
+        } catch ({ error }) {
+          assert.equal(error.errorCode.code, code);
+          assert.equal(error.errorMessage, message);
+        }
+      });
+    }
+
+    it("executes transaction within amount limits", async () => {
+      await testEnvironment.transferRestrictionsHelper.setTransferRuleAmountLimits(
+        new anchor.BN(500),
+        new anchor.BN(2_000),
+        new anchor.BN(250),
+        firstGroupIdx,
+        firstGroupIdx,
+        transferAdminRole,
+        testEnvironment.transferAdmin
+      );
+      const { minAmount, maxAmount, lotSize } =
+        await testEnvironment.transferRestrictionsHelper.transferRuleData(
+          transferRulePubkey
+        );
+      assert.equal(minAmount.toNumber(), 500);
+      assert.equal(maxAmount.toNumber(), 2_000);
+      assert.equal(lotSize.toNumber(), 250);
+
+      await testEnvironment.transferRestrictionsHelper.program.methods
+        .enforceTransferRestrictions(transferAmount)
+        .accountsStrict({
+          sourceAccount: investorWallet0AssociatedAccount,
+          mint: testEnvironment.mintKeypair.publicKey,
+          destinationAccount: investorWallet1AssociatedAccount,
+          transferRestrictionData:
+            testEnvironment.transferRestrictionsHelper
+              .transferRestrictionDataPubkey,
+          securityAssociatedAccountFrom: securityAssociatedAccountFromPubkey,
+          securityAssociatedAccountTo: securityAssociatedAccountToPubkey,
+          transferRule: transferRulePubkey,
+          transferRestrictionGroupFrom: transferGroupPubkey,
+          transferRestrictionGroupTo: transferGroupPubkey,
+          transferRestrictionHolderFrom: holderFromPubkey,
+          transferRestrictionHolderTo: holderToPubkey,
+          transferRuleFromGroupToAny: transferRuleFromGroupToAnyPubkey,
+          transferRuleFromAnyToGroup: transferRuleFromAnyToGroupPubkey,
+          authority: null,
+        })
+        .signers([])
+        .rpc({ commitment: testEnvironment.commitment });
+    });
+
+    it("fails to set minimum amount above maximum amount", async () => {
+      try {
+        await testEnvironment.transferRestrictionsHelper.setTransferRuleAmountLimits(
+          new anchor.BN(3_000),
+          new anchor.BN(2_000),
+          new anchor.BN(0),
+          firstGroupIdx,
+          firstGroupIdx,
+          transferAdminRole,
+          testEnvironment.transferAdmin
+        );
+        assert.fail("Expect an error");
+      } catch ({ error }) {
+        assert.equal(error.errorCode.code, "InvalidTransferRuleAmountLimits");
+        assert.equal(error.errorMessage, "Invalid transfer rule amount limits");
+      }
+    });
+  });
+
+  describe("when transfer rule has expired", () => {
+    before(async () => {
+      const now = await getNowTs(testEnvironment.connection);
+
+      await testEnvironment.transferRestrictionsHelper.setAllowTransferRule(
+        new anchor.BN(now - 1000),
+        new anchor.BN(now - 1),
+        transferRulePubkey,
+        transferGroupPubkey,
+        transferGroupPubkey,
+        testEnvironment.accessControlHelper.walletRolePDA(
+          testEnvironment.transferAdmin.publicKey
+        )[0],
+        testEnvironment.transferAdmin
+      );
+    });
+
+    it("fails transaction with error", async () => {
+      try {
+        await testEnvironment.transferRestrictionsHelper.program.methods
+          .enforceTransferRestrictions(transferAmount)
+          .accountsStrict({
+            sourceAccount: investorWallet0AssociatedAccount,
+            mint: testEnvironment.mintKeypair.publicKey,
+            destinationAccount: investorWallet1AssociatedAccount,
+            transferRestrictionData:
+              testEnvironment.transferRestrictionsHelper
+                .transferRestrictionDataPubkey,
+            securityAssociatedAccountFrom: securityAssociatedAccountFromPubkey,
+            securityAssociatedAccountTo: securityAssociatedAccountToPubkey,
+            transferRule: transferRulePubkey,
+            transferRestrictionGroupFrom: transferGroupPubkey,
+            transferRestrictionGroupTo: transferGroupPubkey,
+            transferRestrictionHolderFrom: holderFromPubkey,
+            transferRestrictionHolderTo: holderToPubkey,
+            transferRuleFromGroupToAny: transferRuleFromGroupToAnyPubkey,
+            transferRuleFromAnyToGroup: transferRuleFromAnyToGroupPubkey,
+            authority: null,
+          })
+          .signers([])
+          .rpc({ commitment: testEnvironment.commitment });
+        assert.fail("Expect an error");
+      } catch ({ error }) {
+        assert.equal(error.errorCode.code, "TransferRuleExpired");
+        assert.equal(error.errorMessage, "Transfer rule has expired");
+      }
+    });
+  });
+
   describe("when transfer rule is not approved", () => {
     before(async () => {
       await testEnvironment.transferRestrictionsHelper.setAllowTransferRule(
+        new anchor.BN(0),
         new anchor.BN(0),
         transferRulePubkey,
         transferGroupPubkey,
@@ -235,7 +449,7 @@ describe("Enforce transfer restrictions", () => {
     it("fails transaction with error", async () => {
       try {
         await testEnvironment.transferRestrictionsHelper.program.methods
-          .enforceTransferRestrictions()
+          .enforceTransferRestrictions(transferAmount)
           .accountsStrict({
             sourceAccount: investorWallet0AssociatedAccount,
             mint: testEnvironment.mintKeypair.publicKey,
@@ -246,6 +460,13 @@ describe("Enforce transfer restrictions", () => {
             securityAssociatedAccountFrom: securityAssociatedAccountFromPubkey,
             securityAssociatedAccountTo: securityAssociatedAccountToPubkey,
             transferRule: transferRulePubkey,
+            transferRestrictionGroupFrom: transferGroupPubkey,
+            transferRestrictionGroupTo: transferGroupPubkey,
+            transferRestrictionHolderFrom: holderFromPubkey,
+            transferRestrictionHolderTo: holderToPubkey,
+            transferRuleFromGroupToAny: transferRuleFromGroupToAnyPubkey,
+            transferRuleFromAnyToGroup: transferRuleFromAnyToGroupPubkey,
+            authority: null,
           })
           .signers([])
           .rpc({ commitment: testEnvironment.commitment });
@@ -263,6 +484,7 @@ describe("Enforce transfer restrictions", () => {
 
       await testEnvironment.transferRestrictionsHelper.setAllowTransferRule(
         new anchor.BN(lockedUntil + 1000),
+        new anchor.BN(0),
         transferRulePubkey,
         transferGroupPubkey,
         transferGroupPubkey,
@@ -276,7 +498,7 @@ describe("Enforce transfer restrictions", () => {
     it("fails transaction with error", async () => {
       try {
         await testEnvironment.transferRestrictionsHelper.program.methods
-          .enforceTransferRestrictions()
+          .enforceTransferRestrictions(transferAmount)
           .accountsStrict({
             sourceAccount: investorWallet0AssociatedAccount,
             mint: testEnvironment.mintKeypair.publicKey,
@@ -287,6 +509,13 @@ describe("Enforce transfer restrictions", () => {
             securityAssociatedAccountFrom: securityAssociatedAccountFromPubkey,
             securityAssociatedAccountTo: securityAssociatedAccountToPubkey,
             transferRule: transferRulePubkey,
+            transferRestrictionGroupFrom: transferGroupPubkey,
+            transferRestrictionGroupTo: transferGroupPubkey,
+            transferRestrictionHolderFrom: holderFromPubkey,
+            transferRestrictionHolderTo: holderToPubkey,
+            transferRuleFromGroupToAny: transferRuleFromGroupToAnyPubkey,
+            transferRuleFromAnyToGroup: transferRuleFromAnyToGroupPubkey,
+            authority: null,
           })
           .signers([])
           .rpc({ commitment: testEnvironment.commitment });
@@ -301,6 +530,158 @@ describe("Enforce transfer restrictions", () => {
     });
   });
 
+  describe("when sender group is locked", () => {
+    before(async () => {
+      await testEnvironment.transferRestrictionsHelper.setAllowTransferRule(
+        new anchor.BN(1),
+        new anchor.BN(0),
+        transferRulePubkey,
+        transferGroupPubkey,
+        transferGroupPubkey,
+        transferAdminRole,
+        testEnvironment.transferAdmin
+      );
+      const now = await getNowTs(testEnvironment.connection);
+      await testEnvironment.transferRestrictionsHelper.setGroupLockup(
+        new anchor.BN(now + 1000),
+        false,
+        transferGroupPubkey,
+        transferAdminRole,
+        testEnvironment.transferAdmin
+      );
+    });
+
+    after(async () => {
+      await testEnvironment.transferRestrictionsHelper.setGroupLockup(
+        new anchor.BN(0),
+        false,
+        transferGroupPubkey,
+        transferAdminRole,
+        testEnvironment.transferAdmin
+      );
+    });
+
+    it("fails transaction with error", async () => {
+      try {
+        await testEnvironment.transferRestrictionsHelper.program.methods
+          .enforceTransferRestrictions(transferAmount)
+          .accountsStrict({
+            sourceAccount: investorWallet0AssociatedAccount,
+            mint: testEnvironment.mintKeypair.publicKey,
+            destinationAccount: investorWallet1AssociatedAccount,
+            transferRestrictionData:
+              testEnvironment.transferRestrictionsHelper
+                .transferRestrictionDataPubkey,
+            securityAssociatedAccountFrom: securityAssociatedAccountFromPubkey,
+            securityAssociatedAccountTo: securityAssociatedAccountToPubkey,
+            transferRule: transferRulePubkey,
+            transferRestrictionGroupFrom: transferGroupPubkey,
+            transferRestrictionGroupTo: transferGroupPubkey,
+            transferRestrictionHolderFrom: holderFromPubkey,
+            transferRestrictionHolderTo: holderToPubkey,
+            transferRuleFromGroupToAny: transferRuleFromGroupToAnyPubkey,
+            transferRuleFromAnyToGroup: transferRuleFromAnyToGroupPubkey,
+            authority: null,
+          })
+          .signers([])
+          .rpc({ commitment: testEnvironment.commitment });
+        assert.fail("Expect an error");
+      } catch ({ error }) {
+        assert.equal(error.errorCode.code, "GroupLocked");
+        assert.equal(error.errorMessage, "Sender group is locked until later");
+      }
+    });
+  });
+
+  describe("when sender holder is inactive", () => {
+    before(async () => {
+      await testEnvironment.transferRestrictionsHelper.setHolderActive(
+        false,
+        holderFromPubkey,
+        transferAdminRole,
+        testEnvironment.transferAdmin
+      );
+    });
+
+    after(async () => {
+      await testEnvironment.transferRestrictionsHelper.setHolderActive(
+        true,
+        holderFromPubkey,
+        transferAdminRole,
+        testEnvironment.transferAdmin
+      );
+    });
+
+    it("fails transaction with error", async () => {
+      try {
+        await testEnvironment.transferRestrictionsHelper.program.methods
+          .enforceTransferRestrictions(transferAmount)
+          .accountsStrict({
+            sourceAccount: investorWallet0AssociatedAccount,
+            mint: testEnvironment.mintKeypair.publicKey,
+            destinationAccount: investorWallet1AssociatedAccount,
+            transferRestrictionData:
+              testEnvironment.transferRestrictionsHelper
+                .transferRestrictionDataPubkey,
+            securityAssociatedAccountFrom: securityAssociatedAccountFromPubkey,
+            securityAssociatedAccountTo: securityAssociatedAccountToPubkey,
+            transferRule: transferRulePubkey,
+            transferRestrictionGroupFrom: transferGroupPubkey,
+            transferRestrictionGroupTo: transferGroupPubkey,
+            transferRestrictionHolderFrom: holderFromPubkey,
+            transferRestrictionHolderTo: holderToPubkey,
+            transferRuleFromGroupToAny: transferRuleFromGroupToAnyPubkey,
+            transferRuleFromAnyToGroup: transferRuleFromAnyToGroupPubkey,
+            authority: null,
+          })
+          .signers([])
+          .rpc({ commitment: testEnvironment.commitment });
+        assert.fail("Expect an error");
+      } catch ({ error }) {
+        assert.equal(error.errorCode.code, "HolderInactive");
+        assert.equal(error.errorMessage, "Holder is inactive");
+      }
+    });
+  });
+
+  describe("when recipient holder max balance is exceeded", () => {
+    before(async () => {
+      const { currentBalance } =
+        await testEnvironment.transferRestrictionsHelper.holderData(
+          holderToPubkey
+        );
+      await testEnvironment.transferRestrictionsHelper.setHolderMaxBalance(
+        currentBalance.add(transferAmount).subn(1),
+        holderToPubkey,
+        transferAdminRole,
+        testEnvironment.transferAdmin
+      );
+    });
+
+    after(async () => {
+      await testEnvironment.transferRestrictionsHelper.setHolderMaxBalance(
+        new anchor.BN(0),
+        holderToPubkey,
+        transferAdminRole,
+        testEnvironment.transferAdmin
+      );
+    });
+
+    it("fails transaction with error", async () => {
+      try {
+        await testEnvironment.transferRestrictionsHelper.enforceTransferRestrictions(
+          investorWallet0AssociatedAccount,
+          investorWallet1AssociatedAccount,
+          transferAmount
+        );
+        assert.fail("Expect an error");
+      } catch ({ error }) {
+        assert.equal(error.errorCode.code, "HolderMaxBalanceExceeded");
+        assert.equal(error.errorMessage, "Holder max balance exceeded");
+      }
+    });
+  });
+
   describe("when transfer restrictions is paused", () => {
     before(async () => {
       await testEnvironment.transferRestrictionsHelper.program.methods
@@ -325,7 +706,7 @@ describe("Enforce transfer restrictions", () => {
     it("fails transaction with error", async () => {
       try {
         await testEnvironment.transferRestrictionsHelper.program.methods
-          .enforceTransferRestrictions()
+          .enforceTransferRestrictions(transferAmount)
           .accountsStrict({
             sourceAccount: investorWallet0AssociatedAccount,
             mint: testEnvironment.mintKeypair.publicKey,
@@ -336,6 +717,13 @@ describe("Enforce transfer restrictions", () => {
             securityAssociatedAccountFrom: securityAssociatedAccountFromPubkey,
             securityAssociatedAccountTo: securityAssociatedAccountToPubkey,
             transferRule: transferRulePubkey,
+            transferRestrictionGroupFrom: transferGroupPubkey,
+            transferRestrictionGroupTo: transferGroupPubkey,
+            transferRestrictionHolderFrom: holderFromPubkey,
+            transferRestrictionHolderTo: holderToPubkey,
+            transferRuleFromGroupToAny: transferRuleFromGroupToAnyPubkey,
+            transferRuleFromAnyToGroup: transferRuleFromAnyToGroupPubkey,
+            authority: null,
           })
           .signers([])
           .rpc({ commitment: testEnvironment.commitment });
@@ -0,0 +1,281 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { PublicKey } from "@solana/web3.js";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+
+describe("Transfer restrictions events", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 3,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  let groupPubkey: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+  });
+
+  it("emits GroupInitializedEvent", async () => {
+    const txSignature =
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+        groupId,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+    [groupPubkey] =
+      testEnvironment.transferRestrictionsHelper.groupPDA(groupId);
+
+    const [event] =
+      await testEnvironment.transferRestrictionsHelper.parseEvents(
+        txSignature
+      );
+    assert.equal(event.name, "GroupInitializedEvent");
+    assert.equal(event.data.group.toBase58(), groupPubkey.toBase58());
+    assert.equal(event.data.groupId.toNumber(), groupId.toNumber());
+    assert.equal(
+      event.data.authority.toBase58(),
+      testEnvironment.transferAdmin.publicKey.toBase58()
+    );
+  });
+
+  it("emits TransferRuleInitializedEvent and AllowTransferRuleSetEvent", async () => {
+    const lockedUntil = new anchor.BN(100);
+    let txSignature =
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+        lockedUntil,
+        groupId,
+        groupId,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+    const [transferRulePubkey] =
+      testEnvironment.transferRestrictionsHelper.transferRulePDA(
+        groupId,
+        groupId
+      );
+
+    let [event] = await testEnvironment.transferRestrictionsHelper.parseEvents(
+      txSignature
+    );
+    assert.equal(event.name, "TransferRuleInitializedEvent");
+    assert.equal(
+      event.data.transferRule.toBase58(),
+      transferRulePubkey.toBase58()
+    );
+    assert.equal(event.data.groupIdFrom.toNumber(), groupId.toNumber());
+    assert.equal(event.data.groupIdTo.toNumber(), groupId.toNumber());
+    assert.equal(event.data.lockedUntil.toNumber(), lockedUntil.toNumber());
+
+    const newLockedUntil = new anchor.BN(200);
+    const newLockedAfter = new anchor.BN(300);
+    txSignature =
+      await testEnvironment.transferRestrictionsHelper.setAllowTransferRule(
+        newLockedUntil,
+        newLockedAfter,
+        transferRulePubkey,
+        groupPubkey,
+        groupPubkey,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+
+    [event] = await testEnvironment.transferRestrictionsHelper.parseEvents(
+      txSignature
+    );
+    assert.equal(event.name, "AllowTransferRuleSetEvent");
+    assert.equal(
+      event.data.lockedUntilBefore.toNumber(),
+      lockedUntil.toNumber()
+    );
+    assert.equal(event.data.lockedAfterBefore.toNumber(), 0);
+    assert.equal(
+      event.data.lockedUntil.toNumber(),
+      newLockedUntil.toNumber()
+    );
+    assert.equal(
+      event.data.lockedAfter.toNumber(),
+      newLockedAfter.toNumber()
+    );
+    assert.equal(
+      event.data.authority.toBase58(),
+      testEnvironment.transferAdmin.publicKey.toBase58()
+    );
+  });
+
+  it("emits HolderMaxSetEvent", async () => {
+    const holderMax = new anchor.BN(10);
+    const txSignature =
+      await testEnvironment.transferRestrictionsHelper.setHolderMax(
+        holderMax,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+
+    const [event] =
+      await testEnvironment.transferRestrictionsHelper.parseEvents(
+        txSignature
+      );
+    assert.equal(event.name, "HolderMaxSetEvent");
+    assert.equal(
+      event.data.holderMaxBefore.toNumber(),
+      testEnvironmentParams.maxHolders
+    );
+    assert.equal(event.data.holderMax.toNumber(), holderMax.toNumber());
+  });
+
+  it("emits GroupPausedEvent", async () => {
+    const txSignature =
+      await testEnvironment.transferRestrictionsHelper.pauseGroup(
+        true,
+        groupPubkey,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+
+    const [event] =
+      await testEnvironment.transferRestrictionsHelper.parseEvents(
+        txSignature
+      );
+    assert.equal(event.name, "GroupPausedEvent");
+    assert.equal(event.data.groupId.toNumber(), groupId.toNumber());
+    assert.isFalse(event.data.pausedBefore);
+    assert.isTrue(event.data.paused);
+  });
+
+  it("emits GroupMaxBalanceSetEvent", async () => {
+    const maxBalance = new anchor.BN(1_000);
+    const txSignature =
+      await testEnvironment.transferRestrictionsHelper.setGroupMaxBalance(
+        maxBalance,
+        groupPubkey,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+
+    const [event] =
+      await testEnvironment.transferRestrictionsHelper.parseEvents(
+        txSignature
+      );
+    assert.equal(event.name, "GroupMaxBalanceSetEvent");
+    assert.equal(event.data.groupId.toNumber(), groupId.toNumber());
+    assert.equal(event.data.maxBalanceBefore.toNumber(), 0);
+    assert.equal(event.data.maxBalance.toNumber(), maxBalance.toNumber());
+    assert.equal(
+      event.data.authority.toBase58(),
+      testEnvironment.transferAdmin.publicKey.toBase58()
+    );
+  });
+
+  it("emits GroupMetadataSetEvent", async () => {
+    const txSignature =
+      await testEnvironment.transferRestrictionsHelper.setGroupMetadata(
+        "Reg S",
+        "US",
+        1,
+        groupPubkey,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+
+    const [event] =
+      await testEnvironment.transferRestrictionsHelper.parseEvents(
+        txSignature
+      );
+    assert.equal(event.name, "GroupMetadataSetEvent");
+    assert.equal(event.data.nameBefore, "");
+    assert.equal(event.data.jurisdictionBefore, "");
+    assert.equal(event.data.flagsBefore, 0);
+    assert.equal(event.data.name, "Reg S");
+    assert.equal(event.data.jurisdiction, "US");
+    assert.equal(event.data.flags, 1);
+  });
+
+  it("emits TransferRuleAmountLimitsSetEvent", async () => {
+    const txSignature =
+      await testEnvironment.transferRestrictionsHelper.setTransferRuleAmountLimits(
+        new anchor.BN(10),
+        new anchor.BN(100),
+        new anchor.BN(5),
+        groupId,
+        groupId,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+
+    const [event] =
+      await testEnvironment.transferRestrictionsHelper.parseEvents(
+        txSignature
+      );
+    assert.equal(event.name, "TransferRuleAmountLimitsSetEvent");
+    assert.equal(event.data.groupIdFrom.toNumber(), groupId.toNumber());
+    assert.equal(event.data.groupIdTo.toNumber(), groupId.toNumber());
+    assert.equal(event.data.minAmountBefore.toNumber(), 0);
+    assert.equal(event.data.maxAmountBefore.toNumber(), 0);
+    assert.equal(event.data.lotSizeBefore.toNumber(), 0);
+    assert.equal(event.data.minAmount.toNumber(), 10);
+    assert.equal(event.data.maxAmount.toNumber(), 100);
+    assert.equal(event.data.lotSize.toNumber(), 5);
+  });
+
+  it("emits HoldersOfRecordEnforcementSetEvent", async () => {
+    const txSignature =
+      await testEnvironment.transferRestrictionsHelper.setHoldersOfRecordEnforcement(
+        true,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+
+    const [event] =
+      await testEnvironment.transferRestrictionsHelper.parseEvents(
+        txSignature
+      );
+    assert.equal(event.name, "HoldersOfRecordEnforcementSetEvent");
+    assert.isFalse(event.data.enforcedBefore);
+    assert.isTrue(event.data.enforced);
+  });
+
+  it("emits DefaultTransferRuleSetEvent", async () => {
+    const lockedUntil = new anchor.BN(100);
+    const txSignature =
+      await testEnvironment.transferRestrictionsHelper.setDefaultTransferRule(
+        lockedUntil,
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(0),
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+
+    const [event] =
+      await testEnvironment.transferRestrictionsHelper.parseEvents(
+        txSignature
+      );
+    assert.equal(event.name, "DefaultTransferRuleSetEvent");
+    assert.isNull(event.data.defaultTransferRuleBefore);
+    assert.equal(
+      event.data.defaultTransferRule.lockedUntil.toNumber(),
+      lockedUntil.toNumber()
+    );
+  });
+});
@@ -0,0 +1,299 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+import { solToLamports, topUpWallet } from "../utils";
+
+describe("Group lockup", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 3,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const reserveAdminGroupId = new anchor.BN(1);
+  const foundersGroupId = new anchor.BN(5);
+  const founder = Keypair.generate();
+  const mintedAmount = 1_000_000 * 10 ** testEnvironmentParams.mint.decimals;
+  const transferAmount = 1_000 * 10 ** testEnvironmentParams.mint.decimals;
+  let foundersGroupPubkey: PublicKey;
+  let reserveAdminTokenAccountPubkey: PublicKey;
+  let founderTokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+
+  async function transfer(
+    sourceTokenAccountPubkey: PublicKey,
+    destinationTokenAccountPubkey: PublicKey,
+    signer: Keypair,
+    amount: number
+  ): Promise<string> {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        sourceTokenAccountPubkey,
+        testEnvironment.mintKeypair.publicKey,
+        destinationTokenAccountPubkey,
+        signer.publicKey,
+        BigInt(amount),
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [signer],
+      { commitment: testEnvironment.commitment }
+    );
+  }
+
+  async function transferFromFounder(): Promise<string> {
+    return transfer(
+      founderTokenAccountPubkey,
+      reserveAdminTokenAccountPubkey,
+      founder,
+      transferAmount
+    );
+  }
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+
+    reserveAdminTokenAccountPubkey =
+      testEnvironment.mintHelper.getAssocciatedTokenAddress(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN(mintedAmount),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      testEnvironment.reserveAdmin
+    );
+    founderTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        founder.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    await topUpWallet(
+      testEnvironment.connection,
+      founder.publicKey,
+      solToLamports(1)
+    );
+
+    const wallets = [
+      {
+        holderId: new anchor.BN(0),
+        groupId: reserveAdminGroupId,
+        owner: testEnvironment.reserveAdmin.publicKey,
+        tokenAccount: reserveAdminTokenAccountPubkey,
+      },
+      {
+        holderId: new anchor.BN(1),
+        groupId: foundersGroupId,
+        owner: founder.publicKey,
+        tokenAccount: founderTokenAccountPubkey,
+      },
+    ];
+    for (const { holderId, groupId, owner, tokenAccount } of wallets) {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+        groupId,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      const [groupPubkey] =
+        testEnvironment.transferRestrictionsHelper.groupPDA(groupId);
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionHolder(
+        holderId,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      const [holderPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderPDA(holderId);
+      const [holderGroupPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+          holderPubkey,
+          groupId
+        );
+      await testEnvironment.transferRestrictionsHelper.initializeHolderGroup(
+        holderGroupPubkey,
+        holderPubkey,
+        groupPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccount(
+        groupPubkey,
+        holderPubkey,
+        holderGroupPubkey,
+        owner,
+        tokenAccount,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+    }
+    [foundersGroupPubkey] =
+      testEnvironment.transferRestrictionsHelper.groupPDA(foundersGroupId);
+
+    const lockedUntil = new anchor.BN(
+      await getNowTs(testEnvironment.connection)
+    );
+    for (const [groupFromId, groupToId] of [
+      [reserveAdminGroupId, foundersGroupId],
+      [foundersGroupId, reserveAdminGroupId],
+    ]) {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+        lockedUntil,
+        groupFromId,
+        groupToId,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+    }
+    await transfer(
+      reserveAdminTokenAccountPubkey,
+      founderTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      transferAmount * 2
+    );
+  });
+
+  it("fails to set group lockup by wallets admin", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setGroupLockup(
+        new anchor.BN(0),
+        true,
+        foundersGroupPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("fails to set the same group lockup", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setGroupLockup(
+        new anchor.BN(0),
+        false,
+        foundersGroupPubkey,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "ValueUnchanged");
+      assert.equal(
+        error.errorMessage,
+        "The provided value is already set. No changes were made"
+      );
+    }
+  });
+
+  it("blocks outbound transfers from frozen group", async () => {
+    await testEnvironment.transferRestrictionsHelper.setGroupLockup(
+      new anchor.BN(0),
+      true,
+      foundersGroupPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const { frozen } =
+      await testEnvironment.transferRestrictionsHelper.groupData(
+        foundersGroupPubkey
+      );
+    assert.isTrue(frozen);
+
+    try {
+      await transferFromFounder();
+      assert.fail("Expect an error");
+    } catch (error) {
+      const msg =
+        "Program log: AnchorError occurred. Error Code: GroupFrozen. Error Number: 6046. Error Message: Sender group is frozen.";
+      assert.isTrue(error.logs.some((log: string) => log === msg));
+    }
+  });
+
+  it("allows inbound transfers to frozen group", async () => {
+    await transfer(
+      reserveAdminTokenAccountPubkey,
+      founderTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      transferAmount
+    );
+  });
+
+  it("blocks outbound transfers from locked group until the lockup ends", async () => {
+    const lockedUntil = (await getNowTs(testEnvironment.connection)) + 3600;
+    await testEnvironment.transferRestrictionsHelper.setGroupLockup(
+      new anchor.BN(lockedUntil),
+      false,
+      foundersGroupPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const { lockedUntil: groupLockedUntil, frozen } =
+      await testEnvironment.transferRestrictionsHelper.groupData(
+        foundersGroupPubkey
+      );
+    assert.equal(groupLockedUntil.toNumber(), lockedUntil);
+    assert.isFalse(frozen);
+
+    try {
+      await transferFromFounder();
+      assert.fail("Expect an error");
+    } catch (error) {
+      const msg =
+        "Program log: AnchorError occurred. Error Code: GroupLocked. Error Number: 6045. Error Message: Sender group is locked until later.";
+      assert.isTrue(error.logs.some((log: string) => log === msg));
+    }
+  });
+
+  it("allows outbound transfers after the group lockup is removed", async () => {
+    await testEnvironment.transferRestrictionsHelper.setGroupLockup(
+      new anchor.BN(0),
+      false,
+      foundersGroupPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    await transferFromFounder();
+  });
+});
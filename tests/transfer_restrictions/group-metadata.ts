@@ -0,0 +1,166 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { PublicKey } from "@solana/web3.js";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { GroupFlags } from "../helpers/transfer-restrictions_helper";
+
+describe("Group metadata", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 3,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  let groupPubkey: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    [groupPubkey] = testEnvironment.transferRestrictionsHelper.groupPDA(groupId);
+  });
+
+  it("initializes group without metadata", async () => {
+    const { name, jurisdiction, flags } =
+      await testEnvironment.transferRestrictionsHelper.groupData(groupPubkey);
+    assert.equal(name, "");
+    assert.equal(jurisdiction, "");
+    assert.equal(flags, GroupFlags.None);
+  });
+
+  it("fails to set group metadata by wallets admin", async () => {
+    const [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    try {
+      await testEnvironment.transferRestrictionsHelper.setGroupMetadata(
+        "Reg S Non-US",
+        "DE",
+        GroupFlags.None,
+        groupPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("sets group metadata by transfer admin", async () => {
+    const groupName = "Founders 2yr lockup";
+    const groupJurisdiction = "US-DE";
+    const groupFlags = GroupFlags.AccreditedOnly | GroupFlags.Lockup;
+    await testEnvironment.transferRestrictionsHelper.setGroupMetadata(
+      groupName,
+      groupJurisdiction,
+      groupFlags,
+      groupPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    const { name, jurisdiction, flags } =
+      await testEnvironment.transferRestrictionsHelper.groupData(groupPubkey);
+    assert.equal(name, groupName);
+    assert.equal(jurisdiction, groupJurisdiction);
+    assert.equal(flags, groupFlags);
+  });
+
+  it("fails to set the same group metadata", async () => {
+    const { name, jurisdiction, flags } =
+      await testEnvironment.transferRestrictionsHelper.groupData(groupPubkey);
+    try {
+      await testEnvironment.transferRestrictionsHelper.setGroupMetadata(
+        name,
+        jurisdiction,
+        flags,
+        groupPubkey,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "ValueUnchanged");
+      assert.equal(
+        error.errorMessage,
+        "The provided value is already set. No changes were made"
+      );
+    }
+  });
+
+  it("fails to set too long group name", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setGroupMetadata(
+        "x".repeat(33),
+        "US",
+        GroupFlags.None,
+        groupPubkey,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "GroupNameTooLong");
+      assert.equal(error.errorMessage, "Group name is too long");
+    }
+  });
+
+  it("fails to set too long group jurisdiction", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setGroupMetadata(
+        "Reg D",
+        "US-CA-1234",
+        GroupFlags.None,
+        groupPubkey,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "GroupJurisdictionTooLong");
+      assert.equal(error.errorMessage, "Group jurisdiction is too long");
+    }
+  });
+
+  it("fails to set unknown group flags", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setGroupMetadata(
+        "Reg D",
+        "US",
+        GroupFlags.All + 1,
+        groupPubkey,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidGroupFlags");
+      assert.equal(error.errorMessage, "Invalid group flags");
+    }
+  });
+});
@@ -0,0 +1,272 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+import { solToLamports, topUpWallet } from "../utils";
+
+describe("Holder active", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 3,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const holderReserveAdminId = new anchor.BN(0);
+  const holderInvestorId = new anchor.BN(1);
+  const investor = Keypair.generate();
+  const mintedAmount = 1_000_000 * 10 ** testEnvironmentParams.mint.decimals;
+  let groupPubkey: PublicKey;
+  let investorHolderPubkey: PublicKey;
+  let reserveAdminTokenAccountPubkey: PublicKey;
+  let investorTokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+
+  async function transfer(
+    sourceTokenAccountPubkey: PublicKey,
+    destinationTokenAccountPubkey: PublicKey,
+    signer: Keypair,
+    amount: number
+  ): Promise<string> {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        sourceTokenAccountPubkey,
+        testEnvironment.mintKeypair.publicKey,
+        destinationTokenAccountPubkey,
+        signer.publicKey,
+        BigInt(amount),
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [signer],
+      { commitment: testEnvironment.commitment }
+    );
+  }
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+
+    reserveAdminTokenAccountPubkey =
+      testEnvironment.mintHelper.getAssocciatedTokenAddress(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN(mintedAmount),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      testEnvironment.reserveAdmin
+    );
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    await topUpWallet(
+      testEnvironment.connection,
+      investor.publicKey,
+      solToLamports(1)
+    );
+
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    [groupPubkey] = testEnvironment.transferRestrictionsHelper.groupPDA(groupId);
+
+    const wallets = [
+      {
+        holderId: holderReserveAdminId,
+        owner: testEnvironment.reserveAdmin.publicKey,
+        tokenAccount: reserveAdminTokenAccountPubkey,
+      },
+      {
+        holderId: holderInvestorId,
+        owner: investor.publicKey,
+        tokenAccount: investorTokenAccountPubkey,
+      },
+    ];
+    for (const { holderId, owner, tokenAccount } of wallets) {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionHolder(
+        holderId,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      const [holderPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderPDA(holderId);
+      const [holderGroupPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+          holderPubkey,
+          groupId
+        );
+      await testEnvironment.transferRestrictionsHelper.initializeHolderGroup(
+        holderGroupPubkey,
+        holderPubkey,
+        groupPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccount(
+        groupPubkey,
+        holderPubkey,
+        holderGroupPubkey,
+        owner,
+        tokenAccount,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+    }
+    [investorHolderPubkey] =
+      testEnvironment.transferRestrictionsHelper.holderPDA(holderInvestorId);
+
+    const lockedUntil = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(lockedUntil),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+  });
+
+  it("fails to set holder active by transfer admin", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setHolderActive(
+        false,
+        investorHolderPubkey,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("fails to set the same holder active value", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setHolderActive(
+        true,
+        investorHolderPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "ValueUnchanged");
+      assert.equal(
+        error.errorMessage,
+        "The provided value is already set. No changes were made"
+      );
+    }
+  });
+
+  it("blocks transfers to and from wallets of inactive holder", async () => {
+    const amount = 1_000;
+    await transfer(
+      reserveAdminTokenAccountPubkey,
+      investorTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      amount
+    );
+
+    await testEnvironment.transferRestrictionsHelper.setHolderActive(
+      false,
+      investorHolderPubkey,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    const { active } =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        investorHolderPubkey
+      );
+    assert.isFalse(active);
+
+    const msg =
+      "Program log: AnchorError occurred. Error Code: HolderInactive. Error Number: 6039. Error Message: Holder is inactive.";
+    try {
+      await transfer(
+        reserveAdminTokenAccountPubkey,
+        investorTokenAccountPubkey,
+        testEnvironment.reserveAdmin,
+        amount
+      );
+      assert.fail("Expect an error");
+    } catch (error) {
+      assert.isTrue(error.logs.some((log: string) => log === msg));
+    }
+    try {
+      await transfer(
+        investorTokenAccountPubkey,
+        reserveAdminTokenAccountPubkey,
+        investor,
+        amount
+      );
+      assert.fail("Expect an error");
+    } catch (error) {
+      assert.isTrue(error.logs.some((log: string) => log === msg));
+    }
+  });
+
+  it("allows transfers again after holder is reactivated", async () => {
+    await testEnvironment.transferRestrictionsHelper.setHolderActive(
+      true,
+      investorHolderPubkey,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+
+    const amount = 500;
+    await transfer(
+      investorTokenAccountPubkey,
+      reserveAdminTokenAccountPubkey,
+      investor,
+      amount
+    );
+    const { currentBalance } =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        investorHolderPubkey
+      );
+    assert.equal(currentBalance.toNumber(), amount);
+  });
+});
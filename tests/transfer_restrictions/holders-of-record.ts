@@ -0,0 +1,303 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+import { solToLamports, topUpWallet } from "../utils";
+
+describe("Holders of record", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 2,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const investor1 = Keypair.generate();
+  const investor2 = Keypair.generate();
+  const mintedAmount = 1_000_000 * 10 ** testEnvironmentParams.mint.decimals;
+  const transferAmount = 100 * 10 ** testEnvironmentParams.mint.decimals;
+  let groupPubkey: PublicKey;
+  let reserveAdminTokenAccountPubkey: PublicKey;
+  let investor1TokenAccountPubkey: PublicKey;
+  let investor2TokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+
+  async function transfer(
+    from: Keypair,
+    fromTokenAccountPubkey: PublicKey,
+    toTokenAccountPubkey: PublicKey,
+    amount: number
+  ): Promise<string> {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        fromTokenAccountPubkey,
+        testEnvironment.mintKeypair.publicKey,
+        toTokenAccountPubkey,
+        from.publicKey,
+        BigInt(amount),
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [from],
+      { commitment: testEnvironment.commitment }
+    );
+  }
+
+  function assertErrorLog(error: any, msg: string) {
+    const hasErrorLog = error.logs.some((log: string) => log === msg);
+    assert.isTrue(hasErrorLog);
+  }
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+    await testEnvironment.transferRestrictionsHelper.setHoldersOfRecordEnforcement(
+      true,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    reserveAdminTokenAccountPubkey =
+      testEnvironment.mintHelper.getAssocciatedTokenAddress(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN(mintedAmount),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      testEnvironment.reserveAdmin
+    );
+    investor1TokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor1.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    investor2TokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor2.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    await topUpWallet(
+      testEnvironment.connection,
+      investor1.publicKey,
+      solToLamports(1)
+    );
+
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    [groupPubkey] = testEnvironment.transferRestrictionsHelper.groupPDA(groupId);
+
+    // more holders than max holders can be provisioned when holders of record are enforced
+    const wallets = [
+      {
+        owner: testEnvironment.reserveAdmin.publicKey,
+        tokenAccount: reserveAdminTokenAccountPubkey,
+      },
+      {
+        owner: investor1.publicKey,
+        tokenAccount: investor1TokenAccountPubkey,
+      },
+      {
+        owner: investor2.publicKey,
+        tokenAccount: investor2TokenAccountPubkey,
+      },
+    ];
+    for (const [index, { owner, tokenAccount }] of wallets.entries()) {
+      const holderId = new anchor.BN(index);
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionHolder(
+        holderId,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      const [holderPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderPDA(holderId);
+      const [holderGroupPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+          holderPubkey,
+          groupId
+        );
+      await testEnvironment.transferRestrictionsHelper.initializeHolderGroup(
+        holderGroupPubkey,
+        holderPubkey,
+        groupPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccount(
+        groupPubkey,
+        holderPubkey,
+        holderGroupPubkey,
+        owner,
+        tokenAccount,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+    }
+
+    const lockedUntil = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(lockedUntil),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+  });
+
+  it("fails to disable holders of record enforcement by wallets admin", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setHoldersOfRecordEnforcement(
+        false,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("fails to enable holders of record enforcement twice", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setHoldersOfRecordEnforcement(
+        true,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "ValueUnchanged");
+      assert.equal(
+        error.errorMessage,
+        "The provided value is already set. No changes were made"
+      );
+    }
+  });
+
+  it("counts only holders with non-zero balance", async () => {
+    const { currentHoldersCount, currentHoldersOfRecordCount } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    assert.equal(currentHoldersCount.toNumber(), 3);
+    assert.equal(currentHoldersOfRecordCount.toNumber(), 1);
+    const { currentHoldersOfRecordCount: groupHoldersOfRecordCount } =
+      await testEnvironment.transferRestrictionsHelper.groupData(groupPubkey);
+    assert.equal(groupHoldersOfRecordCount.toNumber(), 1);
+  });
+
+  it("blocks transfer creating a holder of record beyond max holders", async () => {
+    await transfer(
+      testEnvironment.reserveAdmin,
+      reserveAdminTokenAccountPubkey,
+      investor1TokenAccountPubkey,
+      transferAmount
+    );
+    const { currentHoldersOfRecordCount } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    assert.equal(currentHoldersOfRecordCount.toNumber(), 2);
+
+    try {
+      await transfer(
+        testEnvironment.reserveAdmin,
+        reserveAdminTokenAccountPubkey,
+        investor2TokenAccountPubkey,
+        transferAmount
+      );
+      assert.fail("Expect an error");
+    } catch (error) {
+      assertErrorLog(
+        error,
+        "Program log: AnchorError occurred. Error Code: MaxHoldersReached. Error Number: 6001. Error Message: Max holders reached."
+      );
+    }
+  });
+
+  it("allows new holder of record after another holder exits", async () => {
+    await transfer(
+      investor1,
+      investor1TokenAccountPubkey,
+      reserveAdminTokenAccountPubkey,
+      transferAmount
+    );
+    await transfer(
+      testEnvironment.reserveAdmin,
+      reserveAdminTokenAccountPubkey,
+      investor2TokenAccountPubkey,
+      transferAmount
+    );
+
+    const { currentHoldersOfRecordCount } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    assert.equal(currentHoldersOfRecordCount.toNumber(), 2);
+  });
+
+  it("blocks transfer creating a holder of record beyond group max holders", async () => {
+    await testEnvironment.transferRestrictionsHelper.setHolderMax(
+      new anchor.BN(3),
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    await testEnvironment.transferRestrictionsHelper.setHolderGroupMax(
+      new anchor.BN(2),
+      groupPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    try {
+      await transfer(
+        testEnvironment.reserveAdmin,
+        reserveAdminTokenAccountPubkey,
+        investor1TokenAccountPubkey,
+        transferAmount
+      );
+      assert.fail("Expect an error");
+    } catch (error) {
+      assertErrorLog(
+        error,
+        "Program log: AnchorError occurred. Error Code: MaxHoldersReachedInsideTheGroup. Error Number: 6010. Error Message: Max holders reached inside the group."
+      );
+    }
+  });
+});
@@ -0,0 +1,254 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import { sha256 } from "js-sha256";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+
+describe("Initialize holder from reference", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 10,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  let groupPubkey: PublicKey;
+  const investor = Keypair.generate();
+  let investorTokenAccountPubkey: PublicKey;
+  let reserveAdminTokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+
+  async function provisionWallet(
+    holderPubkey: PublicKey,
+    owner: PublicKey,
+    tokenAccount: PublicKey
+  ) {
+    const [holderGroupPubkey] =
+      testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+        holderPubkey,
+        groupId
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeHolderGroup(
+      holderGroupPubkey,
+      holderPubkey,
+      groupPubkey,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccount(
+      groupPubkey,
+      holderPubkey,
+      holderGroupPubkey,
+      owner,
+      tokenAccount,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+  }
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    [groupPubkey] = testEnvironment.transferRestrictionsHelper.groupPDA(groupId);
+    const lockedUntil = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(lockedUntil),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    reserveAdminTokenAccountPubkey =
+      testEnvironment.mintHelper.getAssocciatedTokenAddress(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN(1_000_000),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      testEnvironment.reserveAdmin
+    );
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+  });
+
+  it("fails to initialize holder from reference by reserve admin", async () => {
+    const [reserveAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    try {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionHolderFromReference(
+        investor.publicKey.toBuffer(),
+        reserveAdminWalletRole,
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("initializes holder with id derived from investor wallet", async () => {
+    const { holderIds: holderIdsBefore, currentHoldersCount: countBefore } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionHolderFromReference(
+      investor.publicKey.toBuffer(),
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+
+    const holderId =
+      testEnvironment.transferRestrictionsHelper.holderIdFromReference(
+        investor.publicKey.toBuffer()
+      );
+    const [holderPubkey] =
+      testEnvironment.transferRestrictionsHelper.holderPDA(holderId);
+    const holderData =
+      await testEnvironment.transferRestrictionsHelper.holderData(holderPubkey);
+    assert.equal(holderData.id.toString(), holderId.toString());
+    assert.isTrue(holderData.active);
+
+    const { holderIds, currentHoldersCount } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    assert.equal(holderIds.toNumber(), holderIdsBefore.toNumber());
+    assert.equal(currentHoldersCount.toNumber(), countBefore.toNumber() + 1);
+  });
+
+  it("fails to initialize holder from the same reference twice", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionHolderFromReference(
+        investor.publicKey.toBuffer(),
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch (error) {
+      const isAlreadyInUse = error.logs.some((log: string) =>
+        log.includes("already in use")
+      );
+      assert.isTrue(isAlreadyInUse);
+    }
+  });
+
+  it("initializes holder with id derived from KYC reference hash alongside sequential holders", async () => {
+    const reference = Buffer.from(sha256.array("kyc-provider:investor-42"));
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionHolderFromReference(
+      reference,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const [holderPubkey] = testEnvironment.transferRestrictionsHelper.holderPDA(
+      testEnvironment.transferRestrictionsHelper.holderIdFromReference(
+        reference
+      )
+    );
+    const holderInfo = await testEnvironment.connection.getAccountInfo(
+      holderPubkey
+    );
+    assert.isNotNull(holderInfo);
+
+    const { holderIds } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionHolder(
+      holderIds,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    const { holderIds: holderIdsAfter } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    assert.equal(holderIdsAfter.toNumber(), holderIds.toNumber() + 1);
+  });
+
+  it("transfers to wallet of holder initialized from reference", async () => {
+    const { holderIds } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    const [reserveAdminHolderPubkey] =
+      testEnvironment.transferRestrictionsHelper.holderPDA(holderIds.subn(1));
+    await provisionWallet(
+      reserveAdminHolderPubkey,
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey
+    );
+    const [investorHolderPubkey] =
+      testEnvironment.transferRestrictionsHelper.holderPDA(
+        testEnvironment.transferRestrictionsHelper.holderIdFromReference(
+          investor.publicKey.toBuffer()
+        )
+      );
+    await provisionWallet(
+      investorHolderPubkey,
+      investor.publicKey,
+      investorTokenAccountPubkey
+    );
+
+    const amount = 1_000;
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        reserveAdminTokenAccountPubkey,
+        testEnvironment.mintKeypair.publicKey,
+        investorTokenAccountPubkey,
+        testEnvironment.reserveAdmin.publicKey,
+        BigInt(amount),
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+    await sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [testEnvironment.reserveAdmin],
+      { commitment: testEnvironment.commitment }
+    );
+
+    const { currentBalance } =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        investorHolderPubkey
+      );
+    assert.equal(currentBalance.toNumber(), amount);
+  });
+});
@@ -251,6 +251,7 @@ describe("Initialize transfer restriction Holder", () => {
               .transferRestrictionDataPubkey,
           authorityWalletRole: authorityWalletRolePubkey,
           payer: signer.publicKey,
+          rentReceiver: signer.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([signer])
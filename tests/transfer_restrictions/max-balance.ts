@@ -0,0 +1,513 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+
+describe("Max balance", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 3,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const holderReserveAdminId = new anchor.BN(0);
+  const holderInvestorId = new anchor.BN(1);
+  const investor = Keypair.generate();
+  const mintedAmount = 1_000_000 * 10 ** testEnvironmentParams.mint.decimals;
+  let groupPubkey: PublicKey;
+  let reserveAdminHolderPubkey: PublicKey;
+  let investorHolderPubkey: PublicKey;
+  let reserveAdminTokenAccountPubkey: PublicKey;
+  let investorTokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+
+  async function transferFromReserveAdmin(amount: number): Promise<string> {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        reserveAdminTokenAccountPubkey,
+        testEnvironment.mintKeypair.publicKey,
+        investorTokenAccountPubkey,
+        testEnvironment.reserveAdmin.publicKey,
+        BigInt(amount),
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [testEnvironment.reserveAdmin],
+      { commitment: testEnvironment.commitment }
+    );
+  }
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+
+    reserveAdminTokenAccountPubkey =
+      testEnvironment.mintHelper.getAssocciatedTokenAddress(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN(mintedAmount),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      testEnvironment.reserveAdmin
+    );
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    [groupPubkey] = testEnvironment.transferRestrictionsHelper.groupPDA(groupId);
+
+    const wallets = [
+      {
+        holderId: holderReserveAdminId,
+        owner: testEnvironment.reserveAdmin.publicKey,
+        tokenAccount: reserveAdminTokenAccountPubkey,
+      },
+      {
+        holderId: holderInvestorId,
+        owner: investor.publicKey,
+        tokenAccount: investorTokenAccountPubkey,
+      },
+    ];
+    for (const { holderId, owner, tokenAccount } of wallets) {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionHolder(
+        holderId,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      const [holderPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderPDA(holderId);
+      const [holderGroupPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+          holderPubkey,
+          groupId
+        );
+      await testEnvironment.transferRestrictionsHelper.initializeHolderGroup(
+        holderGroupPubkey,
+        holderPubkey,
+        groupPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccount(
+        groupPubkey,
+        holderPubkey,
+        holderGroupPubkey,
+        owner,
+        tokenAccount,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+    }
+    [reserveAdminHolderPubkey] =
+      testEnvironment.transferRestrictionsHelper.holderPDA(
+        holderReserveAdminId
+      );
+    [investorHolderPubkey] =
+      testEnvironment.transferRestrictionsHelper.holderPDA(holderInvestorId);
+
+    const lockedUntil = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(lockedUntil),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+  });
+
+  it("tracks holder balance on security associated account initialization", async () => {
+    const { currentBalance, maxBalance } =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        reserveAdminHolderPubkey
+      );
+    assert.equal(currentBalance.toNumber(), mintedAmount);
+    assert.equal(maxBalance.toNumber(), 0);
+  });
+
+  it("fails to set group max balance by wallets admin", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setGroupMaxBalance(
+        new anchor.BN(1_000),
+        groupPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("fails to set holder max balance by reserve admin", async () => {
+    const [reserveAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    try {
+      await testEnvironment.transferRestrictionsHelper.setHolderMaxBalance(
+        new anchor.BN(1_000),
+        investorHolderPubkey,
+        reserveAdminWalletRole,
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("fails to set the same holder max balance", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setHolderMaxBalance(
+        new anchor.BN(0),
+        investorHolderPubkey,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "ValueUnchanged");
+      assert.equal(
+        error.errorMessage,
+        "The provided value is already set. No changes were made"
+      );
+    }
+  });
+
+  it("blocks transfer above holder max balance", async () => {
+    const maxBalance = 1_000 * 10 ** testEnvironmentParams.mint.decimals;
+    await testEnvironment.transferRestrictionsHelper.setHolderMaxBalance(
+      new anchor.BN(maxBalance),
+      investorHolderPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const { maxBalance: holderMaxBalance } =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        investorHolderPubkey
+      );
+    assert.equal(holderMaxBalance.toNumber(), maxBalance);
+
+    try {
+      await transferFromReserveAdmin(maxBalance + 1);
+      assert.fail("Expect an error");
+    } catch (error) {
+      const msg =
+        "Program log: AnchorError occurred. Error Code: HolderMaxBalanceExceeded. Error Number: 6024. Error Message: Holder max balance exceeded.";
+      const isHolderMaxBalanceExceeded = error.logs.some(
+        (log: string) => log === msg
+      );
+      assert.isTrue(isHolderMaxBalanceExceeded);
+    }
+
+    await transferFromReserveAdmin(maxBalance);
+    const { currentBalance: investorBalance } =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        investorHolderPubkey
+      );
+    assert.equal(investorBalance.toNumber(), maxBalance);
+    const { currentBalance: reserveAdminBalance } =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        reserveAdminHolderPubkey
+      );
+    assert.equal(reserveAdminBalance.toNumber(), mintedAmount - maxBalance);
+  });
+
+  it("blocks transfer above group max balance", async () => {
+    await testEnvironment.transferRestrictionsHelper.setHolderMaxBalance(
+      new anchor.BN(0),
+      investorHolderPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const maxBalance = 2_000 * 10 ** testEnvironmentParams.mint.decimals;
+    await testEnvironment.transferRestrictionsHelper.setGroupMaxBalance(
+      new anchor.BN(maxBalance),
+      groupPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const { maxBalance: groupMaxBalance } =
+      await testEnvironment.transferRestrictionsHelper.groupData(groupPubkey);
+    assert.equal(groupMaxBalance.toNumber(), maxBalance);
+
+    try {
+      await transferFromReserveAdmin(maxBalance);
+      assert.fail("Expect an error");
+    } catch (error) {
+      const msg =
+        "Program log: AnchorError occurred. Error Code: GroupMaxBalanceExceeded. Error Number: 6025. Error Message: Group max balance exceeded.";
+      const isGroupMaxBalanceExceeded = error.logs.some(
+        (log: string) => log === msg
+      );
+      assert.isTrue(isGroupMaxBalanceExceeded);
+    }
+  });
+
+  it("tracks holder balance after mint", async () => {
+    const mintAmount = 500 * 10 ** testEnvironmentParams.mint.decimals;
+    const { currentBalance: balanceBefore } =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        investorHolderPubkey
+      );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN(mintAmount),
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      testEnvironment.reserveAdmin
+    );
+
+    const { currentBalance: balanceAfter } =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        investorHolderPubkey
+      );
+    assert.equal(
+      balanceAfter.toNumber(),
+      balanceBefore.toNumber() + mintAmount
+    );
+  });
+
+  it("tracks holder balance after burn", async () => {
+    const burnAmount = 200 * 10 ** testEnvironmentParams.mint.decimals;
+    const { currentBalance: balanceBefore } =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        investorHolderPubkey
+      );
+    await testEnvironment.accessControlHelper.burnSecurities(
+      new anchor.BN(burnAmount),
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      testEnvironment.reserveAdmin
+    );
+
+    const { currentBalance: balanceAfter } =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        investorHolderPubkey
+      );
+    assert.equal(
+      balanceAfter.toNumber(),
+      balanceBefore.toNumber() - burnAmount
+    );
+  });
+
+  it("fails to mint without the holder balance accounts", async () => {
+    const [reserveAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    const holderBalanceAccounts =
+      await testEnvironment.accessControlHelper.holderBalanceAccounts(
+        investorTokenAccountPubkey
+      );
+    try {
+      await testEnvironment.accessControlHelper.program.methods
+        .mintSecurities(new anchor.BN(1), "")
+        .accountsStrict({
+          authority: testEnvironment.reserveAdmin.publicKey,
+          authorityWalletRole: reserveAdminWalletRole,
+          accessControl:
+            testEnvironment.accessControlHelper.accessControlPubkey,
+          securityMint: testEnvironment.mintKeypair.publicKey,
+          destinationAccount: investorTokenAccountPubkey,
+          destinationAuthority: investor.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .remainingAccounts(holderBalanceAccounts.slice(0, 2))
+        .signers([testEnvironment.reserveAdmin])
+        .rpc({ commitment: testEnvironment.commitment });
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidHolderBalanceAccounts");
+      assert.equal(
+        error.errorMessage,
+        "Missing or invalid accounts to sync the holder balance"
+      );
+    }
+  });
+
+  it("tracks holder balances after force transfer", async () => {
+    const transferAmount = 100 * 10 ** testEnvironmentParams.mint.decimals;
+    const { currentBalance: investorBalanceBefore } =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        investorHolderPubkey
+      );
+    const { currentBalance: reserveAdminBalanceBefore } =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        reserveAdminHolderPubkey
+      );
+    await testEnvironment.accessControlHelper.forceTransferBetween(
+      transferAmount,
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      testEnvironment.reserveAdmin,
+      testEnvironment.connection
+    );
+
+    const { currentBalance: investorBalanceAfter } =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        investorHolderPubkey
+      );
+    const { currentBalance: reserveAdminBalanceAfter } =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        reserveAdminHolderPubkey
+      );
+    assert.equal(
+      investorBalanceAfter.toNumber(),
+      investorBalanceBefore.toNumber() - transferAmount
+    );
+    assert.equal(
+      reserveAdminBalanceAfter.toNumber(),
+      reserveAdminBalanceBefore.toNumber() + transferAmount
+    );
+  });
+
+  it("fails to move a wallet to a group with a lower max balance", async () => {
+    const newGroupId = new anchor.BN(2);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      newGroupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const [newGroupPubkey] =
+      testEnvironment.transferRestrictionsHelper.groupPDA(newGroupId);
+    await testEnvironment.transferRestrictionsHelper.setGroupMaxBalance(
+      new anchor.BN(1),
+      newGroupPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const [holderGroupCurrentPubkey] =
+      testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+        reserveAdminHolderPubkey,
+        groupId
+      );
+    const [holderGroupNewPubkey] =
+      testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+        reserveAdminHolderPubkey,
+        newGroupId
+      );
+    await testEnvironment.transferRestrictionsHelper.initializeHolderGroup(
+      holderGroupNewPubkey,
+      reserveAdminHolderPubkey,
+      newGroupPubkey,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    const [securityAssociatedAccountPubkey] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        reserveAdminTokenAccountPubkey
+      );
+
+    try {
+      await testEnvironment.transferRestrictionsHelper.updateWalletGroup(
+        securityAssociatedAccountPubkey,
+        groupPubkey,
+        newGroupPubkey,
+        holderGroupCurrentPubkey,
+        holderGroupNewPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.reserveAdmin.publicKey,
+        reserveAdminTokenAccountPubkey,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "GroupMaxBalanceExceeded");
+      assert.equal(error.errorMessage, "Group max balance exceeded");
+    }
+  });
+
+  it("fails to migrate accounts with the current layout", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.migrateAccount(
+        investorHolderPubkey,
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "AccountAlreadyMigrated");
+      assert.equal(error.errorMessage, "Account is already migrated");
+    }
+
+    try {
+      await testEnvironment.transferRestrictionsHelper.migrateSecurityAssociatedAccount(
+        investorTokenAccountPubkey,
+        investorHolderPubkey,
+        groupId,
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "AccountAlreadyMigrated");
+      assert.equal(error.errorMessage, "Account is already migrated");
+    }
+  });
+
+  it("fails to migrate a security associated account by migrate account", async () => {
+    const [securityAssociatedAccountPubkey] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        investorTokenAccountPubkey
+      );
+    try {
+      await testEnvironment.transferRestrictionsHelper.migrateAccount(
+        securityAssociatedAccountPubkey,
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidAccountToMigrate");
+      assert.equal(error.errorMessage, "Account type cannot be migrated");
+    }
+  });
+});
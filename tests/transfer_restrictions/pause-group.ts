@@ -0,0 +1,313 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+import { ANY_TRANSFER_GROUP_ID } from "../helpers/transfer-restrictions_helper";
+
+describe("Pause group transfers", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 3,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const issuerGroupId = new anchor.BN(1);
+  const exchangeGroupId = new anchor.BN(2);
+  const investor = Keypair.generate();
+  const exchange = Keypair.generate();
+  const mintedAmount = 1_000_000 * 10 ** testEnvironmentParams.mint.decimals;
+  const transferAmount = 1_000 * 10 ** testEnvironmentParams.mint.decimals;
+  let issuerGroupPubkey: PublicKey;
+  let exchangeGroupPubkey: PublicKey;
+  let reserveAdminTokenAccountPubkey: PublicKey;
+  let investorTokenAccountPubkey: PublicKey;
+  let exchangeTokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+
+  async function transferFromReserveAdmin(
+    destinationTokenAccountPubkey: PublicKey
+  ): Promise<string> {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        reserveAdminTokenAccountPubkey,
+        testEnvironment.mintKeypair.publicKey,
+        destinationTokenAccountPubkey,
+        testEnvironment.reserveAdmin.publicKey,
+        BigInt(transferAmount),
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [testEnvironment.reserveAdmin],
+      { commitment: testEnvironment.commitment }
+    );
+  }
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+
+    reserveAdminTokenAccountPubkey =
+      testEnvironment.mintHelper.getAssocciatedTokenAddress(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN(mintedAmount),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      testEnvironment.reserveAdmin
+    );
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+    exchangeTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        exchange.publicKey,
+        testEnvironment.reserveAdmin
+      );
+
+    for (const groupId of [issuerGroupId, exchangeGroupId]) {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+        groupId,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+    }
+    [issuerGroupPubkey] =
+      testEnvironment.transferRestrictionsHelper.groupPDA(issuerGroupId);
+    [exchangeGroupPubkey] =
+      testEnvironment.transferRestrictionsHelper.groupPDA(exchangeGroupId);
+
+    const wallets = [
+      {
+        holderId: new anchor.BN(0),
+        groupId: issuerGroupId,
+        owner: testEnvironment.reserveAdmin.publicKey,
+        tokenAccount: reserveAdminTokenAccountPubkey,
+      },
+      {
+        holderId: new anchor.BN(1),
+        groupId: issuerGroupId,
+        owner: investor.publicKey,
+        tokenAccount: investorTokenAccountPubkey,
+      },
+      {
+        holderId: new anchor.BN(2),
+        groupId: exchangeGroupId,
+        owner: exchange.publicKey,
+        tokenAccount: exchangeTokenAccountPubkey,
+      },
+    ];
+    for (const { holderId, groupId, owner, tokenAccount } of wallets) {
+      const [groupPubkey] =
+        testEnvironment.transferRestrictionsHelper.groupPDA(groupId);
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionHolder(
+        holderId,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      const [holderPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderPDA(holderId);
+      const [holderGroupPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+          holderPubkey,
+          groupId
+        );
+      await testEnvironment.transferRestrictionsHelper.initializeHolderGroup(
+        holderGroupPubkey,
+        holderPubkey,
+        groupPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccount(
+        groupPubkey,
+        holderPubkey,
+        holderGroupPubkey,
+        owner,
+        tokenAccount,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+    }
+
+    const lockedUntil = new anchor.BN(
+      await getNowTs(testEnvironment.connection)
+    );
+    for (const groupToId of [issuerGroupId, exchangeGroupId]) {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+        lockedUntil,
+        issuerGroupId,
+        groupToId,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+    }
+  });
+
+  it("fails to pause group by wallets admin", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.pauseGroup(
+        true,
+        exchangeGroupPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("fails to unpause not paused group", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.pauseGroup(
+        false,
+        exchangeGroupPubkey,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "ValueUnchanged");
+      assert.equal(
+        error.errorMessage,
+        "The provided value is already set. No changes were made"
+      );
+    }
+  });
+
+  it("blocks transfers to paused group", async () => {
+    await testEnvironment.transferRestrictionsHelper.pauseGroup(
+      true,
+      exchangeGroupPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const { paused } =
+      await testEnvironment.transferRestrictionsHelper.groupData(
+        exchangeGroupPubkey
+      );
+    assert.isTrue(paused);
+
+    try {
+      await transferFromReserveAdmin(exchangeTokenAccountPubkey);
+      assert.fail("Expect an error");
+    } catch (error) {
+      const msg =
+        "Program log: AnchorError occurred. Error Code: GroupTransfersPaused. Error Number: 6047. Error Message: Transfers of the group are paused.";
+      assert.isTrue(error.logs.some((log: string) => log === msg));
+    }
+  });
+
+  it("allows transfers between wallets of other groups", async () => {
+    await transferFromReserveAdmin(investorTokenAccountPubkey);
+  });
+
+  it("enforces group pause for lockup releases", async () => {
+    const [securityAssociatedAccountFrom] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        reserveAdminTokenAccountPubkey
+      );
+    const [securityAssociatedAccountTo] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        exchangeTokenAccountPubkey
+      );
+    try {
+      await testEnvironment.transferRestrictionsHelper.program.methods
+        .enforceTransferRestrictions(new anchor.BN(transferAmount))
+        .accountsStrict({
+          sourceAccount: reserveAdminTokenAccountPubkey,
+          mint: testEnvironment.mintKeypair.publicKey,
+          destinationAccount: exchangeTokenAccountPubkey,
+          transferRestrictionData:
+            testEnvironment.transferRestrictionsHelper
+              .transferRestrictionDataPubkey,
+          securityAssociatedAccountFrom,
+          securityAssociatedAccountTo,
+          transferRule:
+            testEnvironment.transferRestrictionsHelper.transferRulePDA(
+              issuerGroupId,
+              exchangeGroupId
+            )[0],
+          transferRestrictionGroupFrom: issuerGroupPubkey,
+          transferRestrictionGroupTo: exchangeGroupPubkey,
+          transferRestrictionHolderFrom:
+            testEnvironment.transferRestrictionsHelper.holderPDA(
+              new anchor.BN(0)
+            )[0],
+          transferRestrictionHolderTo:
+            testEnvironment.transferRestrictionsHelper.holderPDA(
+              new anchor.BN(2)
+            )[0],
+          transferRuleFromGroupToAny:
+            testEnvironment.transferRestrictionsHelper.transferRulePDA(
+              issuerGroupId,
+              ANY_TRANSFER_GROUP_ID
+            )[0],
+          transferRuleFromAnyToGroup:
+            testEnvironment.transferRestrictionsHelper.transferRulePDA(
+              ANY_TRANSFER_GROUP_ID,
+              exchangeGroupId
+            )[0],
+          authority: null,
+        })
+        .rpc({ commitment: testEnvironment.commitment });
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "GroupTransfersPaused");
+      assert.equal(error.errorMessage, "Transfers of the group are paused");
+    }
+  });
+
+  it("allows transfers to unpaused group", async () => {
+    await testEnvironment.transferRestrictionsHelper.pauseGroup(
+      false,
+      exchangeGroupPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    await transferFromReserveAdmin(exchangeTokenAccountPubkey);
+  });
+});
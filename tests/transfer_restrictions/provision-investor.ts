@@ -0,0 +1,223 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+
+describe("Provision investor", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 10,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  let groupPubkey: PublicKey;
+  const investors = Array.from({ length: 4 }, () => Keypair.generate());
+  const investorTokenAccounts: PublicKey[] = [];
+  const mintedAmount = 1_000 * 10 ** testEnvironmentParams.mint.decimals;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    [groupPubkey] = testEnvironment.transferRestrictionsHelper.groupPDA(groupId);
+
+    for (const investor of investors) {
+      const tokenAccount =
+        await testEnvironment.mintHelper.createAssociatedTokenAccount(
+          investor.publicKey,
+          testEnvironment.reserveAdmin
+        );
+      await testEnvironment.accessControlHelper.mintSecurities(
+        new anchor.BN(mintedAmount),
+        investor.publicKey,
+        tokenAccount,
+        testEnvironment.reserveAdmin
+      );
+      investorTokenAccounts.push(tokenAccount);
+    }
+  });
+
+  async function assertProvisioned(
+    holderId: anchor.BN,
+    tokenAccount: PublicKey
+  ) {
+    const [holderPubkey] =
+      testEnvironment.transferRestrictionsHelper.holderPDA(holderId);
+    const holderData =
+      await testEnvironment.transferRestrictionsHelper.holderData(holderPubkey);
+    assert.equal(holderData.id.toNumber(), holderId.toNumber());
+    assert.equal(holderData.currentWalletsCount.toNumber(), 1);
+    assert.equal(holderData.currentHolderGroupCount.toNumber(), 1);
+    assert.equal(holderData.currentBalance.toNumber(), mintedAmount);
+    assert.isTrue(holderData.active);
+
+    const holderGroupData =
+      await testEnvironment.transferRestrictionsHelper.holderGroupData(
+        testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+          holderPubkey,
+          groupId
+        )[0]
+      );
+    assert.equal(holderGroupData.group.toNumber(), groupId.toNumber());
+    assert.equal(holderGroupData.holder.toBase58(), holderPubkey.toBase58());
+    assert.equal(holderGroupData.currentWalletsCount.toNumber(), 1);
+
+    const saaData =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+          tokenAccount
+        )[0]
+      );
+    assert.equal(saaData.group.toNumber(), groupId.toNumber());
+    assert.equal(saaData.holder.toBase58(), holderPubkey.toBase58());
+    assert.equal(saaData.balance.toNumber(), mintedAmount);
+  }
+
+  it("fails to provision investor by reserve admin", async () => {
+    const [reserveAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    try {
+      await testEnvironment.transferRestrictionsHelper.provisionInvestor(
+        new anchor.BN(0),
+        groupId,
+        investors[0].publicKey,
+        investorTokenAccounts[0],
+        reserveAdminWalletRole,
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("provisions investor by wallets admin", async () => {
+    const holderId = new anchor.BN(0);
+    await testEnvironment.transferRestrictionsHelper.provisionInvestor(
+      holderId,
+      groupId,
+      investors[0].publicKey,
+      investorTokenAccounts[0],
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+
+    await assertProvisioned(holderId, investorTokenAccounts[0]);
+    const { currentHoldersCount } =
+      await testEnvironment.transferRestrictionsHelper.groupData(groupPubkey);
+    assert.equal(currentHoldersCount.toNumber(), 1);
+    const transferRestrictionData =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    assert.equal(transferRestrictionData.currentHoldersCount.toNumber(), 1);
+    assert.equal(transferRestrictionData.holderIds.toNumber(), 1);
+  });
+
+  it("fails to provision investors with invalid remaining accounts", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.program.methods
+        .provisionInvestors([new anchor.BN(1), new anchor.BN(2)])
+        .accountsStrict({
+          group: groupPubkey,
+          securityToken: testEnvironment.mintKeypair.publicKey,
+          transferRestrictionData:
+            testEnvironment.transferRestrictionsHelper
+              .transferRestrictionDataPubkey,
+          authorityWalletRole: walletsAdminWalletRole,
+          payer: testEnvironment.walletsAdmin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          {
+            pubkey: testEnvironment.transferRestrictionsHelper.holderPDA(
+              new anchor.BN(1)
+            )[0],
+            isSigner: false,
+            isWritable: true,
+          },
+        ])
+        .signers([testEnvironment.walletsAdmin])
+        .rpc({ commitment: testEnvironment.commitment });
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidRemainingAccounts");
+      assert.equal(error.errorMessage, "Invalid remaining accounts");
+    }
+  });
+
+  it("fails to provision investors when one of the wallets is already provisioned", async () => {
+    const holderIds = [new anchor.BN(1), new anchor.BN(2)];
+    try {
+      await testEnvironment.transferRestrictionsHelper.provisionInvestors(
+        holderIds,
+        groupId,
+        [investorTokenAccounts[1], investorTokenAccounts[0]],
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch (error) {
+      assert.isDefined(error.logs);
+    }
+
+    const holderInfo = await testEnvironment.connection.getAccountInfo(
+      testEnvironment.transferRestrictionsHelper.holderPDA(holderIds[0])[0]
+    );
+    assert.isNull(holderInfo);
+  });
+
+  it("provisions investors in batch by transfer admin", async () => {
+    const holderIds = [new anchor.BN(1), new anchor.BN(2), new anchor.BN(3)];
+    await testEnvironment.transferRestrictionsHelper.provisionInvestors(
+      holderIds,
+      groupId,
+      investorTokenAccounts.slice(1),
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    for (const [index, holderId] of holderIds.entries()) {
+      await assertProvisioned(holderId, investorTokenAccounts[index + 1]);
+    }
+    const groupData =
+      await testEnvironment.transferRestrictionsHelper.groupData(groupPubkey);
+    assert.equal(groupData.currentHoldersCount.toNumber(), 4);
+    const transferRestrictionData =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    assert.equal(transferRestrictionData.currentHoldersCount.toNumber(), 4);
+    assert.equal(transferRestrictionData.holderIds.toNumber(), 4);
+    assert.equal(
+      transferRestrictionData.currentHoldersOfRecordCount.toNumber(),
+      4
+    );
+  });
+});
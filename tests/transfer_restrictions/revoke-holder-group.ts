@@ -128,6 +128,7 @@ describe("Revoke holder group", () => {
           group: groupPubkey,
           authorityWalletRole: authorityWalletRolePubkey,
           payer: signer.publicKey,
+          rentReceiver: signer.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([signer])
@@ -164,6 +165,7 @@ describe("Revoke holder group", () => {
           group: groupPubkey,
           authorityWalletRole: authorityWalletRolePubkey,
           payer: signer.publicKey,
+          rentReceiver: signer.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([signer])
@@ -205,6 +207,7 @@ describe("Revoke holder group", () => {
         group: groupPubkey,
         authorityWalletRole: authorityWalletRolePubkey,
         payer: signer.publicKey,
+        rentReceiver: signer.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .signers([signer])
@@ -253,6 +256,7 @@ describe("Revoke holder group", () => {
         group: groupPubkey,
         authorityWalletRole: authorityWalletRolePubkey,
         payer: signer.publicKey,
+        rentReceiver: signer.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .signers([signer])
@@ -318,6 +322,7 @@ describe("Revoke holder group", () => {
           group: groupPubkey,
           authorityWalletRole: authorityWalletRolePubkey,
           payer: signer.publicKey,
+          rentReceiver: signer.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([signer])
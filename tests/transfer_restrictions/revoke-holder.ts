@@ -121,6 +121,7 @@ describe("Revoke holder", () => {
               .transferRestrictionDataPubkey,
           authorityWalletRole: authorityWalletRolePubkey,
           payer: signer.publicKey,
+          rentReceiver: signer.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([signer])
@@ -150,6 +151,7 @@ describe("Revoke holder", () => {
               .transferRestrictionDataPubkey,
           authorityWalletRole: authorityWalletRolePubkey,
           payer: signer.publicKey,
+          rentReceiver: signer.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([signer])
@@ -185,6 +187,7 @@ describe("Revoke holder", () => {
               .transferRestrictionDataPubkey,
           authorityWalletRole: authorityWalletRolePubkey,
           payer: signer.publicKey,
+          rentReceiver: signer.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([signer])
@@ -237,6 +240,7 @@ describe("Revoke holder", () => {
         group: groupPubkey,
         authorityWalletRole: authorityWalletRolePubkey,
         payer: signer.publicKey,
+        rentReceiver: signer.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .signers([signer])
@@ -251,6 +255,7 @@ describe("Revoke holder", () => {
             .transferRestrictionDataPubkey,
         authorityWalletRole: authorityWalletRolePubkey,
         payer: signer.publicKey,
+        rentReceiver: signer.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .signers([signer])
@@ -295,6 +300,7 @@ describe("Revoke holder", () => {
         group: groupPubkey,
         authorityWalletRole: authorityWalletRolePubkey,
         payer: signer.publicKey,
+        rentReceiver: signer.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .signers([signer])
@@ -308,6 +314,7 @@ describe("Revoke holder", () => {
             .transferRestrictionDataPubkey,
         authorityWalletRole: authorityWalletRolePubkey,
         payer: signer.publicKey,
+        rentReceiver: signer.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .signers([signer])
@@ -367,6 +374,7 @@ describe("Revoke holder", () => {
               .transferRestrictionDataPubkey,
           authorityWalletRole: authorityWalletRolePubkey,
           payer: signer.publicKey,
+          rentReceiver: signer.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([signer])
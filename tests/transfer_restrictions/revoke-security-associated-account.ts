@@ -182,6 +182,7 @@ describe("Revoke security associated account", () => {
           associatedTokenAccount: investorsTokenAccounts[holderIdx],
           authorityWalletRole: authorityWalletRolePubkey,
           payer: signer.publicKey,
+          rentReceiver: signer.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([signer])
@@ -227,6 +228,7 @@ describe("Revoke security associated account", () => {
           associatedTokenAccount: investorsTokenAccounts[holderIdx],
           authorityWalletRole: authorityWalletRolePubkey,
           payer: signer.publicKey,
+          rentReceiver: signer.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([signer])
@@ -287,6 +289,7 @@ describe("Revoke security associated account", () => {
           associatedTokenAccount: investorsTokenAccounts[0],
           authorityWalletRole: authorityWalletRolePubkey,
           payer: signer.publicKey,
+          rentReceiver: signer.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([signer])
@@ -370,6 +373,7 @@ describe("Revoke security associated account", () => {
           associatedTokenAccount: investorsTokenAccounts[1],
           authorityWalletRole: authorityWalletRolePubkey,
           payer: signer.publicKey,
+          rentReceiver: signer.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([signer])
@@ -403,4 +407,115 @@ describe("Revoke security associated account", () => {
       );
     });
   });
+
+  describe("when rent receiver is designated", () => {
+    it("fails to revoke security associated account with another holder", async () => {
+      const signer = testEnvironment.walletsAdmin;
+      const [authorityWalletRolePubkey] =
+        testEnvironment.accessControlHelper.walletRolePDA(signer.publicKey);
+      const [anotherHolderPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderPDA(new anchor.BN(0));
+      const [anotherHolderGroupPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+          anotherHolderPubkey,
+          groupId
+        );
+      const [securityAssociatedAccountPubkey] =
+        testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+          investorsTokenAccounts[2]
+        );
+
+      try {
+        await testEnvironment.transferRestrictionsHelper.program.methods
+          .revokeSecurityAssociatedAccount()
+          .accountsStrict({
+            securityAssociatedAccount: securityAssociatedAccountPubkey,
+            group: groupPubkey,
+            holder: anotherHolderPubkey,
+            holderGroup: anotherHolderGroupPubkey,
+            securityToken: testEnvironment.mintKeypair.publicKey,
+            transferRestrictionData:
+              testEnvironment.transferRestrictionsHelper
+                .transferRestrictionDataPubkey,
+            userWallet: investors[2].publicKey,
+            associatedTokenAccount: investorsTokenAccounts[2],
+            authorityWalletRole: authorityWalletRolePubkey,
+            payer: signer.publicKey,
+            rentReceiver: signer.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([signer])
+          .rpc({ commitment: testEnvironment.commitment });
+        assert.fail("Expect an error");
+      } catch ({ error }) {
+        assert.equal(error.errorCode.code, "ConstraintRaw");
+        assert.equal(error.errorMessage, "A raw constraint was violated");
+      }
+    });
+
+    it("refunds rent to the receiver and re-initializes account without stale data", async () => {
+      const signer = testEnvironment.walletsAdmin;
+      const [authorityWalletRolePubkey] =
+        testEnvironment.accessControlHelper.walletRolePDA(signer.publicKey);
+      const rentReceiver = Keypair.generate();
+      const [securityAssociatedAccountPubkey] =
+        testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+          investorsTokenAccounts[2]
+        );
+      const { lamports: rent } =
+        await testEnvironment.connection.getAccountInfo(
+          securityAssociatedAccountPubkey
+        );
+      await testEnvironment.transferRestrictionsHelper.updateWalletTransferBlocks(
+        true,
+        true,
+        investors[2].publicKey,
+        investorsTokenAccounts[2],
+        authorityWalletRolePubkey,
+        signer
+      );
+
+      await testEnvironment.transferRestrictionsHelper.revokeSecurityAssociatedAccount(
+        securityAssociatedAccountPubkey,
+        investors[2].publicKey,
+        investorsTokenAccounts[2],
+        authorityWalletRolePubkey,
+        signer,
+        rentReceiver.publicKey
+      );
+      assert.isNull(
+        await testEnvironment.connection.getAccountInfo(
+          securityAssociatedAccountPubkey
+        )
+      );
+      assert.equal(
+        await testEnvironment.connection.getBalance(rentReceiver.publicKey),
+        rent
+      );
+
+      const [holderPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderPDA(new anchor.BN(1));
+      const [holderGroupPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+          holderPubkey,
+          groupId
+        );
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccount(
+        groupPubkey,
+        holderPubkey,
+        holderGroupPubkey,
+        investors[2].publicKey,
+        investorsTokenAccounts[2],
+        authorityWalletRolePubkey,
+        signer
+      );
+      const { group, sendBlocked, receiveBlocked } =
+        await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+          securityAssociatedAccountPubkey
+        );
+      assert.equal(group.toNumber(), groupId.toNumber());
+      assert.isFalse(sendBlocked);
+      assert.isFalse(receiveBlocked);
+    });
+  });
 });
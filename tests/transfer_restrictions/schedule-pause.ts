@@ -0,0 +1,278 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+
+describe("Scheduled pause", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 3,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const investor = Keypair.generate();
+  const mintedAmount = 1_000_000 * 10 ** testEnvironmentParams.mint.decimals;
+  const transferAmount = 1_000 * 10 ** testEnvironmentParams.mint.decimals;
+  let reserveAdminTokenAccountPubkey: PublicKey;
+  let investorTokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+
+  async function transferToInvestor(): Promise<string> {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        reserveAdminTokenAccountPubkey,
+        testEnvironment.mintKeypair.publicKey,
+        investorTokenAccountPubkey,
+        testEnvironment.reserveAdmin.publicKey,
+        BigInt(transferAmount),
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [testEnvironment.reserveAdmin],
+      { commitment: testEnvironment.commitment }
+    );
+  }
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+
+    reserveAdminTokenAccountPubkey =
+      testEnvironment.mintHelper.getAssocciatedTokenAddress(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN(mintedAmount),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      testEnvironment.reserveAdmin
+    );
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const [groupPubkey] =
+      testEnvironment.transferRestrictionsHelper.groupPDA(groupId);
+    const wallets = [
+      {
+        holderId: new anchor.BN(0),
+        owner: testEnvironment.reserveAdmin.publicKey,
+        tokenAccount: reserveAdminTokenAccountPubkey,
+      },
+      {
+        holderId: new anchor.BN(1),
+        owner: investor.publicKey,
+        tokenAccount: investorTokenAccountPubkey,
+      },
+    ];
+    for (const { holderId, owner, tokenAccount } of wallets) {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionHolder(
+        holderId,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      const [holderPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderPDA(holderId);
+      const [holderGroupPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+          holderPubkey,
+          groupId
+        );
+      await testEnvironment.transferRestrictionsHelper.initializeHolderGroup(
+        holderGroupPubkey,
+        holderPubkey,
+        groupPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccount(
+        groupPubkey,
+        holderPubkey,
+        holderGroupPubkey,
+        owner,
+        tokenAccount,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+    }
+
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(await getNowTs(testEnvironment.connection)),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+  });
+
+  it("fails to schedule pause by wallets admin", async () => {
+    const now = await getNowTs(testEnvironment.connection);
+    try {
+      await testEnvironment.transferRestrictionsHelper.schedulePause(
+        new anchor.BN(now),
+        new anchor.BN(now + 3600),
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("fails to schedule pause ending before it starts", async () => {
+    const now = await getNowTs(testEnvironment.connection);
+    try {
+      await testEnvironment.transferRestrictionsHelper.schedulePause(
+        new anchor.BN(now + 3600),
+        new anchor.BN(now + 60),
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidPauseSchedule");
+      assert.equal(
+        error.errorMessage,
+        "Scheduled pause must end after it starts and in the future"
+      );
+    }
+  });
+
+  it("fails to schedule pause which has already ended", async () => {
+    const now = await getNowTs(testEnvironment.connection);
+    try {
+      await testEnvironment.transferRestrictionsHelper.schedulePause(
+        new anchor.BN(now - 3600),
+        new anchor.BN(now - 60),
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidPauseSchedule");
+      assert.equal(
+        error.errorMessage,
+        "Scheduled pause must end after it starts and in the future"
+      );
+    }
+  });
+
+  it("allows transfers before the scheduled pause starts", async () => {
+    const now = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.schedulePause(
+      new anchor.BN(now + 3600),
+      new anchor.BN(now + 7200),
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const { paused, pausedFrom, pausedUntil } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    assert.isFalse(paused);
+    assert.equal(pausedFrom.toNumber(), now + 3600);
+    assert.equal(pausedUntil.toNumber(), now + 7200);
+
+    await transferToInvestor();
+  });
+
+  it("blocks transfers during the scheduled pause and lifts it automatically", async () => {
+    const now = await getNowTs(testEnvironment.connection);
+    const pausedUntil = now + 5;
+    await testEnvironment.transferRestrictionsHelper.schedulePause(
+      new anchor.BN(now),
+      new anchor.BN(pausedUntil),
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    try {
+      await transferToInvestor();
+      assert.fail("Expect an error");
+    } catch (error) {
+      const msg =
+        "Program log: AnchorError occurred. Error Code: AllTransfersPaused. Error Number: 6004. Error Message: All transfers are paused.";
+      assert.isTrue(error.logs.some((log: string) => log === msg));
+    }
+
+    while ((await getNowTs(testEnvironment.connection)) < pausedUntil) {
+      await new Promise((resolve) => setTimeout(resolve, 500));
+    }
+    await transferToInvestor();
+  });
+
+  it("cancels the scheduled pause", async () => {
+    await testEnvironment.transferRestrictionsHelper.schedulePause(
+      new anchor.BN(0),
+      new anchor.BN(0),
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const { pausedFrom, pausedUntil } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    assert.equal(pausedFrom.toNumber(), 0);
+    assert.equal(pausedUntil.toNumber(), 0);
+
+    try {
+      await testEnvironment.transferRestrictionsHelper.schedulePause(
+        new anchor.BN(0),
+        new anchor.BN(0),
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "ValueUnchanged");
+      assert.equal(
+        error.errorMessage,
+        "The provided value is already set. No changes were made"
+      );
+    }
+  });
+});
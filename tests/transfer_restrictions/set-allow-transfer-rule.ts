@@ -1,6 +1,6 @@
 import * as anchor from "@coral-xyz/anchor";
 import { assert } from "chai";
-import { PublicKey } from "@solana/web3.js";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
 
 import {
   TestEnvironment,
@@ -65,7 +65,7 @@ describe("Set allow transfer rule", () => {
     const lockedUntil = new anchor.BN(0);
     try {
       await testEnvironment.transferRestrictionsHelper.program.methods
-        .setAllowTransferRule(lockedUntil)
+        .setAllowTransferRule(lockedUntil, new anchor.BN(0))
         .accountsStrict({
           transferRule: transferRulePubkey,
           transferRestrictionData:
@@ -77,6 +77,7 @@ describe("Set allow transfer rule", () => {
             testEnvironment.accessControlHelper.accessControlPubkey,
           authorityWalletRole: authorityWalletRolePubkey,
           payer: signer.publicKey,
+          systemProgram: SystemProgram.programId,
         })
         .signers([signer])
         .rpc({ commitment: testEnvironment.commitment });
@@ -94,7 +95,7 @@ describe("Set allow transfer rule", () => {
     const lockedUntil = new anchor.BN(0);
     try {
       await testEnvironment.transferRestrictionsHelper.program.methods
-        .setAllowTransferRule(lockedUntil)
+        .setAllowTransferRule(lockedUntil, new anchor.BN(0))
         .accountsStrict({
           transferRule: transferRulePubkey,
           transferRestrictionData:
@@ -106,6 +107,7 @@ describe("Set allow transfer rule", () => {
             testEnvironment.accessControlHelper.accessControlPubkey,
           authorityWalletRole: authorityWalletRolePubkey,
           payer: signer.publicKey,
+          systemProgram: SystemProgram.programId,
         })
         .signers([signer])
         .rpc({ commitment: testEnvironment.commitment });
@@ -123,7 +125,7 @@ describe("Set allow transfer rule", () => {
     const lockedUntil = new anchor.BN(0);
     try {
       await testEnvironment.transferRestrictionsHelper.program.methods
-        .setAllowTransferRule(lockedUntil)
+        .setAllowTransferRule(lockedUntil, new anchor.BN(0))
         .accountsStrict({
           transferRule: transferRulePubkey,
           transferRestrictionData:
@@ -135,6 +137,7 @@ describe("Set allow transfer rule", () => {
             testEnvironment.accessControlHelper.accessControlPubkey,
           authorityWalletRole: authorityWalletRolePubkey,
           payer: signer.publicKey,
+          systemProgram: SystemProgram.programId,
         })
         .signers([signer])
         .rpc({ commitment: testEnvironment.commitment });
@@ -151,7 +154,7 @@ describe("Set allow transfer rule", () => {
       testEnvironment.accessControlHelper.walletRolePDA(signer.publicKey);
     const lockedUntil = new anchor.BN(0);
     await testEnvironment.transferRestrictionsHelper.program.methods
-      .setAllowTransferRule(lockedUntil)
+      .setAllowTransferRule(lockedUntil, new anchor.BN(0))
       .accountsStrict({
         transferRule: transferRulePubkey,
         transferRestrictionData:
@@ -163,6 +166,7 @@ describe("Set allow transfer rule", () => {
           testEnvironment.accessControlHelper.accessControlPubkey,
         authorityWalletRole: authorityWalletRolePubkey,
         payer: signer.publicKey,
+        systemProgram: SystemProgram.programId,
       })
       .signers([signer])
       .rpc({ commitment: testEnvironment.commitment });
@@ -180,7 +184,7 @@ describe("Set allow transfer rule", () => {
     const lockedUntil = new anchor.BN(0);
     try {
       await testEnvironment.transferRestrictionsHelper.program.methods
-        .setAllowTransferRule(lockedUntil)
+        .setAllowTransferRule(lockedUntil, new anchor.BN(0))
         .accountsStrict({
           transferRule: transferRulePubkey,
           transferRestrictionData:
@@ -192,6 +196,7 @@ describe("Set allow transfer rule", () => {
             testEnvironment.accessControlHelper.accessControlPubkey,
           authorityWalletRole: authorityWalletRolePubkey,
           payer: signer.publicKey,
+          systemProgram: SystemProgram.programId,
         })
         .signers([signer])
         .rpc({ commitment: testEnvironment.commitment });
@@ -204,4 +209,58 @@ describe("Set allow transfer rule", () => {
       );
     }
   });
+
+  it("sets allow transfer rule with locked after by transfer admin", async () => {
+    const signer = testEnvironment.transferAdmin;
+    const [authorityWalletRolePubkey] =
+      testEnvironment.accessControlHelper.walletRolePDA(signer.publicKey);
+    const lockedUntil = new anchor.BN(await getNowTs(testEnvironment.connection));
+    const lockedAfter = lockedUntil.addn(1000);
+    await testEnvironment.transferRestrictionsHelper.setAllowTransferRule(
+      lockedUntil,
+      lockedAfter,
+      transferRulePubkey,
+      groupFromPubkey,
+      groupToPubkey,
+      authorityWalletRolePubkey,
+      signer
+    );
+    const transferRuleData =
+      await testEnvironment.transferRestrictionsHelper.transferRuleData(
+        transferRulePubkey
+      );
+    assert.equal(
+      transferRuleData.lockedUntil.toString(),
+      lockedUntil.toString()
+    );
+    assert.equal(
+      transferRuleData.lockedAfter.toString(),
+      lockedAfter.toString()
+    );
+  });
+
+  it("fails to set locked after not later than locked until", async () => {
+    const signer = testEnvironment.transferAdmin;
+    const [authorityWalletRolePubkey] =
+      testEnvironment.accessControlHelper.walletRolePDA(signer.publicKey);
+    const lockedUntil = new anchor.BN(await getNowTs(testEnvironment.connection));
+    try {
+      await testEnvironment.transferRestrictionsHelper.setAllowTransferRule(
+        lockedUntil,
+        lockedUntil,
+        transferRulePubkey,
+        groupFromPubkey,
+        groupToPubkey,
+        authorityWalletRolePubkey,
+        signer
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidTransferRuleTimeWindow");
+      assert.equal(
+        error.errorMessage,
+        "Transfer rule locked after must be later than locked until"
+      );
+    }
+  });
 });
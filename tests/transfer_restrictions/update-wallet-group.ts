@@ -212,6 +212,7 @@ describe("Update wallet group", () => {
           transferRestrictionGroupNew: secondGroupPubkey,
           holderGroupCurrent: holderGroupCurrentPubkey,
           holderGroupNew: holderGroupNewPubkey,
+          holder: holderPubkey,
           authorityWalletRole: authorityWalletRolePubkey,
           userWallet: userWalletPubkey,
           userAssociatedTokenAccount: userTokenAccountPubkey,
@@ -264,6 +265,7 @@ describe("Update wallet group", () => {
           transferRestrictionGroupNew: secondGroupPubkey,
           holderGroupCurrent: holderGroupCurrentPubkey,
           holderGroupNew: holderGroupNewPubkey,
+          holder: holderPubkey,
           authorityWalletRole: authorityWalletRolePubkey,
           userWallet: userWalletPubkey,
           userAssociatedTokenAccount: userTokenAccountPubkey,
@@ -311,6 +313,7 @@ describe("Update wallet group", () => {
           transferRestrictionGroupNew: firstGroupPubkey,
           holderGroupCurrent: holderGroupCurrentPubkey,
           holderGroupNew: holderGroupCurrentPubkey,
+          holder: holderPubkey,
           authorityWalletRole: authorityWalletRolePubkey,
           userWallet: userWalletPubkey,
           userAssociatedTokenAccount: userTokenAccountPubkey,
@@ -381,6 +384,7 @@ describe("Update wallet group", () => {
         transferRestrictionGroupNew: secondGroupPubkey,
         holderGroupCurrent: holderGroupCurrentPubkey,
         holderGroupNew: holderGroupNewPubkey,
+        holder: holderPubkey,
         authorityWalletRole: authorityWalletRolePubkey,
         userWallet: userWalletPubkey,
         userAssociatedTokenAccount: userTokenAccountPubkey,
@@ -510,6 +514,7 @@ describe("Update wallet group", () => {
         transferRestrictionGroupNew: secondGroupPubkey,
         holderGroupCurrent: holderGroupCurrentPubkey,
         holderGroupNew: holderGroupNewPubkey,
+        holder: holderPubkey,
         authorityWalletRole: authorityWalletRolePubkey,
         userWallet: userWalletPubkey,
         userAssociatedTokenAccount: userTokenAccountPubkey,
@@ -615,6 +620,7 @@ describe("Update wallet group", () => {
         transferRestrictionGroupNew: secondGroupPubkey,
         holderGroupCurrent: holderGroupCurrentPubkey,
         holderGroupNew: holderGroupNewPubkey,
+        holder: holderPubkey,
         authorityWalletRole: authorityWalletRolePubkey,
         userWallet: userWalletPubkey,
         userAssociatedTokenAccount: userTokenAccountPubkey,
@@ -732,6 +738,7 @@ describe("Update wallet group", () => {
           transferRestrictionGroupNew: secondGroupPubkey,
           holderGroupCurrent: holderGroupCurrentPubkey,
           holderGroupNew: holderGroupNewPubkey,
+          holder: holderPubkey,
           authorityWalletRole: authorityWalletRolePubkey,
           userWallet: userWalletPubkey,
           userAssociatedTokenAccount: userTokenAccountPubkey,
@@ -0,0 +1,333 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+
+describe("Update wallet holder", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 3,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const holderCurrentId = new anchor.BN(0);
+  const holderNewId = new anchor.BN(1);
+  let groupPubkey: PublicKey;
+  let holderCurrentPubkey: PublicKey;
+  let holderNewPubkey: PublicKey;
+  const investorWallet0 = Keypair.generate();
+  let investorWallet0TokenAccount: PublicKey;
+  const investorWallet1 = Keypair.generate();
+  let investorWallet1TokenAccount: PublicKey;
+  const investorWallet2 = Keypair.generate();
+  let investorWallet2TokenAccount: PublicKey;
+  const mintedAmount = 1_000 * 10 ** testEnvironmentParams.mint.decimals;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    [groupPubkey] = testEnvironment.transferRestrictionsHelper.groupPDA(groupId);
+
+    for (const holderId of [holderCurrentId, holderNewId]) {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionHolder(
+        holderId,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      const [holderPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderPDA(holderId);
+      const [holderGroupPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+          holderPubkey,
+          groupId
+        );
+      await testEnvironment.transferRestrictionsHelper.initializeHolderGroup(
+        holderGroupPubkey,
+        holderPubkey,
+        groupPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+    }
+    [holderCurrentPubkey] =
+      testEnvironment.transferRestrictionsHelper.holderPDA(holderCurrentId);
+    [holderNewPubkey] =
+      testEnvironment.transferRestrictionsHelper.holderPDA(holderNewId);
+
+    const wallets = [
+      { wallet: investorWallet0, holderPubkey: holderCurrentPubkey },
+      { wallet: investorWallet1, holderPubkey: holderCurrentPubkey },
+      { wallet: investorWallet2, holderPubkey: holderNewPubkey },
+    ];
+    const tokenAccounts: PublicKey[] = [];
+    for (const { wallet, holderPubkey } of wallets) {
+      const tokenAccount =
+        await testEnvironment.mintHelper.createAssociatedTokenAccount(
+          wallet.publicKey,
+          testEnvironment.reserveAdmin
+        );
+      await testEnvironment.accessControlHelper.mintSecurities(
+        new anchor.BN(mintedAmount),
+        wallet.publicKey,
+        tokenAccount,
+        testEnvironment.reserveAdmin
+      );
+      const [holderGroupPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+          holderPubkey,
+          groupId
+        );
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccount(
+        groupPubkey,
+        holderPubkey,
+        holderGroupPubkey,
+        wallet.publicKey,
+        tokenAccount,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      tokenAccounts.push(tokenAccount);
+    }
+    [
+      investorWallet0TokenAccount,
+      investorWallet1TokenAccount,
+      investorWallet2TokenAccount,
+    ] = tokenAccounts;
+  });
+
+  it("fails to update wallet holder by reserve admin", async () => {
+    const [reserveAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    try {
+      await testEnvironment.transferRestrictionsHelper.updateWalletHolder(
+        groupId,
+        holderCurrentPubkey,
+        holderNewPubkey,
+        reserveAdminWalletRole,
+        investorWallet0.publicKey,
+        investorWallet0TokenAccount,
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("fails to update wallet to the same holder", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.updateWalletHolder(
+        groupId,
+        holderCurrentPubkey,
+        holderCurrentPubkey,
+        walletsAdminWalletRole,
+        investorWallet0.publicKey,
+        investorWallet0TokenAccount,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(
+        error.errorCode.code,
+        "NewHolderIsTheSameAsTheCurrentHolder"
+      );
+      assert.equal(
+        error.errorMessage,
+        "New holder is the same as the current holder"
+      );
+    }
+  });
+
+  it("fails to update wallet holder above the new holder max balance", async () => {
+    await testEnvironment.transferRestrictionsHelper.setHolderMaxBalance(
+      new anchor.BN(mintedAmount),
+      holderNewPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    try {
+      await testEnvironment.transferRestrictionsHelper.updateWalletHolder(
+        groupId,
+        holderCurrentPubkey,
+        holderNewPubkey,
+        walletsAdminWalletRole,
+        investorWallet0.publicKey,
+        investorWallet0TokenAccount,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "HolderMaxBalanceExceeded");
+      assert.equal(error.errorMessage, "Holder max balance exceeded");
+    }
+    await testEnvironment.transferRestrictionsHelper.setHolderMaxBalance(
+      new anchor.BN(0),
+      holderNewPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+  });
+
+  it("fails to update wallet holder above the group max balance", async () => {
+    await testEnvironment.transferRestrictionsHelper.setGroupMaxBalance(
+      new anchor.BN(mintedAmount),
+      groupPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    try {
+      await testEnvironment.transferRestrictionsHelper.updateWalletHolder(
+        groupId,
+        holderCurrentPubkey,
+        holderNewPubkey,
+        walletsAdminWalletRole,
+        investorWallet0.publicKey,
+        investorWallet0TokenAccount,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "GroupMaxBalanceExceeded");
+      assert.equal(error.errorMessage, "Group max balance exceeded");
+    }
+    await testEnvironment.transferRestrictionsHelper.setGroupMaxBalance(
+      new anchor.BN(0),
+      groupPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+  });
+
+  it("updates wallet holder by wallets admin when more than 1 wallet in current holder", async () => {
+    await testEnvironment.transferRestrictionsHelper.updateWalletHolder(
+      groupId,
+      holderCurrentPubkey,
+      holderNewPubkey,
+      walletsAdminWalletRole,
+      investorWallet0.publicKey,
+      investorWallet0TokenAccount,
+      testEnvironment.walletsAdmin
+    );
+
+    const saaData =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+          investorWallet0TokenAccount
+        )[0]
+      );
+    assert.equal(saaData.holder.toBase58(), holderNewPubkey.toBase58());
+    assert.equal(saaData.holderId.toNumber(), holderNewId.toNumber());
+
+    const holderCurrentData =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        holderCurrentPubkey
+      );
+    assert.equal(holderCurrentData.currentWalletsCount.toNumber(), 1);
+    assert.equal(holderCurrentData.currentBalance.toNumber(), mintedAmount);
+    const holderNewData =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        holderNewPubkey
+      );
+    assert.equal(holderNewData.currentWalletsCount.toNumber(), 2);
+    assert.equal(holderNewData.currentBalance.toNumber(), 2 * mintedAmount);
+
+    const holderGroupCurrentData =
+      await testEnvironment.transferRestrictionsHelper.holderGroupData(
+        testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+          holderCurrentPubkey,
+          groupId
+        )[0]
+      );
+    assert.equal(holderGroupCurrentData.currentWalletsCount.toNumber(), 1);
+    const holderGroupNewData =
+      await testEnvironment.transferRestrictionsHelper.holderGroupData(
+        testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+          holderNewPubkey,
+          groupId
+        )[0]
+      );
+    assert.equal(holderGroupNewData.currentWalletsCount.toNumber(), 2);
+
+    const groupData =
+      await testEnvironment.transferRestrictionsHelper.groupData(groupPubkey);
+    assert.equal(groupData.currentHoldersCount.toNumber(), 2);
+  });
+
+  it("merges holders by moving the last wallet and retiring the empty holder", async () => {
+    await testEnvironment.transferRestrictionsHelper.updateWalletHolder(
+      groupId,
+      holderCurrentPubkey,
+      holderNewPubkey,
+      transferAdminWalletRole,
+      investorWallet1.publicKey,
+      investorWallet1TokenAccount,
+      testEnvironment.transferAdmin
+    );
+
+    const holderCurrentData =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        holderCurrentPubkey
+      );
+    assert.equal(holderCurrentData.currentWalletsCount.toNumber(), 0);
+    assert.equal(holderCurrentData.currentBalance.toNumber(), 0);
+    const holderNewData =
+      await testEnvironment.transferRestrictionsHelper.holderData(
+        holderNewPubkey
+      );
+    assert.equal(holderNewData.currentWalletsCount.toNumber(), 3);
+    assert.equal(holderNewData.currentBalance.toNumber(), 3 * mintedAmount);
+    const groupData =
+      await testEnvironment.transferRestrictionsHelper.groupData(groupPubkey);
+    assert.equal(groupData.currentHoldersCount.toNumber(), 1);
+
+    await testEnvironment.transferRestrictionsHelper.revokeHolderGroup(
+      holderCurrentPubkey,
+      groupId,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    await testEnvironment.transferRestrictionsHelper.revokeHolder(
+      holderCurrentPubkey,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+
+    const holderCurrentInfo =
+      await testEnvironment.connection.getAccountInfo(holderCurrentPubkey);
+    assert.isNull(holderCurrentInfo);
+    const { currentHoldersCount } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    assert.equal(currentHoldersCount.toNumber(), 1);
+  });
+});
@@ -0,0 +1,306 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+
+describe("Group velocity limit", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 3,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const holderReserveAdminId = new anchor.BN(0);
+  const holderInvestorId = new anchor.BN(1);
+  const investor = Keypair.generate();
+  const mintedAmount = 1_000_000 * 10 ** testEnvironmentParams.mint.decimals;
+  let groupPubkey: PublicKey;
+  let reserveAdminTokenAccountPubkey: PublicKey;
+  let investorTokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+
+  async function transferFromReserveAdmin(amount: number): Promise<string> {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        reserveAdminTokenAccountPubkey,
+        testEnvironment.mintKeypair.publicKey,
+        investorTokenAccountPubkey,
+        testEnvironment.reserveAdmin.publicKey,
+        BigInt(amount),
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [testEnvironment.reserveAdmin],
+      { commitment: testEnvironment.commitment }
+    );
+  }
+
+  async function sentWithinVelocityWindow(
+    tokenAccountPubkey: PublicKey
+  ): Promise<number> {
+    const [securityAssociatedAccountPubkey] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        tokenAccountPubkey
+      );
+    const { velocitySent } =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        securityAssociatedAccountPubkey
+      );
+    return velocitySent.reduce((sent, amount) => sent + amount.toNumber(), 0);
+  }
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+
+    reserveAdminTokenAccountPubkey =
+      testEnvironment.mintHelper.getAssocciatedTokenAddress(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN(mintedAmount),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      testEnvironment.reserveAdmin
+    );
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    [groupPubkey] = testEnvironment.transferRestrictionsHelper.groupPDA(groupId);
+
+    const wallets = [
+      {
+        holderId: holderReserveAdminId,
+        owner: testEnvironment.reserveAdmin.publicKey,
+        tokenAccount: reserveAdminTokenAccountPubkey,
+      },
+      {
+        holderId: holderInvestorId,
+        owner: investor.publicKey,
+        tokenAccount: investorTokenAccountPubkey,
+      },
+    ];
+    for (const { holderId, owner, tokenAccount } of wallets) {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionHolder(
+        holderId,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      const [holderPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderPDA(holderId);
+      const [holderGroupPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+          holderPubkey,
+          groupId
+        );
+      await testEnvironment.transferRestrictionsHelper.initializeHolderGroup(
+        holderGroupPubkey,
+        holderPubkey,
+        groupPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccount(
+        groupPubkey,
+        holderPubkey,
+        holderGroupPubkey,
+        owner,
+        tokenAccount,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+    }
+
+    const lockedUntil = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(lockedUntil),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+  });
+
+  it("fails to set group velocity limit by wallets admin", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setGroupVelocityLimit(
+        new anchor.BN(1_000),
+        new anchor.BN(3_600),
+        groupPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("fails to set group velocity limit without period", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setGroupVelocityLimit(
+        new anchor.BN(1_000),
+        new anchor.BN(0),
+        groupPubkey,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "InvalidVelocityPeriod");
+      assert.equal(
+        error.errorMessage,
+        "Velocity period must be non-zero when velocity limit is set"
+      );
+    }
+  });
+
+  it("blocks transfers above velocity limit within the period", async () => {
+    const velocityLimit = 1_000 * 10 ** testEnvironmentParams.mint.decimals;
+    await testEnvironment.transferRestrictionsHelper.setGroupVelocityLimit(
+      new anchor.BN(velocityLimit),
+      new anchor.BN(3_600),
+      groupPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const { velocityLimit: groupVelocityLimit, velocityPeriod } =
+      await testEnvironment.transferRestrictionsHelper.groupData(groupPubkey);
+    assert.equal(groupVelocityLimit.toNumber(), velocityLimit);
+    assert.equal(velocityPeriod.toNumber(), 3_600);
+
+    const firstTransferAmount = 600 * 10 ** testEnvironmentParams.mint.decimals;
+    await transferFromReserveAdmin(firstTransferAmount);
+    assert.equal(
+      await sentWithinVelocityWindow(reserveAdminTokenAccountPubkey),
+      firstTransferAmount
+    );
+
+    try {
+      await transferFromReserveAdmin(
+        500 * 10 ** testEnvironmentParams.mint.decimals
+      );
+      assert.fail("Expect an error");
+    } catch (error) {
+      const msg =
+        "Program log: AnchorError occurred. Error Code: VelocityLimitExceeded. Error Number: 6030. Error Message: Velocity limit exceeded.";
+      const isVelocityLimitExceeded = error.logs.some(
+        (log: string) => log === msg
+      );
+      assert.isTrue(isVelocityLimitExceeded);
+    }
+
+    await transferFromReserveAdmin(velocityLimit - firstTransferAmount);
+  });
+
+  it("counts enforced transfers signed by the source account owner", async () => {
+    const velocityLimit = 1_500 * 10 ** testEnvironmentParams.mint.decimals;
+    const transferAmount = 500 * 10 ** testEnvironmentParams.mint.decimals;
+    await testEnvironment.transferRestrictionsHelper.setGroupVelocityLimit(
+      new anchor.BN(velocityLimit),
+      new anchor.BN(3_600),
+      groupPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const sentBefore = await sentWithinVelocityWindow(
+      reserveAdminTokenAccountPubkey
+    );
+
+    await testEnvironment.transferRestrictionsHelper.enforceTransferRestrictions(
+      reserveAdminTokenAccountPubkey,
+      investorTokenAccountPubkey,
+      new anchor.BN(transferAmount)
+    );
+    assert.equal(
+      await sentWithinVelocityWindow(reserveAdminTokenAccountPubkey),
+      sentBefore
+    );
+
+    await testEnvironment.transferRestrictionsHelper.enforceTransferRestrictions(
+      reserveAdminTokenAccountPubkey,
+      investorTokenAccountPubkey,
+      new anchor.BN(transferAmount),
+      testEnvironment.reserveAdmin
+    );
+    assert.equal(
+      await sentWithinVelocityWindow(reserveAdminTokenAccountPubkey),
+      sentBefore + transferAmount
+    );
+
+    try {
+      await testEnvironment.transferRestrictionsHelper.enforceTransferRestrictions(
+        reserveAdminTokenAccountPubkey,
+        investorTokenAccountPubkey,
+        new anchor.BN(1),
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "VelocityLimitExceeded");
+      assert.equal(error.errorMessage, "Velocity limit exceeded");
+    }
+  });
+
+  it("removes velocity limit", async () => {
+    await testEnvironment.transferRestrictionsHelper.setGroupVelocityLimit(
+      new anchor.BN(0),
+      new anchor.BN(0),
+      groupPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    await transferFromReserveAdmin(
+      500 * 10 ** testEnvironmentParams.mint.decimals
+    );
+  });
+});
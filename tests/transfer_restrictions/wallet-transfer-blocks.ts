@@ -0,0 +1,314 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+import { solToLamports, topUpWallet } from "../utils";
+
+describe("Wallet transfer blocks", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 3,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const groupId = new anchor.BN(1);
+  const holderReserveAdminId = new anchor.BN(0);
+  const holderInvestorId = new anchor.BN(1);
+  const investor = Keypair.generate();
+  const mintedAmount = 1_000_000 * 10 ** testEnvironmentParams.mint.decimals;
+  let groupPubkey: PublicKey;
+  let reserveAdminTokenAccountPubkey: PublicKey;
+  let investorTokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+
+  async function transferFromReserveAdmin(amount: number): Promise<string> {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        reserveAdminTokenAccountPubkey,
+        testEnvironment.mintKeypair.publicKey,
+        investorTokenAccountPubkey,
+        testEnvironment.reserveAdmin.publicKey,
+        BigInt(amount),
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [testEnvironment.reserveAdmin],
+      { commitment: testEnvironment.commitment }
+    );
+  }
+
+  async function transferFromInvestor(amount: number): Promise<string> {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        investorTokenAccountPubkey,
+        testEnvironment.mintKeypair.publicKey,
+        reserveAdminTokenAccountPubkey,
+        investor.publicKey,
+        BigInt(amount),
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [investor],
+      { commitment: testEnvironment.commitment }
+    );
+  }
+
+  function assertErrorLog(error: any, msg: string) {
+    const hasErrorLog = error.logs.some((log: string) => log === msg);
+    assert.isTrue(hasErrorLog);
+  }
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+
+    reserveAdminTokenAccountPubkey =
+      testEnvironment.mintHelper.getAssocciatedTokenAddress(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN(mintedAmount),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      testEnvironment.reserveAdmin
+    );
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    [groupPubkey] = testEnvironment.transferRestrictionsHelper.groupPDA(groupId);
+
+    const wallets = [
+      {
+        holderId: holderReserveAdminId,
+        owner: testEnvironment.reserveAdmin.publicKey,
+        tokenAccount: reserveAdminTokenAccountPubkey,
+      },
+      {
+        holderId: holderInvestorId,
+        owner: investor.publicKey,
+        tokenAccount: investorTokenAccountPubkey,
+      },
+    ];
+    for (const { holderId, owner, tokenAccount } of wallets) {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionHolder(
+        holderId,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      const [holderPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderPDA(holderId);
+      const [holderGroupPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+          holderPubkey,
+          groupId
+        );
+      await testEnvironment.transferRestrictionsHelper.initializeHolderGroup(
+        holderGroupPubkey,
+        holderPubkey,
+        groupPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccount(
+        groupPubkey,
+        holderPubkey,
+        holderGroupPubkey,
+        owner,
+        tokenAccount,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+    }
+
+    const lockedUntil = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(lockedUntil),
+      groupId,
+      groupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    await topUpWallet(
+      testEnvironment.connection,
+      investor.publicKey,
+      solToLamports(1)
+    );
+  });
+
+  const transferAmount = 100 * 10 ** testEnvironmentParams.mint.decimals;
+  const sendBlockedMsg =
+    "Program log: AnchorError occurred. Error Code: WalletSendBlocked. Error Number: 6034. Error Message: Sending tokens is blocked for the wallet.";
+  const receiveBlockedMsg =
+    "Program log: AnchorError occurred. Error Code: WalletReceiveBlocked. Error Number: 6035. Error Message: Receiving tokens is blocked for the wallet.";
+
+  it("fails to update wallet transfer blocks by reserve admin", async () => {
+    const [reserveAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    try {
+      await testEnvironment.transferRestrictionsHelper.updateWalletTransferBlocks(
+        true,
+        false,
+        investor.publicKey,
+        investorTokenAccountPubkey,
+        reserveAdminWalletRole,
+        testEnvironment.reserveAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("fails to update wallet transfer blocks with the same values", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.updateWalletTransferBlocks(
+        false,
+        false,
+        investor.publicKey,
+        investorTokenAccountPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "ValueUnchanged");
+      assert.equal(
+        error.errorMessage,
+        "The provided value is already set. No changes were made"
+      );
+    }
+  });
+
+  it("blocks sending but allows receiving by wallets admin", async () => {
+    await transferFromReserveAdmin(transferAmount);
+    await testEnvironment.transferRestrictionsHelper.updateWalletTransferBlocks(
+      true,
+      false,
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+    const [investorSecurityAssociatedAccountPubkey] =
+      testEnvironment.transferRestrictionsHelper.securityAssociatedAccountPDA(
+        investorTokenAccountPubkey
+      );
+    const { sendBlocked, receiveBlocked } =
+      await testEnvironment.transferRestrictionsHelper.securityAssociatedAccountData(
+        investorSecurityAssociatedAccountPubkey
+      );
+    assert.isTrue(sendBlocked);
+    assert.isFalse(receiveBlocked);
+
+    try {
+      await transferFromInvestor(transferAmount);
+      assert.fail("Expect an error");
+    } catch (error) {
+      assertErrorLog(error, sendBlockedMsg);
+    }
+    await transferFromReserveAdmin(transferAmount);
+  });
+
+  it("blocks receiving but allows minting by transfer admin", async () => {
+    await testEnvironment.transferRestrictionsHelper.updateWalletTransferBlocks(
+      true,
+      true,
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    try {
+      await transferFromReserveAdmin(transferAmount);
+      assert.fail("Expect an error");
+    } catch (error) {
+      assertErrorLog(error, receiveBlockedMsg);
+    }
+
+    const { amount: balanceBefore } =
+      await testEnvironment.mintHelper.getAccount(investorTokenAccountPubkey);
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN(transferAmount),
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      testEnvironment.reserveAdmin
+    );
+    const { amount: balanceAfter } =
+      await testEnvironment.mintHelper.getAccount(investorTokenAccountPubkey);
+    assert.equal(
+      balanceAfter.toString(),
+      (balanceBefore + BigInt(transferAmount)).toString()
+    );
+  });
+
+  it("unblocks wallet", async () => {
+    await testEnvironment.transferRestrictionsHelper.updateWalletTransferBlocks(
+      false,
+      false,
+      investor.publicKey,
+      investorTokenAccountPubkey,
+      walletsAdminWalletRole,
+      testEnvironment.walletsAdmin
+    );
+
+    await transferFromInvestor(transferAmount);
+    await transferFromReserveAdmin(transferAmount);
+  });
+});
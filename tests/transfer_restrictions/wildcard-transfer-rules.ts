@@ -0,0 +1,376 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Keypair,
+  PublicKey,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createTransferCheckedWithTransferHookInstruction,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import {
+  TestEnvironment,
+  TestEnvironmentParams,
+} from "../helpers/test_environment";
+import { getNowTs } from "../helpers/clock_helper";
+import { ANY_TRANSFER_GROUP_ID } from "../helpers/transfer-restrictions_helper";
+
+describe("Wildcard and default transfer rules", () => {
+  const testEnvironmentParams: TestEnvironmentParams = {
+    mint: {
+      decimals: 6,
+      name: "XYZ Token",
+      symbol: "XYZ",
+      uri: "https://example.com",
+    },
+    initialSupply: 1_000_000_000_000,
+    maxHolders: 3,
+    maxTotalSupply: 100_000_000_000_000,
+  };
+  let testEnvironment: TestEnvironment;
+  const reserveAdminGroupId = new anchor.BN(1);
+  const investorGroupId = new anchor.BN(2);
+  const investor = Keypair.generate();
+  const mintedAmount = 1_000_000 * 10 ** testEnvironmentParams.mint.decimals;
+  const transferAmount = 1_000 * 10 ** testEnvironmentParams.mint.decimals;
+  let reserveAdminTokenAccountPubkey: PublicKey;
+  let investorTokenAccountPubkey: PublicKey;
+  let walletsAdminWalletRole: PublicKey;
+  let transferAdminWalletRole: PublicKey;
+
+  async function transferFromReserveAdmin(amount: number): Promise<string> {
+    const transferWithHookInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        testEnvironment.connection,
+        reserveAdminTokenAccountPubkey,
+        testEnvironment.mintKeypair.publicKey,
+        investorTokenAccountPubkey,
+        testEnvironment.reserveAdmin.publicKey,
+        BigInt(amount),
+        testEnvironmentParams.mint.decimals,
+        undefined,
+        testEnvironment.commitment,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+    return sendAndConfirmTransaction(
+      testEnvironment.connection,
+      new Transaction().add(transferWithHookInstruction),
+      [testEnvironment.reserveAdmin],
+      { commitment: testEnvironment.commitment }
+    );
+  }
+
+  async function assertTransferFails(
+    code: string,
+    number: number,
+    message: string
+  ) {
+    try {
+      await transferFromReserveAdmin(transferAmount);
+      assert.fail("Expect an error");
+    } catch (error) {
+      const msg = `Program log: AnchorError occurred. Error Code: ${code}. Error Number: ${number}. Error Message: ${message}.`;
+      assert.isTrue(error.logs.some((log: string) => log === msg));
+    }
+  }
+
+  async function assertInvestorBalanceIncreased(
+    transfer: () => Promise<string>
+  ) {
+    const { amount: balanceBefore } =
+      await testEnvironment.mintHelper.getAccount(investorTokenAccountPubkey);
+    await transfer();
+    const { amount: balanceAfter } =
+      await testEnvironment.mintHelper.getAccount(investorTokenAccountPubkey);
+    assert.equal(balanceAfter - balanceBefore, BigInt(transferAmount));
+  }
+
+  before(async () => {
+    testEnvironment = new TestEnvironment(testEnvironmentParams);
+    await testEnvironment.setupAccessControl();
+    await testEnvironment.setupTransferRestrictions();
+
+    [walletsAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.walletsAdmin.publicKey
+      );
+    [transferAdminWalletRole] =
+      testEnvironment.accessControlHelper.walletRolePDA(
+        testEnvironment.transferAdmin.publicKey
+      );
+
+    reserveAdminTokenAccountPubkey =
+      testEnvironment.mintHelper.getAssocciatedTokenAddress(
+        testEnvironment.reserveAdmin.publicKey
+      );
+    await testEnvironment.accessControlHelper.mintSecurities(
+      new anchor.BN(mintedAmount),
+      testEnvironment.reserveAdmin.publicKey,
+      reserveAdminTokenAccountPubkey,
+      testEnvironment.reserveAdmin
+    );
+    investorTokenAccountPubkey =
+      await testEnvironment.mintHelper.createAssociatedTokenAccount(
+        investor.publicKey,
+        testEnvironment.reserveAdmin
+      );
+
+    const wallets = [
+      {
+        holderId: new anchor.BN(0),
+        groupId: reserveAdminGroupId,
+        owner: testEnvironment.reserveAdmin.publicKey,
+        tokenAccount: reserveAdminTokenAccountPubkey,
+      },
+      {
+        holderId: new anchor.BN(1),
+        groupId: investorGroupId,
+        owner: investor.publicKey,
+        tokenAccount: investorTokenAccountPubkey,
+      },
+    ];
+    for (const { holderId, groupId, owner, tokenAccount } of wallets) {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+        groupId,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      const [groupPubkey] =
+        testEnvironment.transferRestrictionsHelper.groupPDA(groupId);
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionHolder(
+        holderId,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      const [holderPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderPDA(holderId);
+      const [holderGroupPubkey] =
+        testEnvironment.transferRestrictionsHelper.holderGroupPDA(
+          holderPubkey,
+          groupId
+        );
+      await testEnvironment.transferRestrictionsHelper.initializeHolderGroup(
+        holderGroupPubkey,
+        holderPubkey,
+        groupPubkey,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      await testEnvironment.transferRestrictionsHelper.initializeSecurityAssociatedAccount(
+        groupPubkey,
+        holderPubkey,
+        holderGroupPubkey,
+        owner,
+        tokenAccount,
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+    }
+  });
+
+  it("fails to initialize group with the wildcard group id", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRestrictionGroup(
+        ANY_TRANSFER_GROUP_ID,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "ReservedTransferGroupId");
+      assert.equal(
+        error.errorMessage,
+        "Transfer group id is reserved for wildcard transfer rules"
+      );
+    }
+  });
+
+  it("fails to initialize wildcard transfer rule without groups", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+        new anchor.BN(1),
+        ANY_TRANSFER_GROUP_ID,
+        ANY_TRANSFER_GROUP_ID,
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "WildcardTransferRuleWithoutGroup");
+      assert.equal(
+        error.errorMessage,
+        "Wildcard transfer rule requires a group, use the default transfer rule instead"
+      );
+    }
+  });
+
+  it("blocks transfer between groups without transfer rules", async () => {
+    await assertTransferFails(
+      "TransferRuleAccountDataIsEmtpy",
+      6013,
+      "Transfer rule account data is empty"
+    );
+  });
+
+  it("fails to set default transfer rule by wallets admin", async () => {
+    try {
+      await testEnvironment.transferRestrictionsHelper.setDefaultTransferRule(
+        new anchor.BN(1),
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(0),
+        walletsAdminWalletRole,
+        testEnvironment.walletsAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "Unauthorized");
+      assert.equal(error.errorMessage, "Unauthorized");
+    }
+  });
+
+  it("allows transfer by the default transfer rule", async () => {
+    const lockedUntil = new anchor.BN(
+      await getNowTs(testEnvironment.connection)
+    );
+    await testEnvironment.transferRestrictionsHelper.setDefaultTransferRule(
+      lockedUntil,
+      new anchor.BN(0),
+      new anchor.BN(0),
+      new anchor.BN(0),
+      new anchor.BN(0),
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const { defaultTransferRule } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    assert.equal(
+      defaultTransferRule.lockedUntil.toString(),
+      lockedUntil.toString()
+    );
+    assert.isTrue(
+      defaultTransferRule.transferGroupIdFrom.eq(ANY_TRANSFER_GROUP_ID)
+    );
+    assert.isTrue(
+      defaultTransferRule.transferGroupIdTo.eq(ANY_TRANSFER_GROUP_ID)
+    );
+
+    await assertInvestorBalanceIncreased(() =>
+      transferFromReserveAdmin(transferAmount)
+    );
+  });
+
+  it("enforces transfer restrictions by the default transfer rule", async () => {
+    await testEnvironment.transferRestrictionsHelper.enforceTransferRestrictions(
+      reserveAdminTokenAccountPubkey,
+      investorTokenAccountPubkey,
+      new anchor.BN(transferAmount)
+    );
+  });
+
+  it("fails to set the same default transfer rule", async () => {
+    const { defaultTransferRule } =
+      await testEnvironment.transferRestrictionsHelper.transferRestrictionData();
+    try {
+      await testEnvironment.transferRestrictionsHelper.setDefaultTransferRule(
+        defaultTransferRule.lockedUntil,
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(0),
+        transferAdminWalletRole,
+        testEnvironment.transferAdmin
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "ValueUnchanged");
+      assert.equal(
+        error.errorMessage,
+        "The provided value is already set. No changes were made"
+      );
+    }
+  });
+
+  it("applies wildcard rule to the recipient group over the default rule", async () => {
+    const lockedUntil = (await getNowTs(testEnvironment.connection)) + 3600;
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(lockedUntil),
+      ANY_TRANSFER_GROUP_ID,
+      investorGroupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+    const [transferRulePubkey] =
+      testEnvironment.transferRestrictionsHelper.transferRulePDA(
+        ANY_TRANSFER_GROUP_ID,
+        investorGroupId
+      );
+    const { transferGroupIdFrom, transferGroupIdTo } =
+      await testEnvironment.transferRestrictionsHelper.transferRuleData(
+        transferRulePubkey
+      );
+    assert.isTrue(transferGroupIdFrom.eq(ANY_TRANSFER_GROUP_ID));
+    assert.equal(transferGroupIdTo.toNumber(), investorGroupId.toNumber());
+
+    await assertTransferFails(
+      "TransferRuleNotAllowedUntilLater",
+      6002,
+      "Transfer rule not allowed until later"
+    );
+    try {
+      await testEnvironment.transferRestrictionsHelper.enforceTransferRestrictions(
+        reserveAdminTokenAccountPubkey,
+        investorTokenAccountPubkey,
+        new anchor.BN(transferAmount)
+      );
+      assert.fail("Expect an error");
+    } catch ({ error }) {
+      assert.equal(error.errorCode.code, "TransferRuleNotAllowedUntilLater");
+      assert.equal(error.errorMessage, "Transfer rule not allowed until later");
+    }
+  });
+
+  it("applies wildcard rule from the sender group over the recipient group one", async () => {
+    const lockedUntil = await getNowTs(testEnvironment.connection);
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(lockedUntil),
+      reserveAdminGroupId,
+      ANY_TRANSFER_GROUP_ID,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    await assertInvestorBalanceIncreased(() =>
+      transferFromReserveAdmin(transferAmount)
+    );
+  });
+
+  it("applies exact group pair rule over wildcard rules", async () => {
+    const lockedUntil = (await getNowTs(testEnvironment.connection)) + 3600;
+    await testEnvironment.transferRestrictionsHelper.initializeTransferRule(
+      new anchor.BN(lockedUntil),
+      reserveAdminGroupId,
+      investorGroupId,
+      transferAdminWalletRole,
+      testEnvironment.transferAdmin
+    );
+
+    await assertTransferFails(
+      "TransferRuleNotAllowedUntilLater",
+      6002,
+      "Transfer rule not allowed until later"
+    );
+    const { code } =
+      await testEnvironment.transferRestrictionsHelper.detectTransferRestriction(
+        reserveAdminTokenAccountPubkey,
+        investorTokenAccountPubkey,
+        new anchor.BN(transferAmount)
+      );
+    assert.equal(code, 6002);
+  });
+});